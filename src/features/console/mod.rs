@@ -1,7 +1,10 @@
 use async_broadcast::{Sender as BroadcastSender, Receiver as BroadcastReceiver};
+use tokio::io::{stdin, AsyncBufReadExt, BufReader};
 use tokio::task::JoinHandle;
 use colored::*;
 
+use crate::primary::client::movement::route;
+use crate::primary::injector::PacketInjector;
 use crate::primary::traits::Feature;
 use crate::primary::types::HandlerOutput;
 
@@ -28,8 +31,32 @@ impl Feature for Console {
     }
 
     fn get_tasks(&mut self) -> Vec<JoinHandle<()>> {
+        let sender = self._sender.as_ref().unwrap().clone();
         let mut receiver = self._receiver.as_mut().unwrap().clone();
 
+        // accepts commands like: inject CMSG_NAME_QUERY {"guid": 1} or route play <name> pingpong
+        let handle_commands = || {
+            tokio::spawn(async move {
+                let mut lines = BufReader::new(stdin()).lines();
+
+                while let Ok(Some(line)) = lines.next_line().await {
+                    if let Some(command) = line.trim().strip_prefix("inject ") {
+                        let output = match PacketInjector::from_command(command) {
+                            Ok(packet) => HandlerOutput::Data(packet),
+                            Err(err) => HandlerOutput::ErrorMessage(err.to_string(), None),
+                        };
+
+                        sender.broadcast(output).await.unwrap();
+                    } else if let Some(command) = line.trim().strip_prefix("route ") {
+                        let output = route::parse_command(command)
+                            .unwrap_or_else(|err| HandlerOutput::ErrorMessage(err.to_string(), None));
+
+                        sender.broadcast(output).await.unwrap();
+                    }
+                }
+            })
+        };
+
         let handle_input = || {
             tokio::spawn(async move {
                 loop {
@@ -63,6 +90,7 @@ impl Feature for Console {
         };
 
         vec![
+            handle_commands(),
             handle_input(),
         ]
     }
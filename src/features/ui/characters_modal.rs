@@ -91,7 +91,7 @@ impl UIModalComponent for CharactersModal {
                             .fg(Color::LightGreen)
                             .add_modifier(Modifier::BOLD)
                     ),
-                    Span::raw(format!(", guid: {:?}, ", character.guid)),
+                    Span::raw(format!(", guid: {}, ", character.guid.counter())),
                     Span::styled(
                         format!("{} lvl", character.level),
                         Style::default()
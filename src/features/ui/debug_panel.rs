@@ -14,6 +14,7 @@ use crate::features::ui::info_panel::InfoPanel;
 use crate::primary::traits::paginator::Paginator;
 use crate::features::ui::traits::ui_component::{UIComponent};
 use crate::features::ui::types::{LoggerOutput, UIEventFlags};
+use crate::primary::client::WorldLocation;
 use crate::primary::types::HandlerOutput;
 
 const PANEL_TITLE: &str = "I/O MONITOR";
@@ -63,6 +64,11 @@ impl DebugPanel {
         self.total_errors_amount
     }
 
+    pub fn set_location(&mut self, location: WorldLocation) -> &mut Self {
+        self.info_panel.set_location(location);
+        self
+    }
+
     pub fn add_item(&mut self, output: LoggerOutput) -> &mut Self {
         if let Some(item) = self.generate_item(output) {
             self.items.push(item);
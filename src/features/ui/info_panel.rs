@@ -6,6 +6,7 @@ use tui::text::{Span, Spans};
 use tui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap};
 
 use crate::features::ui::traits::ui_component::{UIComponent};
+use crate::primary::client::WorldLocation;
 
 pub struct InfoPanel {
     selected_index: usize,
@@ -13,6 +14,7 @@ pub struct InfoPanel {
     total_response_amount: usize,
     total_request_amount: usize,
     total_errors_amount: usize,
+    location: Option<WorldLocation>,
 }
 
 impl InfoPanel {
@@ -40,6 +42,11 @@ impl InfoPanel {
         self.total_errors_amount = total_errors;
         self
     }
+
+    pub fn set_location(&mut self, location: WorldLocation) -> &mut Self {
+        self.location = Some(location);
+        self
+    }
 }
 
 impl UIComponent for InfoPanel {
@@ -50,6 +57,7 @@ impl UIComponent for InfoPanel {
             total_response_amount: 0,
             total_request_amount: 0,
             total_errors_amount: 0,
+            location: None,
         }
     }
 
@@ -83,6 +91,23 @@ impl UIComponent for InfoPanel {
             );
         }
 
+        if let Some(location) = self.location {
+            spans.extend(
+                vec![
+                    Span::raw(" "),
+                    Span::styled(
+                        format!(
+                            "[MAP {} ZONE {} AREA {}]",
+                            location.map_id,
+                            location.zone_id,
+                            location.area_id,
+                        ),
+                        Style::default().fg(Color::LightGreen)
+                    )
+                ]
+            );
+        }
+
         let block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Plain);
@@ -0,0 +1,103 @@
+use std::sync::{Arc, Mutex as SyncMutex};
+use crossterm::event::{KeyCode, KeyModifiers};
+use tui::backend::Backend;
+use tui::Frame;
+use tui::layout::{Alignment, Rect};
+use tui::style::{Color, Style};
+use tui::widgets::{Block, Borders, BorderType, Clear, Paragraph};
+
+use crate::features::ui::traits::ui_component::{UIComponent};
+use crate::features::ui::types::{UIEventFlags};
+use crate::primary::client::movement::route;
+use crate::primary::injector::PacketInjector;
+use crate::primary::types::HandlerOutput;
+
+const PANEL_TITLE: &str = "INJECT PACKET: <opcode> <hex or json body> | route <command> (Enter to send, Esc to close)";
+const PANEL_HEIGHT: u16 = 3;
+
+pub struct InjectorInput {
+    command: String,
+}
+
+impl InjectorInput {
+    pub fn handle_key_event(
+        &mut self,
+        key_modifiers: KeyModifiers,
+        key_code: KeyCode,
+        event_flags: Arc<SyncMutex<UIEventFlags>>
+    ) -> Option<HandlerOutput> {
+        let mut output = None;
+        let mut guard = event_flags.lock().unwrap();
+
+        if !guard.contains(UIEventFlags::IS_INJECTOR_OPENED) {
+            if key_code == KeyCode::Char('p') && key_modifiers.contains(KeyModifiers::CONTROL) {
+                guard.set(UIEventFlags::IS_INJECTOR_OPENED, true);
+                guard.set(UIEventFlags::IS_EVENT_HANDLED, true);
+            }
+
+            return output;
+        }
+
+        match key_code {
+            KeyCode::Char(char) if !key_modifiers.contains(KeyModifiers::CONTROL) => {
+                self.command.push(char);
+            },
+            KeyCode::Backspace => {
+                self.command.pop();
+            },
+            KeyCode::Esc => {
+                guard.set(UIEventFlags::IS_INJECTOR_OPENED, false);
+            },
+            KeyCode::Enter => {
+                let result = match self.command.trim().strip_prefix("route ") {
+                    Some(command) => route::parse_command(command).map_err(|err| err.to_string()),
+                    None => PacketInjector::from_command(&self.command)
+                        .map(HandlerOutput::Data)
+                        .map_err(|err| err.to_string()),
+                };
+
+                output = match result {
+                    Ok(output) => {
+                        self.command.clear();
+                        guard.set(UIEventFlags::IS_INJECTOR_OPENED, false);
+                        Some(output)
+                    },
+                    Err(err) => Some(HandlerOutput::ErrorMessage(err, None)),
+                };
+            },
+            _ => {},
+        };
+
+        // while input is opened, other components should not react on keys
+        guard.set(UIEventFlags::IS_EVENT_HANDLED, true);
+
+        output
+    }
+}
+
+impl UIComponent for InjectorInput {
+    fn new() -> Self {
+        Self {
+            command: String::new(),
+        }
+    }
+
+    fn render<B: Backend>(&mut self, frame: &mut Frame<B>, rect: Rect) {
+        let height = PANEL_HEIGHT.min(rect.height);
+        let rect = Rect::new(rect.x, rect.y + rect.height - height, rect.width, height);
+
+        let block = Block::default()
+            .title(PANEL_TITLE)
+            .title_alignment(Alignment::Left)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+
+        let paragraph = Paragraph::new(format!("> {}", self.command))
+            .alignment(Alignment::Left)
+            .style(Style::default().fg(Color::LightYellow).bg(Color::Black))
+            .block(block);
+
+        frame.render_widget(Clear, rect);
+        frame.render_widget(paragraph, rect);
+    }
+}
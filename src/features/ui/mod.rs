@@ -31,6 +31,7 @@ mod debug_panel;
 mod info_panel;
 mod realm_modal;
 mod debug_details_panel;
+mod injector_input;
 pub mod types;
 mod title;
 mod traits;
@@ -38,9 +39,11 @@ mod traits;
 use crate::features::ui::traits::ui_component::{UIComponent, UIModalComponent};
 use crate::features::ui::characters_modal::CharactersModal;
 use crate::features::ui::debug_panel::DebugPanel;
+use crate::features::ui::injector_input::InjectorInput;
 use crate::features::ui::realm_modal::RealmModal;
 use crate::features::ui::title::Title;
 use crate::features::ui::types::{LoggerOutput, UIEventFlags};
+use crate::primary::client::WorldEvent;
 use crate::primary::traits::Feature;
 use crate::primary::types::HandlerOutput;
 
@@ -88,6 +91,7 @@ impl Feature for UI {
         let event_flags = Arc::new(SyncMutex::new(UIEventFlags::NONE));
         let characters_modal = Arc::new(SyncMutex::new(CharactersModal::new()));
         let debug_panel = Arc::new(SyncMutex::new(DebugPanel::new()));
+        let injector_input = Arc::new(SyncMutex::new(InjectorInput::new()));
         let realm_modal = Arc::new(SyncMutex::new(RealmModal::new()));
         let title = Arc::new(SyncMutex::new(Title::new()));
 
@@ -96,6 +100,7 @@ impl Feature for UI {
             let event_flags = Arc::clone(&event_flags);
             let characters_modal = Arc::clone(&characters_modal);
             let debug_panel = Arc::clone(&debug_panel);
+            let injector_input = Arc::clone(&injector_input);
             let realm_modal = Arc::clone(&realm_modal);
 
             tokio::spawn(async move {
@@ -114,20 +119,32 @@ impl Feature for UI {
 
                                     event_flags.lock().unwrap().set(UIEventFlags::IS_EVENT_HANDLED, false);
 
-                                    let outputs: Vec<HandlerOutput> = vec![
-                                        characters_modal.lock().unwrap().handle_key_event(
-                                            modifiers, code, Arc::clone(&event_flags)
-                                        ),
-                                        realm_modal.lock().unwrap().handle_key_event(
-                                            modifiers, code, Arc::clone(&event_flags)
-                                        ),
-                                        debug_panel.lock().unwrap().handle_key_event(
-                                            modifiers, code, Arc::clone(&event_flags)
-                                        )
-                                    ].into_iter()
-                                        .filter(|item| item.is_some())
-                                        .flatten()
-                                        .collect();
+                                    let injector_output = injector_input.lock().unwrap().handle_key_event(
+                                        modifiers, code, Arc::clone(&event_flags)
+                                    );
+
+                                    let is_event_handled = {
+                                        event_flags.lock().unwrap().contains(UIEventFlags::IS_EVENT_HANDLED)
+                                    };
+
+                                    let outputs: Vec<HandlerOutput> = if is_event_handled {
+                                        injector_output.into_iter().collect()
+                                    } else {
+                                        vec![
+                                            characters_modal.lock().unwrap().handle_key_event(
+                                                modifiers, code, Arc::clone(&event_flags)
+                                            ),
+                                            realm_modal.lock().unwrap().handle_key_event(
+                                                modifiers, code, Arc::clone(&event_flags)
+                                            ),
+                                            debug_panel.lock().unwrap().handle_key_event(
+                                                modifiers, code, Arc::clone(&event_flags)
+                                            )
+                                        ].into_iter()
+                                            .filter(|item| item.is_some())
+                                            .flatten()
+                                            .collect()
+                                    };
 
                                     for output in outputs {
                                         sender.broadcast(output).await.unwrap();
@@ -207,6 +224,9 @@ impl Feature for UI {
                                 );
                                 realm_modal.lock().unwrap().set_items(realms);
                             },
+                            HandlerOutput::WorldEvent(WorldEvent::LocationChanged(_, location)) => {
+                                debug_panel.lock().unwrap().set_location(location);
+                            },
                             HandlerOutput::ExitConfirmed => {
                                 Self::handle_exit();
                             }
@@ -222,6 +242,7 @@ impl Feature for UI {
             let event_flags = Arc::clone(&event_flags);
             let characters_modal = Arc::clone(&characters_modal);
             let debug_panel = Arc::clone(&debug_panel);
+            let injector_input = Arc::clone(&injector_input);
             let realm_modal = Arc::clone(&realm_modal);
             let title = Arc::clone(&title);
 
@@ -253,6 +274,10 @@ impl Feature for UI {
                             realm_modal.lock().unwrap().render(frame, chunks[1]);
                         }
 
+                        if event_flags.lock().unwrap().contains(UIEventFlags::IS_INJECTOR_OPENED) {
+                            injector_input.lock().unwrap().render(frame, chunks[1]);
+                        }
+
                     }).unwrap();
 
                     sleep(Duration::from_millis(30)).await;
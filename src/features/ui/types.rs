@@ -17,5 +17,6 @@ bitflags! {
         const IS_REALM_MODAL_OPENED = 0x00000010;
         const IS_EVENT_HANDLED = 0x00000100;
         const IS_EXIT_REQUESTED = 0x00001000;
+        const IS_INJECTOR_OPENED = 0x00010000;
     }
 }
\ No newline at end of file
@@ -109,17 +109,49 @@ pub mod async_broadcast {
 }
 
 pub mod chat {
-    pub use crate::primary::client::chat::types::{Language, MessageType, TextEmoteType, EmoteType};
+    pub use crate::primary::client::chat::types::{
+        AddonChannel, ChatTag, Language, Message, MessageType, OutgoingMessage, TextEmoteType, EmoteType,
+    };
+    pub use crate::primary::client::chat::sender::{get_default_language, ChatSender};
 }
 
 pub mod movement {
-    pub use crate::primary::client::movement::types::{MovementFlags, MovementFlagsExtra};
+    pub use crate::primary::client::movement::types::{
+        FollowEvent, FollowStopReason, FollowTarget, JumpType, MovementFlags, MovementFlagsExtra, PatrolMode, Route,
+        RoutePoint, SplineFlags,
+    };
+    pub use crate::primary::client::{JumpInfo, MovementInfo};
+    pub use crate::primary::client::{MonsterMove, Spline, SplineFacing};
+    pub use crate::primary::client::movement::controller::{MovementController, HEARTBEAT_INTERVAL};
+    pub use crate::primary::client::movement::jump::{Jump, GRAVITY, JUMP_VELOCITY, TERMINAL_VELOCITY};
+    pub use crate::primary::client::movement::route::{
+        load_route, parse_command as parse_route_command, save_route, validate_name as validate_route_name,
+        RouteRecorder,
+    };
 }
 
 pub mod player {
     pub use crate::primary::client::{
         ObjectField, Player, PlayerField, Position, UnitField, Race, Class, Gender
     };
+    pub use crate::primary::client::player::types::{PlayerFlags, UnitFlags, UnitFlags2};
+    pub use crate::primary::client::player::views::{PlayerView, PowerType, Stat, UnitView};
+}
+
+pub mod navigation {
+    pub use crate::primary::navigation::{
+        MeshHeader, MeshTile, MmapParser, MmapTileHeader, NavMesh, NavMeshParams, Navigator, Poly, PolyDetail,
+    };
+}
+
+pub mod objects {
+    pub use crate::primary::shared::storage::objects::{
+        FieldChange, ObjectEvent, ObjectManager, ObjectType, WorldObject,
+    };
+    pub use crate::primary::shared::storage::fields::UpdateFieldStore;
+    pub use crate::primary::shared::storage::spatial::SpatialIndex;
+    pub use crate::primary::shared::storage::DataStorage;
+    pub use crate::primary::types::{HighGuid, ObjectGuid};
 }
 
 pub mod realm {
@@ -128,7 +160,7 @@ pub mod realm {
 
 pub mod packet {
     pub mod custom_fields {
-        pub use crate::primary::types::PackedGuid;
+        pub use crate::primary::types::{ObjectGuid, PackedGuid};
         pub use crate::primary::types::TerminatedString;
     }
 
@@ -137,7 +169,10 @@ pub mod packet {
     }
     
     pub mod movement {
-        pub use crate::primary::client::movement::packet::{MovementOutcome, MovementOpcodes};
+        pub use crate::primary::client::movement::packet::{
+            MovementAckOutcome, MovementInfoOutcome, MovementOutcome, MovementOpcodes, MovementValueAckOutcome,
+            TeleportAckOutcome, WorldportAckOutcome,
+        };
     }
 
     pub mod player {
@@ -147,6 +182,24 @@ pub mod packet {
     pub mod idewave {
         pub use idewave_packet::{WorldPacket, LoginPacket, FieldsSerializer};
     }
+
+    pub mod injector {
+        pub use crate::primary::injector::PacketInjector;
+    }
+
+    pub mod dissector {
+        pub use crate::primary::dissector::{Dissector, DissectorRegistry};
+    }
+
+    pub mod opcodes {
+        pub use crate::primary::client::{Opcode, OpcodeDirection, WorldOpcode};
+    }
+}
+
+pub mod terrain {
+    pub use crate::primary::terrain::{
+        GridLiquid, GridMap, HeightMap, LiquidFlags, LiquidInfo, MapParser, Terrain,
+    };
 }
 
 pub mod traits {
@@ -158,6 +211,10 @@ pub mod types {
     pub use crate::primary::types::{HandlerOutput, IncomingPacket, OutgoingPacket};
 }
 
+pub mod world {
+    pub use crate::primary::client::{InstanceBind, WorldEvent, WorldLocation};
+}
+
 pub mod errors {
-    pub use crate::primary::errors::{FieldError};
+    pub use crate::primary::errors::{FieldError, InjectorError, NavigationError, OpcodeError, RouteError, TerrainError};
 }
\ No newline at end of file
@@ -1,4 +1,3 @@
-use std::io::BufRead;
 use sha1::{Sha1};
 use async_trait::async_trait;
 use serde::{Serialize, Deserialize};
@@ -19,28 +18,14 @@ with_opcode! {
         #[serde(serialize_with = "crate::primary::serializers::array_serializer::serialize_array")]
         server_ephemeral: [u8; 32],
         g_len: u8,
-        #[dynamic_field]
+        #[length_from = "g_len"]
         g: Vec<u8>,
         n_len: u8,
-        #[dynamic_field]
+        #[length_from = "n_len"]
         n: Vec<u8>,
         #[serde(serialize_with = "crate::primary::serializers::array_serializer::serialize_array")]
         salt: [u8; 32],
     }
-
-    impl Income {
-        fn g<R: BufRead>(mut reader: R, initial: &mut Self) -> Vec<u8> {
-            let mut buffer = vec![0u8; initial.g_len as usize];
-            reader.read_exact(&mut buffer).unwrap();
-            buffer
-        }
-
-        fn n<R: BufRead>(mut reader: R, initial: &mut Self) -> Vec<u8> {
-            let mut buffer = vec![0u8; initial.n_len as usize];
-            reader.read_exact(&mut buffer).unwrap();
-            buffer
-        }
-    }
 }
 
 with_opcode! {
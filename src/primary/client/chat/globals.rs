@@ -1,6 +1,9 @@
+use anyhow::{Result as AnyResult};
+
 use crate::primary::macros::with_opcode;
-use crate::primary::client::Opcode;
-use crate::primary::types::{TerminatedString};
+use crate::primary::client::{Message, MessageType, Opcode};
+use crate::primary::client::chat::types::ChatTag;
+use crate::primary::types::{ObjectGuid, TerminatedString};
 
 with_opcode! {
     @world_opcode(Opcode::CMSG_JOIN_CHANNEL)
@@ -11,4 +14,173 @@ with_opcode! {
         pub unknown1: u8,
         pub channel_name: TerminatedString,
     }
+}
+
+// SMSG_MESSAGECHAT, layout after the flags depends on message type
+#[derive(WorldPacket, Serialize, Deserialize, Debug, Default)]
+#[options(no_opcode)]
+pub struct MessageChatIncome {
+    pub message_type: u8,
+    pub language: u32,
+    pub sender_guid: ObjectGuid,
+    #[skip(4)]
+    #[if_expr = "MessageType::has_sender_name(message_type)"]
+    pub sender_name_length: u32,
+    #[if_expr = "MessageType::has_sender_name(message_type)"]
+    #[length_from = "sender_name_length"]
+    pub sender_name: TerminatedString,
+    #[if_expr = "message_type == MessageType::CHANNEL"]
+    pub channel_name: TerminatedString,
+    pub target_guid: ObjectGuid,
+    #[if_expr = "MessageType::has_target_name(message_type, target_guid)"]
+    pub target_name_length: u32,
+    #[if_expr = "MessageType::has_target_name(message_type, target_guid)"]
+    #[length_from = "target_name_length"]
+    pub target_name: TerminatedString,
+    pub message_length: u32,
+    #[length_from = "message_length"]
+    pub message: TerminatedString,
+    pub chat_tag: u8,
+    #[if_expr = "MessageType::is_achievement(message_type)"]
+    pub achievement_id: u32,
+}
+
+// SMSG_GM_MESSAGECHAT, same as above but player messages also contain sender name
+#[derive(WorldPacket, Serialize, Deserialize, Debug, Default)]
+#[options(no_opcode)]
+pub struct GmMessageChatIncome {
+    pub message_type: u8,
+    pub language: u32,
+    pub sender_guid: ObjectGuid,
+    #[skip(4)]
+    #[if_expr = "!MessageType::is_bg_system(message_type) && !MessageType::is_achievement(message_type)"]
+    pub sender_name_length: u32,
+    #[if_expr = "!MessageType::is_bg_system(message_type) && !MessageType::is_achievement(message_type)"]
+    #[length_from = "sender_name_length"]
+    pub sender_name: TerminatedString,
+    #[if_expr = "message_type == MessageType::CHANNEL"]
+    pub channel_name: TerminatedString,
+    pub target_guid: ObjectGuid,
+    #[if_expr = "MessageType::has_target_name(message_type, target_guid)"]
+    pub target_name_length: u32,
+    #[if_expr = "MessageType::has_target_name(message_type, target_guid)"]
+    #[length_from = "target_name_length"]
+    pub target_name: TerminatedString,
+    pub message_length: u32,
+    #[length_from = "message_length"]
+    pub message: TerminatedString,
+    pub chat_tag: u8,
+    #[if_expr = "MessageType::is_achievement(message_type)"]
+    pub achievement_id: u32,
+}
+
+macro_rules! impl_into_message {
+    ($($income:ty),*) => {
+        $(
+            impl From<$income> for Message {
+                fn from(income: $income) -> Self {
+                    Self {
+                        message_type: income.message_type,
+                        language: income.language,
+                        sender_guid: income.sender_guid,
+                        sender_name: income.sender_name.to_string(),
+                        channel_name: income.channel_name.to_string(),
+                        target_guid: income.target_guid,
+                        target_name: income.target_name.to_string(),
+                        text: income.message.to_string(),
+                        chat_tag: ChatTag::from_bits_truncate(income.chat_tag),
+                        achievement_id: MessageType::is_achievement(income.message_type)
+                            .then_some(income.achievement_id),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_into_message!(MessageChatIncome, GmMessageChatIncome);
+
+// parses both chat opcodes, returns message and json details of the packet
+pub fn parse_chat_message(opcode: u16, data: &[u8]) -> AnyResult<(Message, String)> {
+    match opcode {
+        Opcode::SMSG_GM_MESSAGECHAT => {
+            let (income, json) = GmMessageChatIncome::from_binary(data)?;
+            Ok((income.into(), json))
+        },
+        _ => {
+            let (income, json) = MessageChatIncome::from_binary(data)?;
+            Ok((income.into(), json))
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primary::client::chat::globals::parse_chat_message;
+    use crate::primary::client::chat::types::{ChatTag, MessageType};
+    use crate::primary::client::Opcode;
+    use crate::primary::types::{HighGuid, ObjectGuid};
+
+    fn build(message_type: u8, sender_guid: ObjectGuid, variable: &[u8], text: &str, tail: &[u8]) -> Vec<u8> {
+        let mut data = vec![message_type];
+        data.extend_from_slice(&7u32.to_le_bytes());
+        data.extend_from_slice(&sender_guid.raw().to_le_bytes());
+        data.extend_from_slice(&[0; 4]);
+        data.extend_from_slice(variable);
+        data.extend_from_slice(&(text.len() as u32 + 1).to_le_bytes());
+        data.extend_from_slice(text.as_bytes());
+        data.push(0);
+        data.extend_from_slice(tail);
+        data
+    }
+
+    #[test]
+    fn test_parse_chat_message() {
+        let player = ObjectGuid::create(HighGuid::Player, 0, 5);
+        let creature = ObjectGuid::create(HighGuid::Unit, 1234, 10);
+
+        // monster yell to a non-player target
+        let mut variable = vec![];
+        variable.extend_from_slice(&5u32.to_le_bytes());
+        variable.extend_from_slice(b"Boss\0");
+        variable.extend_from_slice(&creature.raw().to_le_bytes());
+        variable.extend_from_slice(&5u32.to_le_bytes());
+        variable.extend_from_slice(b"Adds\0");
+        let data = build(MessageType::MONSTER_YELL, creature, &variable, "Die!", &[0]);
+
+        let (message, _) = parse_chat_message(Opcode::SMSG_MESSAGECHAT, &data).unwrap();
+        assert_eq!(message.sender_name, "Boss");
+        assert_eq!(message.target_name, "Adds");
+        assert_eq!(message.text, "Die!");
+        assert_eq!(message.achievement_id, None);
+
+        // channel message from an AFK player
+        let mut variable = b"world\0".to_vec();
+        variable.extend_from_slice(&0u64.to_le_bytes());
+        let data = build(MessageType::CHANNEL, player, &variable, "hi", &[1]);
+
+        let (message, _) = parse_chat_message(Opcode::SMSG_MESSAGECHAT, &data).unwrap();
+        assert_eq!(message.channel_name, "world");
+        assert_eq!(message.text, "hi");
+        assert_eq!(message.chat_tag, ChatTag::AFK);
+
+        // achievement
+        let data = build(MessageType::ACHIEVEMENT, player, &player.raw().to_le_bytes(), "%s", &[0, 6, 0, 0, 0]);
+
+        let (message, _) = parse_chat_message(Opcode::SMSG_MESSAGECHAT, &data).unwrap();
+        assert_eq!(message.target_guid, player);
+        assert_eq!(message.achievement_id, Some(6));
+
+        // whisper from GM contains sender name
+        let mut variable = vec![];
+        variable.extend_from_slice(&3u32.to_le_bytes());
+        variable.extend_from_slice(b"Gm\0");
+        variable.extend_from_slice(&0u64.to_le_bytes());
+        let data = build(MessageType::WHISPER, player, &variable, "hello", &[4]);
+
+        let (message, _) = parse_chat_message(Opcode::SMSG_GM_MESSAGECHAT, &data).unwrap();
+        assert_eq!(message.sender_name, "Gm");
+        assert_eq!(message.text, "hello");
+        assert_eq!(message.chat_tag, ChatTag::GM);
+    }
 }
\ No newline at end of file
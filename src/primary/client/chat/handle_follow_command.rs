@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+
+use crate::primary::client::chat::globals::parse_chat_message;
+use crate::primary::client::chat::types::MessageType;
+use crate::primary::client::movement::types::FollowTarget;
+use crate::primary::types::{HandlerInput, HandlerOutput, HandlerResult};
+use crate::primary::traits::packet_handler::PacketHandler;
+
+const FOLLOW_COMMAND: &str = "follow me";
+const STOP_FOLLOW_COMMAND: &str = "stop follow";
+
+// reacts on whispered commands, so the character can be controlled from the game
+pub struct Handler;
+#[async_trait]
+impl PacketHandler for Handler {
+    async fn handle(&mut self, input: &mut HandlerInput) -> HandlerResult {
+        let mut response = Vec::new();
+
+        // broken message is already reported by the chat logger
+        let Ok((message, _)) = parse_chat_message(input.opcode, &input.data) else {
+            return Ok(response);
+        };
+        let sender_guid = message.sender_guid;
+
+        if message.message_type != MessageType::WHISPER {
+            return Ok(response);
+        }
+
+        let command = message.text.trim().to_lowercase();
+        if command == FOLLOW_COMMAND {
+            response.push(HandlerOutput::Follow(FollowTarget::Guid(sender_guid)));
+        } else if command == STOP_FOLLOW_COMMAND {
+            let follow_target = input.session.lock().await.follow_target;
+            if follow_target == Some(sender_guid) {
+                response.push(HandlerOutput::StopFollow);
+            }
+        }
+
+        Ok(response)
+    }
+}
\ No newline at end of file
@@ -1,6 +1,8 @@
 pub mod globals;
+mod handle_follow_command;
 mod log_chat_message;
 mod query_unknown_player;
+pub mod sender;
 pub mod types;
 
 use crate::primary::client::opcodes::Opcode;
@@ -12,15 +14,13 @@ pub struct ChatProcessor;
 impl Processor for ChatProcessor {
     fn get_handlers(input: &mut HandlerInput) -> ProcessorResult {
         let handlers: ProcessorResult = match input.opcode {
-            Opcode::SMSG_MESSAGECHAT => {
+            Opcode::SMSG_MESSAGECHAT | Opcode::SMSG_GM_MESSAGECHAT => {
                 vec![
                     Box::new(query_unknown_player::Handler),
                     Box::new(log_chat_message::Handler),
+                    Box::new(handle_follow_command::Handler),
                 ]
             },
-            Opcode::SMSG_TEXT_EMOTE => {
-                vec![]
-            },
             _ => vec![]
         };
 
@@ -29,9 +29,9 @@ impl Processor for ChatProcessor {
 }
 
 pub mod packet {
-    use crate::primary::client::Opcode;
+    use crate::primary::client::{MessageType, Opcode};
     use crate::primary::macros::with_opcode;
-    use crate::primary::types::TerminatedString;
+    use crate::primary::types::{ObjectGuid, TerminatedString};
 
     with_opcode! {
         @world_opcode(Opcode::CMSG_MESSAGECHAT)
@@ -39,6 +39,10 @@ pub mod packet {
         pub struct ChatOutcome {
             pub message_type: u32,
             pub language: u32,
+            // whisper receiver or channel name
+            #[serde(default)]
+            #[if_expr = "message_type == MessageType::WHISPER as u32 || message_type == MessageType::CHANNEL as u32"]
+            pub target: TerminatedString,
             pub message: TerminatedString,
         }
     }
@@ -57,7 +61,7 @@ pub mod packet {
         pub struct TextEmoteOutcome {
             pub text_emote_type: u32,
             pub emote_num: u32,
-            pub guid: u64,
+            pub guid: ObjectGuid,
         }
     }
 }
\ No newline at end of file
@@ -2,14 +2,14 @@ use async_trait::async_trait;
 
 use crate::primary::client::Opcode;
 use crate::primary::client::player::globals::NameQueryOutcome;
-use crate::primary::types::{HandlerInput, HandlerOutput, HandlerResult};
+use crate::primary::types::{HandlerInput, HandlerOutput, HandlerResult, ObjectGuid};
 use crate::primary::traits::packet_handler::PacketHandler;
 
 #[derive(WorldPacket, Serialize, Deserialize, Debug)]
 #[options(no_opcode)]
 struct Income {
     skip: [u8; 5],
-    sender_guid: u64,
+    sender_guid: ObjectGuid,
 }
 
 pub struct Handler;
@@ -26,11 +26,12 @@ impl PacketHandler for Handler {
             Some(json),
         ));
 
-        let players_map = &mut input.data_storage.lock().unwrap().players_map;
-        if players_map.get(&sender_guid).is_none() {
+        let mut guard = input.data_storage.lock().unwrap();
+        if sender_guid.is_player()
+            && !guard.players_map.contains_key(&sender_guid)
+            && guard.add_name_query(sender_guid)
+        {
             response.push(HandlerOutput::Data(NameQueryOutcome { guid: sender_guid }.unpack()?));
-
-            return Ok(response);
         }
 
         Ok(response)
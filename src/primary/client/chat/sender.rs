@@ -0,0 +1,115 @@
+use anyhow::{Result as AnyResult};
+
+use crate::primary::client::chat::packet::ChatOutcome;
+use crate::primary::client::chat::types::{AddonChannel, Language, MessageType, OutgoingMessage};
+use crate::primary::client::player::types::Race;
+use crate::primary::types::OutgoingPacket;
+
+// language used by characters of given race when no other was chosen
+pub fn get_default_language(race: u8) -> u32 {
+    match race {
+        Race::ORC | Race::UNDEAD | Race::TAUREN | Race::TROLL | Race::GOBLIN | Race::BLOODELF => Language::ORCISH,
+        _ => Language::COMMON,
+    }
+}
+
+pub struct ChatSender {
+    language: u32,
+}
+
+impl ChatSender {
+    pub fn new(language: u32) -> Self {
+        Self { language }
+    }
+
+    pub fn for_race(race: u8) -> Self {
+        Self::new(get_default_language(race))
+    }
+
+    pub fn build(&self, message: OutgoingMessage) -> ChatOutcome {
+        let (message_type, language, target, text) = match message {
+            OutgoingMessage::Say(text) => (MessageType::SAY, self.language, None, text),
+            OutgoingMessage::Yell(text) => (MessageType::YELL, self.language, None, text),
+            OutgoingMessage::Emote(text) => (MessageType::EMOTE, self.language, None, text),
+            OutgoingMessage::Party(text) => (MessageType::PARTY, self.language, None, text),
+            OutgoingMessage::Raid(text) => (MessageType::RAID, self.language, None, text),
+            OutgoingMessage::RaidWarning(text) => (MessageType::RAID_WARNING, self.language, None, text),
+            OutgoingMessage::Guild(text) => (MessageType::GUILD, self.language, None, text),
+            OutgoingMessage::Officer(text) => (MessageType::OFFICER, self.language, None, text),
+            OutgoingMessage::Whisper(target, text) => {
+                (MessageType::WHISPER, self.language, Some(target), text)
+            },
+            OutgoingMessage::Channel(channel, text) => {
+                (MessageType::CHANNEL, self.language, Some(channel), text)
+            },
+            OutgoingMessage::Afk(text) => (MessageType::AFK, Language::UNIVERSAL, None, text),
+            OutgoingMessage::Dnd(text) => (MessageType::DND, Language::UNIVERSAL, None, text),
+            OutgoingMessage::Addon(channel, prefix, text) => {
+                let (message_type, target) = match channel {
+                    AddonChannel::Party => (MessageType::PARTY, None),
+                    AddonChannel::Raid => (MessageType::RAID, None),
+                    AddonChannel::Guild => (MessageType::GUILD, None),
+                    AddonChannel::Battleground => (MessageType::BATTLEGROUND, None),
+                    AddonChannel::Whisper(target) => (MessageType::WHISPER, Some(target)),
+                };
+
+                // server splits prefix and text by tab
+                (message_type, Language::ADDON, target, format!("{}\t{}", prefix, text))
+            },
+        };
+
+        ChatOutcome {
+            message_type: message_type as u32,
+            language,
+            target: target.unwrap_or_default().into(),
+            message: text.into(),
+        }
+    }
+
+    pub fn send(&self, message: OutgoingMessage) -> AnyResult<OutgoingPacket> {
+        self.build(message).unpack()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primary::client::chat::sender::ChatSender;
+    use crate::primary::client::chat::types::{AddonChannel, Language, MessageType, OutgoingMessage};
+
+    // outgoing packet starts with u16 size and u32 opcode
+    const HEADER_SIZE: usize = 6;
+
+    fn body(sender: &ChatSender, message: OutgoingMessage) -> Vec<u8> {
+        sender.send(message).unwrap().data[HEADER_SIZE..].to_vec()
+    }
+
+    #[test]
+    fn test_message_layouts() {
+        let sender = ChatSender::new(Language::COMMON);
+
+        let mut expected = vec![MessageType::SAY, 0, 0, 0, 7, 0, 0, 0];
+        expected.extend_from_slice(b"hi\0");
+        assert_eq!(body(&sender, OutgoingMessage::Say("hi".to_string())), expected);
+
+        let mut expected = vec![MessageType::WHISPER, 0, 0, 0, 7, 0, 0, 0];
+        expected.extend_from_slice(b"Bob\0hi\0");
+        assert_eq!(
+            body(&sender, OutgoingMessage::Whisper("Bob".to_string(), "hi".to_string())),
+            expected,
+        );
+
+        let mut expected = vec![MessageType::CHANNEL, 0, 0, 0, 7, 0, 0, 0];
+        expected.extend_from_slice(b"world\0hi\0");
+        assert_eq!(
+            body(&sender, OutgoingMessage::Channel("world".to_string(), "hi".to_string())),
+            expected,
+        );
+
+        let mut expected = vec![MessageType::GUILD, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF];
+        expected.extend_from_slice(b"PREFIX\tdata\0");
+        assert_eq!(
+            body(&sender, OutgoingMessage::Addon(AddonChannel::Guild, "PREFIX".to_string(), "data".to_string())),
+            expected,
+        );
+    }
+}
\ No newline at end of file
@@ -1,11 +1,33 @@
+use bitflags::bitflags;
+
+use crate::primary::types::{HighGuid, ObjectGuid};
+
+bitflags! {
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct ChatTag: u8 {
+        const NONE = 0x00;
+        const AFK = 0x01;
+        const DND = 0x02;
+        const GM = 0x04;
+        const COMMENTATOR = 0x08;
+        const DEVELOPER = 0x10;
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct Message {
     pub message_type: u8,
     pub language: u32,
-    pub sender_guid: u64,
+    pub sender_guid: ObjectGuid,
+    // filled for monster, foreign whisper and GM messages
+    pub sender_name: String,
     pub channel_name: String,
-    pub target_guid: u64,
+    pub target_guid: ObjectGuid,
+    // filled only when target is not a player
+    pub target_name: String,
     pub text: String,
+    pub chat_tag: ChatTag,
+    pub achievement_id: Option<u32>,
 }
 
 #[non_exhaustive]
@@ -43,6 +65,97 @@ impl MessageType {
     pub const LOOT: u8 = 0x1B;
     pub const MONEY: u8 = 0x1C;
     pub const OPENING: u8 = 0x1D;
+    pub const TRADESKILLS: u8 = 0x1E;
+    pub const PET_INFO: u8 = 0x1F;
+    pub const COMBAT_MISC_INFO: u8 = 0x20;
+    pub const COMBAT_XP_GAIN: u8 = 0x21;
+    pub const COMBAT_HONOR_GAIN: u8 = 0x22;
+    pub const COMBAT_FACTION_CHANGE: u8 = 0x23;
+    pub const BG_SYSTEM_NEUTRAL: u8 = 0x24;
+    pub const BG_SYSTEM_ALLIANCE: u8 = 0x25;
+    pub const BG_SYSTEM_HORDE: u8 = 0x26;
+    pub const RAID_LEADER: u8 = 0x27;
+    pub const RAID_WARNING: u8 = 0x28;
+    pub const RAID_BOSS_EMOTE: u8 = 0x29;
+    pub const RAID_BOSS_WHISPER: u8 = 0x2A;
+    pub const FILTERED: u8 = 0x2B;
+    pub const BATTLEGROUND: u8 = 0x2C;
+    pub const BATTLEGROUND_LEADER: u8 = 0x2D;
+    pub const RESTRICTED: u8 = 0x2E;
+    pub const BATTLENET: u8 = 0x2F;
+    pub const ACHIEVEMENT: u8 = 0x30;
+    pub const GUILD_ACHIEVEMENT: u8 = 0x31;
+    pub const ARENA_POINTS: u8 = 0x32;
+    pub const PARTY_LEADER: u8 = 0x33;
+
+    // sender name and target guid follow the sender guid instead of the default layout
+    pub fn has_sender_name(message_type: u8) -> bool {
+        matches!(
+            message_type,
+            Self::MONSTER_SAY | Self::MONSTER_PARTY | Self::MONSTER_YELL | Self::MONSTER_WHISPER
+            | Self::MONSTER_EMOTE | Self::RAID_BOSS_EMOTE | Self::RAID_BOSS_WHISPER | Self::BATTLENET
+            | Self::WHISPER_FOREIGN
+        )
+    }
+
+    pub fn is_bg_system(message_type: u8) -> bool {
+        matches!(message_type, Self::BG_SYSTEM_NEUTRAL | Self::BG_SYSTEM_ALLIANCE | Self::BG_SYSTEM_HORDE)
+    }
+
+    pub fn is_achievement(message_type: u8) -> bool {
+        matches!(message_type, Self::ACHIEVEMENT | Self::GUILD_ACHIEVEMENT)
+    }
+
+    // player and player-like messages (say, whisper, channel etc)
+    pub fn has_default_layout(message_type: u8) -> bool {
+        !Self::has_sender_name(message_type)
+            && !Self::is_bg_system(message_type)
+            && !Self::is_achievement(message_type)
+    }
+
+    // target name is sent only for non-player targets
+    pub fn has_target_name(message_type: u8, target_guid: ObjectGuid) -> bool {
+        if target_guid.is_empty() || target_guid.is_player() {
+            return false;
+        }
+
+        match message_type {
+            Self::WHISPER_FOREIGN => false,
+            _ if Self::has_sender_name(message_type) => target_guid.high() != HighGuid::Pet,
+            _ => Self::is_bg_system(message_type),
+        }
+    }
+}
+
+// destination of addon message, addon text is not visible in chat frames
+#[derive(Clone, Debug)]
+pub enum AddonChannel {
+    Party,
+    Raid,
+    Guild,
+    Battleground,
+    Whisper(String),
+}
+
+#[derive(Clone, Debug)]
+pub enum OutgoingMessage {
+    Say(String),
+    Yell(String),
+    Emote(String),
+    Party(String),
+    Raid(String),
+    RaidWarning(String),
+    Guild(String),
+    Officer(String),
+    // target name, text
+    Whisper(String, String),
+    // channel name, text
+    Channel(String, String),
+    // empty text toggles the status off
+    Afk(String),
+    Dnd(String),
+    // channel, prefix, text
+    Addon(AddonChannel, String, String),
 }
 
 #[non_exhaustive]
@@ -432,4 +545,5 @@ impl Language {
     pub const ZOMBIE: u32 = 36;
     pub const GNOMISH_BINARY: u32 = 37;
     pub const GOBLIN_BINARY: u32 = 38;
+    pub const ADDON: u32 = 0xFFFFFFFF;
 }
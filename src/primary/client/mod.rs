@@ -1,6 +1,7 @@
 #![allow(clippy::new_without_default)]
 
 use std::io::{Error, ErrorKind};
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex as SyncMutex};
 use std::time::Duration;
 use tokio::sync::{Mutex, Notify};
@@ -17,16 +18,19 @@ pub mod chat;
 pub mod movement;
 mod opcodes;
 pub mod player;
-mod realm;
+pub mod realm;
 mod spell;
 mod trade;
 pub mod types;
 mod warden;
+pub mod world;
 
 #[allow(unused_imports)]
-pub use chat::types::{Language, MessageType, EmoteType, TextEmoteType, Message};
+pub use chat::types::{AddonChannel, ChatTag, Language, MessageType, EmoteType, TextEmoteType, Message, OutgoingMessage};
 pub use movement::types::{MovementFlags, MovementFlagsExtra, SplineFlags, UnitMoveType};
+pub use crate::primary::parsers::movement_parser::types::{JumpInfo, MovementInfo};
 pub use crate::primary::parsers::position_parser::types::Position;
+pub use crate::primary::parsers::spline_parser::types::{MonsterMove, Spline, SplineFacing};
 pub use player::types::{
     Player, ObjectField, UnitField, PlayerField, FieldType, FieldValue,
     Race, Class, Gender,
@@ -34,23 +38,34 @@ pub use player::types::{
 pub use realm::types::{Realm};
 pub use spell::types::{Spell, CooldownInfo};
 pub use warden::types::{WardenModuleInfo};
+pub use world::types::{InstanceBind, WorldEvent, WorldLocation};
 
 use auth::AuthProcessor;
 use chat::ChatProcessor;
+use chat::sender::ChatSender;
 use movement::MovementProcessor;
+use movement::follow;
+use movement::jump;
+use movement::route::{self, RouteRecorder};
+use movement::types::Route;
+use movement::types::FollowStopReason;
 use player::PlayerProcessor;
 use realm::RealmProcessor;
 use spell::SpellProcessor;
 use warden::WardenProcessor;
+use world::WorldProcessor;
 
 // TODO: REMOVE THIS ! (need to think how better refactor this part)
 use auth::login_challenge;
 
-pub use crate::primary::client::opcodes::Opcode;
+pub use crate::primary::client::opcodes::{Opcode, OpcodeDirection, WorldOpcode};
 use crate::primary::client::realm::packet::LogoutOutcome;
 use crate::primary::client::types::{ClientFlags};
 use crate::primary::config::{EnvConfig, EnvConfigParams};
 use crate::primary::crypto::warden_crypt::WardenCrypt;
+use crate::primary::dissector::DissectorRegistry;
+use crate::primary::navigation::Navigator;
+use crate::primary::terrain::Terrain;
 use crate::primary::shared::storage::DataStorage;
 use crate::primary::shared::session::Session;
 use crate::primary::network::stream::{Reader, Writer};
@@ -236,17 +251,15 @@ impl Client {
                     result = Self::read_packet(&reader) => {
                         match result {
                             Ok(packet) => {
-                                let processors = {
-                                    let connected_to_realm = {
-                                        client_flags.lock().unwrap().contains(
-                                            ClientFlags::IS_CONNECTED_TO_REALM
-                                        )
-                                    };
+                                let connected_to_realm = {
+                                    client_flags.lock().unwrap().contains(
+                                        ClientFlags::IS_CONNECTED_TO_REALM
+                                    )
+                                };
 
-                                    match connected_to_realm {
-                                        true => Self::get_realm_processors(),
-                                        false => Self::get_login_processors(),
-                                    }
+                                let processors = match connected_to_realm {
+                                    true => Self::get_realm_processors(),
+                                    false => Self::get_login_processors(),
                                 };
 
                                 let IncomingPacket { opcode, body: data, .. } = packet.clone();
@@ -268,9 +281,20 @@ impl Client {
                                         packet.opcode as u32
                                     ).unwrap_or(format!("Unknown opcode: {}", input.opcode));
 
+                                    // known packets without handler still can be shown as json
+                                    let dissected = match connected_to_realm {
+                                        true => DissectorRegistry::dissect(packet.opcode, &packet.body),
+                                        false => None,
+                                    };
+
+                                    let details = match dissected {
+                                        Some(Ok(details)) => details,
+                                        _ => encode_hex(&packet.body),
+                                    };
+
                                     query_sender.broadcast(HandlerOutput::ResponseMessage(
                                         opcode_name,
-                                        Some(encode_hex(&packet.body)),
+                                        Some(details),
                                     )).await.unwrap();
                                 }
 
@@ -308,6 +332,12 @@ impl Client {
         })
     }
 
+    async fn get_routes_path(session: &Arc<Mutex<Session>>) -> String {
+        session.lock().await.get_config()
+            .map(|config| config.common.routes_path.clone())
+            .unwrap_or(route::DEFAULT_ROUTES_PATH.to_string())
+    }
+
     fn handle_output(
         &mut self,
         signal_sender: Sender<Signal>,
@@ -317,12 +347,32 @@ impl Client {
         notify: Arc<Notify>,
     ) -> JoinHandle<()> {
         let session = Arc::clone(&self.session);
+        let data_storage = Arc::clone(&self.data_storage);
         let reader = Arc::clone(&self._reader);
         let writer = Arc::clone(&self._writer);
         let warden_crypt = Arc::clone(&self._warden_crypt);
         let client_flags = Arc::clone(&self._flags);
 
         tokio::spawn(async move {
+            let mut movement_task: Option<JoinHandle<()>> = None;
+            let mut follow_task: Option<JoinHandle<()>> = None;
+            // shared with navigation tasks, which read tiles and grids in blocking threads
+            let (navigator, terrain) = {
+                let guard = session.lock().await;
+                let config = guard.get_config().ok();
+
+                (
+                    Arc::new(SyncMutex::new(Navigator::new(
+                        config.and_then(|config| config.common.mmaps_path.as_ref()).map(PathBuf::from)
+                    ))),
+                    Arc::new(SyncMutex::new(Terrain::new(
+                        config.and_then(|config| config.common.maps_path.as_ref()).map(PathBuf::from)
+                    ))),
+                )
+            };
+            let mut patrol_task: Option<JoinHandle<()>> = None;
+            let mut recorder: Option<RouteRecorder> = None;
+
             loop {
                 let result = query_receiver.recv().await;
                 match result {
@@ -401,6 +451,240 @@ impl Client {
                                 session.lock().await.me = Some(character);
                                 notify.notify_one();
                             },
+                            HandlerOutput::MoveTo(waypoints) => {
+                                for task in [patrol_task.take(), movement_task.take()].into_iter().flatten() {
+                                    movement::controller::abort_task(task, &session, &query_sender).await;
+                                }
+
+                                if let Some(task) = follow_task.take() {
+                                    movement::controller::abort_task(task, &session, &query_sender).await;
+                                    follow::stop_following(
+                                        &session, &query_sender, FollowStopReason::Requested,
+                                    ).await;
+                                }
+
+                                movement_task = Some(tokio::spawn(movement::controller::move_to(
+                                    Arc::clone(&session), query_sender.clone(), waypoints,
+                                )));
+                            },
+                            HandlerOutput::StopMovement => {
+                                // patrol runs its legs inline, so it has to be stopped as well
+                                for task in [patrol_task.take(), movement_task.take()].into_iter().flatten() {
+                                    movement::controller::abort_task(task, &session, &query_sender).await;
+                                }
+
+                                // follow controller would keep moving from the outdated position
+                                if let Some(task) = follow_task.take() {
+                                    movement::controller::abort_task(task, &session, &query_sender).await;
+                                    follow::stop_following(
+                                        &session, &query_sender, FollowStopReason::Interrupted,
+                                    ).await;
+                                }
+
+                                movement::controller::stop(&session, &query_sender).await;
+                            },
+                            HandlerOutput::NavigateTo(destination) => {
+                                // search is stored as movement, so new command or stop cancels it
+                                if let Some(task) = movement_task.take() {
+                                    movement::controller::abort_task(task, &session, &query_sender).await;
+                                }
+
+                                movement_task = Some(tokio::spawn(movement::navigate::navigate(
+                                    Arc::clone(&session), Arc::clone(&navigator), Arc::clone(&terrain),
+                                    query_sender.clone(), destination,
+                                )));
+                            },
+                            HandlerOutput::Follow(target) => {
+                                let Some(target) = follow::resolve_target(&data_storage, &target) else {
+                                    query_sender.broadcast(HandlerOutput::ErrorMessage(
+                                        format!("Cannot follow: {:?} not found", target), None,
+                                    )).await.unwrap();
+                                    continue;
+                                };
+
+                                for task in [patrol_task.take(), movement_task.take()].into_iter().flatten() {
+                                    movement::controller::abort_task(task, &session, &query_sender).await;
+                                }
+
+                                if let Some(task) = follow_task.take() {
+                                    movement::controller::abort_task(task, &session, &query_sender).await;
+                                    follow::stop_following(
+                                        &session, &query_sender, FollowStopReason::Requested,
+                                    ).await;
+                                }
+
+                                follow_task = Some(tokio::spawn(follow::follow(
+                                    Arc::clone(&session), Arc::clone(&data_storage), query_sender.clone(), target,
+                                )));
+                            },
+                            HandlerOutput::StopFollow => {
+                                if let Some(task) = follow_task.take() {
+                                    movement::controller::abort_task(task, &session, &query_sender).await;
+                                }
+
+                                follow::stop_following(&session, &query_sender, FollowStopReason::Requested).await;
+                            },
+                            HandlerOutput::Jump(jump_type) => {
+                                let Some((guid, map_id, jump)) = jump::prepare_jump(&session, jump_type).await else {
+                                    query_sender.broadcast(HandlerOutput::ErrorMessage(
+                                        "Cannot jump: current position is unknown".to_string(), None,
+                                    )).await.unwrap();
+                                    continue;
+                                };
+
+                                for task in [patrol_task.take(), movement_task.take()].into_iter().flatten() {
+                                    movement::controller::abort_task(task, &session, &query_sender).await;
+                                }
+
+                                if let Some(task) = follow_task.take() {
+                                    movement::controller::abort_task(task, &session, &query_sender).await;
+                                    follow::stop_following(
+                                        &session, &query_sender, FollowStopReason::Requested,
+                                    ).await;
+                                }
+
+                                // grid can be read from disk, so landing is found inside of the task
+                                let (session, query_sender, terrain) = (
+                                    Arc::clone(&session), query_sender.clone(), Arc::clone(&terrain),
+                                );
+                                movement_task = Some(tokio::spawn(async move {
+                                    let jump = jump::land_on_terrain(terrain, map_id, jump).await;
+                                    jump::perform(session, query_sender, guid, jump).await;
+                                }));
+                            },
+                            HandlerOutput::RecordRoute(name, source) => {
+                                // otherwise invalid name is reported only on save
+                                if let Err(err) = route::validate_name(&name) {
+                                    query_sender.broadcast(
+                                        HandlerOutput::ErrorMessage(err.to_string(), None)
+                                    ).await.unwrap();
+                                    continue;
+                                }
+
+                                let source = match source {
+                                    Some(target) => match follow::resolve_target(&data_storage, &target) {
+                                        Some(guid) => Some(guid),
+                                        None => {
+                                            query_sender.broadcast(HandlerOutput::ErrorMessage(
+                                                format!("Cannot record route: {:?} not found", target), None,
+                                            )).await.unwrap();
+                                            continue;
+                                        },
+                                    },
+                                    None => None,
+                                };
+
+                                let (map_id, interval) = {
+                                    let guard = session.lock().await;
+                                    (
+                                        guard.me.as_ref().map(|me| me.map_id).unwrap_or_default(),
+                                        guard.get_config()
+                                            .map(|config| Duration::from_millis(config.common.route_record_interval))
+                                            .unwrap_or(route::DEFAULT_RECORD_INTERVAL),
+                                    )
+                                };
+
+                                if let Some(previous) = recorder.take() {
+                                    previous.finish();
+                                }
+
+                                recorder = Some(RouteRecorder::start(
+                                    Arc::clone(&session),
+                                    Arc::clone(&data_storage),
+                                    Route::new(&name, map_id),
+                                    source,
+                                    interval,
+                                ));
+
+                                query_sender.broadcast(HandlerOutput::SuccessMessage(
+                                    format!("Recording route '{}'", name), None,
+                                )).await.unwrap();
+                            },
+                            HandlerOutput::MarkRoutePoint(pause) => {
+                                let is_marked = match recorder.as_ref() {
+                                    Some(recorder) => recorder.mark(&session, &data_storage, pause).await,
+                                    None => false,
+                                };
+
+                                let output = match is_marked {
+                                    true => HandlerOutput::DebugMessage(
+                                        format!("Route point marked with {}ms pause", pause), None,
+                                    ),
+                                    false => HandlerOutput::ErrorMessage(
+                                        "Cannot mark route point: no position recorded".to_string(), None,
+                                    ),
+                                };
+
+                                query_sender.broadcast(output).await.unwrap();
+                            },
+                            HandlerOutput::StopRecording => {
+                                let Some(current) = recorder.take() else {
+                                    continue;
+                                };
+
+                                let route = current.finish();
+                                let routes_path = Self::get_routes_path(&session).await;
+
+                                let output = match route::save_route(routes_path, &route) {
+                                    Ok(path) => HandlerOutput::SuccessMessage(
+                                        format!("Route '{}' with {} points saved to {}", route.name, route.points.len(), path.display()),
+                                        None,
+                                    ),
+                                    Err(err) => HandlerOutput::ErrorMessage(err.to_string(), None),
+                                };
+
+                                query_sender.broadcast(output).await.unwrap();
+                            },
+                            HandlerOutput::PlayRoute(name, mode) => {
+                                let routes_path = Self::get_routes_path(&session).await;
+                                let map_id = session.lock().await.me.as_ref().map(|me| me.map_id).unwrap_or_default();
+
+                                let route = route::load_route(routes_path, &name)
+                                    .and_then(|route| route::validate_route(&route, map_id).map(|_| route));
+
+                                let route = match route {
+                                    Ok(route) => route,
+                                    Err(err) => {
+                                        query_sender.broadcast(
+                                            HandlerOutput::ErrorMessage(err.to_string(), None)
+                                        ).await.unwrap();
+                                        continue;
+                                    },
+                                };
+
+                                for task in [patrol_task.take(), movement_task.take()].into_iter().flatten() {
+                                    movement::controller::abort_task(task, &session, &query_sender).await;
+                                }
+
+                                if let Some(task) = follow_task.take() {
+                                    movement::controller::abort_task(task, &session, &query_sender).await;
+                                    follow::stop_following(
+                                        &session, &query_sender, FollowStopReason::Requested,
+                                    ).await;
+                                }
+
+                                patrol_task = Some(tokio::spawn(route::patrol(
+                                    Arc::clone(&session), query_sender.clone(), route, mode,
+                                )));
+                            },
+                            HandlerOutput::SendChatMessage(message) => {
+                                let race = session.lock().await.me.as_ref().map(|me| me.race).unwrap_or_default();
+
+                                match ChatSender::for_race(race).send(message) {
+                                    Ok(packet) => output_sender.send(packet).await.unwrap(),
+                                    Err(err) => {
+                                        query_sender.broadcast(
+                                            HandlerOutput::ErrorMessage(err.to_string(), None)
+                                        ).await.unwrap();
+                                    },
+                                }
+                            },
+                            HandlerOutput::StopRoute => {
+                                if let Some(task) = patrol_task.take() {
+                                    movement::controller::abort_task(task, &session, &query_sender).await;
+                                    movement::controller::stop(&session, &query_sender).await;
+                                }
+                            },
                             _ => {},
                         };
                     },
@@ -502,6 +786,7 @@ impl Client {
             Box::new(RealmProcessor::get_handlers),
             Box::new(SpellProcessor::get_handlers),
             Box::new(WardenProcessor::get_handlers),
+            Box::new(WorldProcessor::get_handlers),
         ]
     }
 }
@@ -565,6 +850,8 @@ mod tests {
 
         let data_storage = &mut *client.data_storage.lock().unwrap();
         assert!(data_storage.players_map.is_empty());
+        assert!(data_storage.objects.is_empty());
+        assert!(data_storage.pending_name_queries.is_empty());
 
         let session = &mut *client.session.lock().await;
         assert!(session.srp.is_none());
@@ -0,0 +1,321 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+use anyhow::{Result as AnyResult};
+use async_broadcast::Sender as BroadcastSender;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tokio::time::sleep;
+
+use crate::primary::client::{MovementFlags, Position, UnitMoveType};
+use crate::primary::client::movement::jump;
+use crate::primary::client::movement::packet::{MovementOpcodes, MovementOutcome};
+use crate::primary::parsers::movement_parser::types::MovementInfo;
+use crate::primary::shared::session::Session;
+use crate::primary::shared::session::types::{ActionFlags, StateFlags};
+use crate::primary::types::{HandlerOutput, ObjectGuid, OutgoingPacket, PackedGuid};
+
+pub const HEARTBEAT_INTERVAL: Duration = Duration::from_millis(500);
+pub const DEFAULT_RUN_SPEED: f32 = 7.0;
+
+// distance to waypoint at which it counts as reached
+const WAYPOINT_TOLERANCE: f32 = 0.1;
+
+// milliseconds since client start, used as movement timestamp
+pub fn get_client_time() -> u32 {
+    static STARTED_AT: OnceLock<Instant> = OnceLock::new();
+
+    STARTED_AT.get_or_init(Instant::now).elapsed().as_millis() as u32
+}
+
+// movement info of current player, used in ACK packets
+pub fn get_movement_info(session: &Session) -> MovementInfo {
+    MovementInfo {
+        movement_flags: session.movement_flags.clone(),
+        time: get_client_time(),
+        position: session.me.as_ref().and_then(|me| me.position).unwrap_or_default(),
+        ..MovementInfo::default()
+    }
+}
+
+pub struct MovementController {
+    position: Position,
+    path: VecDeque<Position>,
+    speed: f32,
+    is_moving: bool,
+}
+
+impl MovementController {
+    pub fn new(position: Position, path: Vec<Position>, speed: f32) -> Self {
+        Self {
+            position,
+            path: path.into_iter().collect(),
+            speed,
+            is_moving: false,
+        }
+    }
+
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.path.is_empty() && !self.is_moving
+    }
+
+    // turns to the first waypoint, START_FORWARD is skipped if movement already started
+    pub fn start(&mut self, is_movement_started: bool) -> Vec<(u16, MovementFlags)> {
+        self.skip_reached();
+
+        let Some(target) = self.path.front().copied() else {
+            return match is_movement_started {
+                true => vec![(MovementOpcodes::MSG_MOVE_STOP, MovementFlags::NONE)],
+                false => vec![],
+            };
+        };
+
+        self.position.orientation = self.position.angle_to(&target);
+        self.is_moving = true;
+
+        match is_movement_started {
+            true => vec![(MovementOpcodes::MSG_MOVE_SET_FACING, MovementFlags::FORWARD)],
+            false => vec![
+                (MovementOpcodes::MSG_MOVE_SET_FACING, MovementFlags::NONE),
+                (MovementOpcodes::MSG_MOVE_START_FORWARD, MovementFlags::FORWARD),
+            ],
+        }
+    }
+
+    pub fn advance(&mut self, elapsed: Duration) -> Vec<(u16, MovementFlags)> {
+        if !self.is_moving {
+            return vec![];
+        }
+
+        let mut distance = self.speed * elapsed.as_secs_f32();
+        let mut steps = Vec::new();
+
+        while let Some(target) = self.path.front().copied() {
+            let remaining = self.position.distance(&target);
+            if remaining > distance {
+                let ratio = distance / remaining;
+                self.position.x += (target.x - self.position.x) * ratio;
+                self.position.y += (target.y - self.position.y) * ratio;
+                self.position.z += (target.z - self.position.z) * ratio;
+                break;
+            }
+
+            distance -= remaining;
+            self.position = Position { orientation: self.position.orientation, ..target };
+            self.path.pop_front();
+            self.skip_reached();
+
+            if let Some(next) = self.path.front() {
+                self.position.orientation = self.position.angle_to(next);
+                steps.push((MovementOpcodes::MSG_MOVE_SET_FACING, MovementFlags::FORWARD));
+            }
+        }
+
+        if self.path.is_empty() {
+            self.is_moving = false;
+            steps.push((MovementOpcodes::MSG_MOVE_STOP, MovementFlags::NONE));
+        } else if steps.is_empty() {
+            steps.push((MovementOpcodes::MSG_MOVE_HEARTBEAT, MovementFlags::FORWARD));
+        }
+
+        steps
+    }
+
+    fn skip_reached(&mut self) {
+        while self.path.front()
+            .map(|target| self.position.distance(target) < WAYPOINT_TOLERANCE)
+            .unwrap_or(false)
+        {
+            self.path.pop_front();
+        }
+    }
+
+    pub fn build_packet(
+        guid: ObjectGuid,
+        opcode: u16,
+        movement_flags: MovementFlags,
+        position: Position,
+    ) -> AnyResult<OutgoingPacket> {
+        MovementOutcome {
+            guid: PackedGuid(guid),
+            movement_flags: movement_flags.bits(),
+            movement_flags2: 0,
+            time: get_client_time(),
+            x: position.x,
+            y: position.y,
+            z: position.z,
+            direction: position.orientation,
+            unknown: 0,
+        }.unpack_with_opcode(opcode as u32)
+    }
+}
+
+// moves current player through the waypoints, should be spawned as separate task
+pub async fn move_to(
+    session: Arc<Mutex<Session>>,
+    query_sender: BroadcastSender<HandlerOutput>,
+    waypoints: Vec<Position>,
+) {
+    let (guid, position, speed, is_movement_started) = {
+        let mut guard = session.lock().await;
+        let is_movement_started = guard.state_flags.contains(StateFlags::IS_MOVEMENT_STARTED);
+
+        let Some(me) = guard.me.as_ref() else {
+            return;
+        };
+
+        if guard.movement_flags.contains(MovementFlags::ROOT) {
+            drop(guard);
+            query_sender.broadcast(
+                HandlerOutput::ErrorMessage("Cannot move: character is rooted".to_string(), None)
+            ).await.unwrap();
+            return;
+        }
+
+        let guid = me.guid;
+        let position = me.position;
+        let speed = me.movement_speed.get(&UnitMoveType::MOVE_RUN).copied().unwrap_or(DEFAULT_RUN_SPEED);
+
+        guard.action_flags.set(ActionFlags::IS_MOVING, true);
+
+        (guid, position, speed, is_movement_started)
+    };
+
+    let Some(position) = position else {
+        session.lock().await.action_flags.set(ActionFlags::IS_MOVING, false);
+        query_sender.broadcast(
+            HandlerOutput::ErrorMessage("Cannot move: current position is unknown".to_string(), None)
+        ).await.unwrap();
+        return;
+    };
+
+    let mut controller = MovementController::new(position, waypoints, speed);
+    let mut steps = controller.start(is_movement_started);
+    let mut last_tick = Instant::now();
+
+    loop {
+        let is_finished = controller.is_finished();
+        send_steps(&session, &query_sender, guid, controller.position(), steps, is_finished).await;
+
+        if is_finished {
+            break;
+        }
+
+        sleep(HEARTBEAT_INTERVAL).await;
+
+        let now = Instant::now();
+        steps = controller.advance(now - last_tick);
+        last_tick = now;
+    }
+}
+
+// stores new position of current player and broadcasts movement packets for it
+pub async fn send_steps(
+    session: &Arc<Mutex<Session>>,
+    query_sender: &BroadcastSender<HandlerOutput>,
+    guid: ObjectGuid,
+    position: Position,
+    steps: Vec<(u16, MovementFlags)>,
+    is_finished: bool,
+) {
+    {
+        let mut guard = session.lock().await;
+        if let Some(me) = guard.me.as_mut() {
+            me.position = Some(position);
+        }
+
+        guard.state_flags.set(StateFlags::IS_MOVEMENT_STARTED, !is_finished);
+        if is_finished {
+            guard.action_flags.set(ActionFlags::IS_MOVING, false);
+        }
+    }
+
+    for (opcode, movement_flags) in steps {
+        let output = match MovementController::build_packet(guid, opcode, movement_flags, position) {
+            Ok(packet) => HandlerOutput::Data(packet),
+            Err(err) => HandlerOutput::ErrorMessage(err.to_string(), None),
+        };
+
+        query_sender.broadcast(output).await.unwrap();
+    }
+}
+
+// waits until aborted task is really stopped, so it cannot overwrite state after cleanup
+pub async fn abort_task(
+    task: JoinHandle<()>,
+    session: &Arc<Mutex<Session>>,
+    query_sender: &BroadcastSender<HandlerOutput>,
+) {
+    task.abort();
+    let _ = task.await;
+
+    jump::interrupt(session, query_sender).await;
+}
+
+// interrupts current movement, the task itself should be aborted by the caller
+pub async fn stop(session: &Arc<Mutex<Session>>, query_sender: &BroadcastSender<HandlerOutput>) {
+    let (guid, position) = {
+        let mut guard = session.lock().await;
+        let is_movement_started = guard.state_flags.contains(StateFlags::IS_MOVEMENT_STARTED);
+
+        guard.action_flags.set(ActionFlags::IS_MOVING, false);
+        guard.state_flags.set(StateFlags::IS_MOVEMENT_STARTED, false);
+
+        match (is_movement_started, guard.me.as_ref()) {
+            (true, Some(me)) => (me.guid, me.position),
+            _ => return,
+        }
+    };
+
+    if let Some(position) = position {
+        let output = match MovementController::build_packet(
+            guid, MovementOpcodes::MSG_MOVE_STOP, MovementFlags::NONE, position,
+        ) {
+            Ok(packet) => HandlerOutput::Data(packet),
+            Err(err) => HandlerOutput::ErrorMessage(err.to_string(), None),
+        };
+
+        query_sender.broadcast(output).await.unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::primary::client::Position;
+    use crate::primary::client::movement::controller::MovementController;
+    use crate::primary::client::movement::packet::MovementOpcodes;
+
+    #[test]
+    fn test_move_through_waypoints() {
+        let mut controller = MovementController::new(
+            Position::new(0.0, 0.0, 0.0, 0.0),
+            vec![Position::new(0.0, 5.0, 0.0, 0.0), Position::new(5.0, 5.0, 0.0, 0.0)],
+            10.0,
+        );
+
+        let opcodes = |steps: Vec<(u16, _)>| steps.into_iter().map(|(opcode, _)| opcode).collect::<Vec<u16>>();
+
+        assert_eq!(
+            opcodes(controller.start(false)),
+            vec![MovementOpcodes::MSG_MOVE_SET_FACING, MovementOpcodes::MSG_MOVE_START_FORWARD],
+        );
+        assert!((controller.position().orientation - std::f32::consts::FRAC_PI_2).abs() < 1e-4);
+
+        assert_eq!(opcodes(controller.advance(Duration::from_millis(250))), vec![MovementOpcodes::MSG_MOVE_HEARTBEAT]);
+        assert!((controller.position().y - 2.5).abs() < 1e-4);
+
+        assert_eq!(opcodes(controller.advance(Duration::from_millis(500))), vec![MovementOpcodes::MSG_MOVE_SET_FACING]);
+        assert!((controller.position().x - 2.5).abs() < 1e-4);
+        assert!(controller.position().orientation.abs() < 1e-4);
+
+        assert_eq!(opcodes(controller.advance(Duration::from_millis(500))), vec![MovementOpcodes::MSG_MOVE_STOP]);
+        assert_eq!(controller.position().x, 5.0);
+        assert!(controller.is_finished());
+    }
+}
\ No newline at end of file
@@ -4,6 +4,7 @@ use crate::primary::client::Opcode;
 use crate::primary::client::player::globals::NameQueryOutcome;
 use crate::primary::types::{HandlerInput, HandlerOutput, HandlerResult, PackedGuid};
 use crate::primary::parsers::movement_parser::types::{MovementInfo};
+use crate::primary::shared::storage::objects::ObjectType;
 use crate::primary::traits::packet_handler::PacketHandler;
 
 #[derive(WorldPacket, Serialize, Deserialize, Debug)]
@@ -30,9 +31,13 @@ impl PacketHandler for Handler {
         let PackedGuid(guid) = packed_guid;
 
         {
-            input.data_storage.lock().unwrap().players_map.entry(guid).and_modify(|p| {
+            let mut guard = input.data_storage.lock().unwrap();
+            guard.players_map.entry(guid).and_modify(|p| {
                 p.position = Some(movement_info.position);
             });
+            if let Some(event) = guard.objects.update_movement(guid, &movement_info) {
+                response.push(HandlerOutput::ObjectEvent(event));
+            }
         }
 
         let my_guid = {
@@ -40,13 +45,14 @@ impl PacketHandler for Handler {
         };
 
         if my_guid != guid {
-            let players_map = &mut input.data_storage.lock().unwrap().players_map;
-            let player = players_map.get(&guid);
+            let mut guard = input.data_storage.lock().unwrap();
+            // units and other non-player objects have no names to query
+            let is_player = guard.objects.get(guid)
+                .map(|object| object.object_type == ObjectType::Player)
+                .unwrap_or(true);
 
-            if player.is_none() {
+            if is_player && !guard.players_map.contains_key(&guid) && guard.add_name_query(guid) {
                 response.push(HandlerOutput::Data(NameQueryOutcome { guid }.unpack()?));
-
-                return Ok(response);
             }
         }
 
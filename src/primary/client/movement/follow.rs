@@ -0,0 +1,230 @@
+use std::sync::{Arc, Mutex as SyncMutex};
+use std::time::Instant;
+use async_broadcast::Sender as BroadcastSender;
+use tokio::sync::Mutex;
+use tokio::time::sleep;
+
+use crate::primary::client::{MovementFlags, Position, UnitMoveType};
+use crate::primary::client::movement::controller::{
+    self, MovementController, DEFAULT_RUN_SPEED, HEARTBEAT_INTERVAL,
+};
+use crate::primary::client::movement::packet::MovementOpcodes;
+use crate::primary::client::movement::types::{FollowEvent, FollowStopReason, FollowTarget};
+use crate::primary::shared::session::Session;
+use crate::primary::shared::session::types::{ActionFlags, StateFlags};
+use crate::primary::shared::storage::DataStorage;
+use crate::primary::types::{HandlerOutput, ObjectGuid};
+
+pub const DEFAULT_FOLLOW_DISTANCE: f32 = 3.0;
+// follow stops when target is further than this
+pub const MAX_FOLLOW_DISTANCE: f32 = 100.0;
+
+// target can move a bit before path is rebuilt
+const REPATH_DISTANCE: f32 = 1.0;
+
+// returns point on the line to the target at the follow distance,
+// None if current position is already close enough
+pub fn get_follow_point(position: &Position, target: &Position, distance: f32) -> Option<Position> {
+    let current_distance = position.distance(target);
+    if current_distance <= distance {
+        return None;
+    }
+
+    let ratio = (current_distance - distance) / current_distance;
+
+    Some(Position::new(
+        position.x + (target.x - position.x) * ratio,
+        position.y + (target.y - position.y) * ratio,
+        position.z + (target.z - position.z) * ratio,
+        position.angle_to(target),
+    ))
+}
+
+pub fn resolve_target(data_storage: &Arc<SyncMutex<DataStorage>>, target: &FollowTarget) -> Option<ObjectGuid> {
+    match target {
+        FollowTarget::Guid(guid) => Some(*guid),
+        FollowTarget::Name(name) => {
+            data_storage.lock().unwrap().get_player_by_name(name).map(|player| player.guid)
+        },
+    }
+}
+
+// follows the target until it goes out of range or disappears, should be spawned as separate task
+pub async fn follow(
+    session: Arc<Mutex<Session>>,
+    data_storage: Arc<SyncMutex<DataStorage>>,
+    query_sender: BroadcastSender<HandlerOutput>,
+    target: ObjectGuid,
+) {
+    let (guid, speed, follow_distance) = {
+        let mut guard = session.lock().await;
+        let follow_distance = guard.get_config()
+            .map(|config| config.common.follow_distance)
+            .unwrap_or(DEFAULT_FOLLOW_DISTANCE);
+
+        let Some(me) = guard.me.as_ref() else {
+            return;
+        };
+
+        let guid = me.guid;
+        let speed = me.movement_speed.get(&UnitMoveType::MOVE_RUN).copied().unwrap_or(DEFAULT_RUN_SPEED);
+
+        guard.follow_target = Some(target);
+        guard.action_flags.set(ActionFlags::IS_FOLLOWING, true);
+
+        (guid, speed, follow_distance)
+    };
+
+    query_sender.broadcast(HandlerOutput::FollowEvent(FollowEvent::Started(target))).await.unwrap();
+
+    let mut controller: Option<MovementController> = None;
+    let mut destination: Option<Position> = None;
+    let mut last_tick = Instant::now();
+
+    let reason = loop {
+        if session.lock().await.movement_flags.contains(MovementFlags::ROOT) {
+            break FollowStopReason::Interrupted;
+        }
+
+        let target_position = data_storage.lock().unwrap().objects.get_world_position(target);
+        let Some(target_position) = target_position else {
+            break FollowStopReason::TargetLost;
+        };
+
+        let now = Instant::now();
+        let mut steps = Vec::new();
+        let position = match controller.as_mut() {
+            Some(controller) => {
+                steps.extend(controller.advance(now - last_tick));
+                Some(controller.position())
+            },
+            None => session.lock().await.me.as_ref().and_then(|me| me.position),
+        };
+        last_tick = now;
+
+        let Some(position) = position else {
+            break FollowStopReason::TargetLost;
+        };
+
+        if position.distance(&target_position) > MAX_FOLLOW_DISTANCE {
+            break FollowStopReason::OutOfRange;
+        }
+
+        let follow_point = get_follow_point(&position, &target_position, follow_distance);
+        let is_repath_needed = match (&follow_point, &destination) {
+            (Some(point), Some(destination)) => point.distance(destination) > REPATH_DISTANCE,
+            (Some(_), None) => true,
+            _ => false,
+        };
+
+        if is_repath_needed {
+            let is_started = session.lock().await.state_flags.contains(StateFlags::IS_MOVEMENT_STARTED);
+            let is_movement_started = is_movement_started(&steps, is_started);
+
+            let mut new_controller = MovementController::new(position, follow_point.into_iter().collect(), speed);
+            // turning while already moving replaces heartbeat of the previous path
+            steps.retain(|(opcode, _)| *opcode != MovementOpcodes::MSG_MOVE_HEARTBEAT);
+            steps.extend(new_controller.start(is_movement_started));
+
+            destination = follow_point;
+            controller = Some(new_controller);
+            session.lock().await.action_flags.set(ActionFlags::IS_MOVING, true);
+        }
+
+        if let Some(current) = controller.as_ref() {
+            let is_finished = current.is_finished();
+            controller::send_steps(&session, &query_sender, guid, current.position(), steps, is_finished).await;
+
+            if is_finished {
+                controller = None;
+                destination = None;
+            }
+        }
+
+        sleep(HEARTBEAT_INTERVAL).await;
+    };
+
+    stop_following(&session, &query_sender, reason).await;
+}
+
+// the task itself should be aborted by the caller
+pub async fn stop_following(
+    session: &Arc<Mutex<Session>>,
+    query_sender: &BroadcastSender<HandlerOutput>,
+    reason: FollowStopReason,
+) {
+    let target = {
+        let mut guard = session.lock().await;
+        guard.action_flags.set(ActionFlags::IS_FOLLOWING, false);
+        guard.follow_target.take()
+    };
+
+    let Some(target) = target else {
+        return;
+    };
+
+    controller::stop(session, query_sender).await;
+
+    query_sender.broadcast(
+        HandlerOutput::FollowEvent(FollowEvent::Stopped(target, reason))
+    ).await.unwrap();
+}
+
+// steps of the current tick are not sent yet, so the session flag is outdated if they stop or start movement
+fn is_movement_started(steps: &[(u16, MovementFlags)], is_started: bool) -> bool {
+    steps.iter().rev()
+        .find_map(|(opcode, _)| match *opcode {
+            MovementOpcodes::MSG_MOVE_STOP => Some(false),
+            MovementOpcodes::MSG_MOVE_START_FORWARD => Some(true),
+            _ => None,
+        })
+        .unwrap_or(is_started)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::primary::client::Position;
+    use crate::primary::client::movement::controller::MovementController;
+    use crate::primary::client::movement::follow::{get_follow_point, is_movement_started};
+    use crate::primary::client::movement::packet::MovementOpcodes;
+
+    #[test]
+    fn test_get_follow_point() {
+        let position = Position::new(0.0, 0.0, 0.0, 0.0);
+
+        let point = get_follow_point(&position, &Position::new(10.0, 0.0, 0.0, 0.0), 3.0).unwrap();
+        assert!((point.x - 7.0).abs() < 1e-4);
+        assert_eq!(point.orientation, 0.0);
+
+        assert!(get_follow_point(&position, &Position::new(0.0, 2.0, 0.0, 0.0), 3.0).is_none());
+    }
+    #[test]
+    fn test_repath_after_stop() {
+        let opcodes = |steps: &[(u16, _)]| steps.iter().map(|(opcode, _)| *opcode).collect::<Vec<u16>>();
+
+        let mut controller = MovementController::new(
+            Position::new(0.0, 0.0, 0.0, 0.0), vec![Position::new(1.0, 0.0, 0.0, 0.0)], 10.0,
+        );
+        controller.start(false);
+
+        // path is finished in the same tick when the target moves away
+        let mut steps = controller.advance(Duration::from_millis(500));
+        assert_eq!(opcodes(&steps), vec![MovementOpcodes::MSG_MOVE_STOP]);
+        assert!(!is_movement_started(&steps, true));
+
+        let mut new_controller = MovementController::new(
+            controller.position(), vec![Position::new(1.0, 5.0, 0.0, 0.0)], 10.0,
+        );
+        steps.extend(new_controller.start(is_movement_started(&steps, true)));
+        assert_eq!(opcodes(&steps), vec![
+            MovementOpcodes::MSG_MOVE_STOP,
+            MovementOpcodes::MSG_MOVE_SET_FACING,
+            MovementOpcodes::MSG_MOVE_START_FORWARD,
+        ]);
+
+        assert!(is_movement_started(&[], true));
+        assert!(is_movement_started(&steps, false));
+    }
+}
\ No newline at end of file
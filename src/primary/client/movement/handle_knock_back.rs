@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+
+use crate::primary::client::{MovementFlags, Opcode};
+use crate::primary::client::movement::controller::get_movement_info;
+use crate::primary::client::movement::packet::MovementAckOutcome;
+use crate::primary::parsers::movement_parser::types::JumpInfo;
+use crate::primary::types::{HandlerInput, HandlerOutput, HandlerResult, PackedGuid};
+use crate::primary::traits::packet_handler::PacketHandler;
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+struct Income {
+    guid: PackedGuid,
+    counter: u32,
+    cos_angle: f32,
+    sin_angle: f32,
+    horizontal_speed: f32,
+    vertical_speed: f32,
+}
+
+pub struct Handler;
+#[async_trait]
+impl PacketHandler for Handler {
+    async fn handle(&mut self, input: &mut HandlerInput) -> HandlerResult {
+        let mut response = Vec::new();
+
+        let (Income {
+            guid,
+            counter,
+            cos_angle,
+            sin_angle,
+            horizontal_speed,
+            vertical_speed,
+        }, json) = Income::from_binary(&input.data)?;
+
+        response.push(HandlerOutput::ResponseMessage(
+            Opcode::get_opcode_name(input.opcode as u32)
+                .unwrap_or(format!("Unknown opcode: {}", input.opcode)),
+            Some(json),
+        ));
+
+        let mut movement_info = {
+            let guard = input.session.lock().await;
+            match guard.me.as_ref() {
+                Some(me) if me.guid == guid => get_movement_info(&guard),
+                _ => return Ok(response),
+            }
+        };
+
+        // knockback starts the fall, so ACK should contain jump data
+        movement_info.movement_flags = MovementFlags::JUMPING;
+        movement_info.jump_info = JumpInfo {
+            jump_vertical_speed: vertical_speed,
+            jump_sin_angle: sin_angle,
+            jump_cos_angle: cos_angle,
+            jump_horizontal_speed: horizontal_speed,
+        };
+
+        response.push(HandlerOutput::Data(MovementAckOutcome {
+            guid,
+            counter,
+            movement_info,
+        }.unpack_with_opcode(Opcode::CMSG_MOVE_KNOCK_BACK_ACK)?));
+
+        Ok(response)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+
+use crate::primary::client::Opcode;
+use crate::primary::parsers::spline_parser::types::MonsterMove;
+use crate::primary::types::{HandlerInput, HandlerOutput, HandlerResult, PackedGuid};
+use crate::primary::traits::packet_handler::PacketHandler;
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+struct Income {
+    packed_guid: PackedGuid,
+    monster_move: MonsterMove,
+}
+
+pub struct Handler;
+#[async_trait]
+impl PacketHandler for Handler {
+    async fn handle(&mut self, input: &mut HandlerInput) -> HandlerResult {
+        let mut response = Vec::new();
+
+        let (Income { packed_guid, monster_move }, json) = Income::from_binary(&input.data)?;
+
+        response.push(HandlerOutput::ResponseMessage(
+            Opcode::get_opcode_name(input.opcode as u32)
+                .unwrap_or(format!("Unknown opcode: {}", input.opcode)),
+            Some(json),
+        ));
+
+        let PackedGuid(guid) = packed_guid;
+        let MonsterMove { start, spline, .. } = monster_move;
+
+        let event = input.data_storage.lock().unwrap().objects.start_spline(guid, start, spline);
+        if let Some(event) = event {
+            response.push(HandlerOutput::ObjectEvent(event));
+        }
+
+        Ok(response)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+
+use crate::primary::client::{MovementFlags, Opcode};
+use crate::primary::client::movement::controller::get_movement_info;
+use crate::primary::client::movement::packet::{MovementAckOutcome, MovementValueAckOutcome};
+use crate::primary::types::{HandlerInput, HandlerOutput, HandlerResult, PackedGuid};
+use crate::primary::traits::packet_handler::PacketHandler;
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+struct Income {
+    guid: PackedGuid,
+    counter: u32,
+}
+
+// handles root/unroot and can fly toggle
+pub struct Handler;
+#[async_trait]
+impl PacketHandler for Handler {
+    async fn handle(&mut self, input: &mut HandlerInput) -> HandlerResult {
+        let mut response = Vec::new();
+
+        let (Income { guid, counter }, json) = Income::from_binary(&input.data)?;
+
+        response.push(HandlerOutput::ResponseMessage(
+            Opcode::get_opcode_name(input.opcode as u32)
+                .unwrap_or(format!("Unknown opcode: {}", input.opcode)),
+            Some(json),
+        ));
+
+        let (flag, is_set) = match input.opcode {
+            Opcode::SMSG_FORCE_MOVE_ROOT => (MovementFlags::ROOT, true),
+            Opcode::SMSG_FORCE_MOVE_UNROOT => (MovementFlags::ROOT, false),
+            Opcode::SMSG_MOVE_SET_CAN_FLY => (MovementFlags::CAN_FLY, true),
+            Opcode::SMSG_MOVE_UNSET_CAN_FLY => (MovementFlags::CAN_FLY, false),
+            _ => return Ok(response),
+        };
+
+        let movement_info = {
+            let mut guard = input.session.lock().await;
+            if guard.me.as_ref().map(|me| me.guid != guid).unwrap_or(true) {
+                return Ok(response);
+            }
+
+            guard.movement_flags.set(flag.clone(), is_set);
+
+            get_movement_info(&guard)
+        };
+
+        let packet = match input.opcode {
+            Opcode::SMSG_FORCE_MOVE_ROOT => {
+                MovementAckOutcome { guid, counter, movement_info }
+                    .unpack_with_opcode(Opcode::CMSG_FORCE_MOVE_ROOT_ACK)?
+            },
+            Opcode::SMSG_FORCE_MOVE_UNROOT => {
+                MovementAckOutcome { guid, counter, movement_info }
+                    .unpack_with_opcode(Opcode::CMSG_FORCE_MOVE_UNROOT_ACK)?
+            },
+            _ => {
+                MovementValueAckOutcome { guid, counter, movement_info, value: 0.0 }
+                    .unpack_with_opcode(Opcode::CMSG_MOVE_SET_CAN_FLY_ACK)?
+            },
+        };
+
+        response.push(HandlerOutput::Data(packet));
+
+        // rooted character cannot continue current movement
+        if flag.contains(MovementFlags::ROOT) && is_set {
+            response.push(HandlerOutput::StopMovement);
+        }
+
+        Ok(response)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+
+use crate::primary::client::{MovementFlags, Opcode, Position};
+use crate::primary::client::movement::packet::WorldportAckOutcome;
+use crate::primary::shared::session::types::ActionFlags;
+use crate::primary::types::{HandlerInput, HandlerOutput, HandlerResult};
+use crate::primary::traits::packet_handler::PacketHandler;
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+struct Income {
+    map_id: u32,
+    x: f32,
+    y: f32,
+    z: f32,
+    orientation: f32,
+}
+
+// teleport to another map, client stays on loading screen until ACK is sent
+pub struct Handler;
+#[async_trait]
+impl PacketHandler for Handler {
+    async fn handle(&mut self, input: &mut HandlerInput) -> HandlerResult {
+        let mut response = Vec::new();
+
+        let (Income { map_id, x, y, z, orientation }, json) = Income::from_binary(&input.data)?;
+
+        response.push(HandlerOutput::ResponseMessage(
+            Opcode::get_opcode_name(input.opcode as u32)
+                .unwrap_or(format!("Unknown opcode: {}", input.opcode)),
+            Some(json),
+        ));
+
+        let position = Position::new(x, y, z, orientation);
+
+        let is_moving = {
+            let mut guard = input.session.lock().await;
+            let is_moving = guard.action_flags.intersects(ActionFlags::IS_MOVING | ActionFlags::IS_FOLLOWING);
+
+            // server sends root, can fly etc again for the new map
+            guard.movement_flags = MovementFlags::NONE;
+
+            if let Some(me) = guard.me.as_mut() {
+                me.map_id = map_id;
+                me.position = Some(position);
+                response.push(HandlerOutput::UpdatePlayer(me.clone()));
+            }
+
+            if let Some(event) = guard.set_map(map_id) {
+                response.push(HandlerOutput::WorldEvent(event));
+            }
+
+            is_moving
+        };
+
+        // objects of the previous map will not be destroyed by server
+        {
+            let mut guard = input.data_storage.lock().unwrap();
+            guard.objects.clear();
+            guard.clear_name_queries();
+        }
+
+        response.push(HandlerOutput::Data(WorldportAckOutcome::default().unpack()?));
+
+        // patrol can wait between legs without moving, and its route belongs to the previous map
+        response.push(HandlerOutput::StopRoute);
+        if is_moving {
+            response.push(HandlerOutput::StopMovement);
+        }
+
+        Ok(response)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,116 @@
+use async_trait::async_trait;
+
+use crate::primary::client::{Opcode, UnitMoveType};
+use crate::primary::client::movement::controller::get_movement_info;
+use crate::primary::client::movement::packet::MovementValueAckOutcome;
+use crate::primary::types::{HandlerInput, HandlerOutput, HandlerResult, PackedGuid};
+use crate::primary::traits::packet_handler::PacketHandler;
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+struct Income {
+    guid: PackedGuid,
+    counter: u32,
+    speed: f32,
+}
+
+// run speed change contains extra byte before the speed
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+struct RunSpeedIncome {
+    guid: PackedGuid,
+    counter: u32,
+    unknown: u8,
+    speed: f32,
+}
+
+pub struct Handler;
+
+impl Handler {
+    fn get_move_type(opcode: u16) -> Option<(u8, u32)> {
+        let move_type = match opcode {
+            Opcode::SMSG_FORCE_WALK_SPEED_CHANGE => {
+                (UnitMoveType::MOVE_WALK, Opcode::CMSG_FORCE_WALK_SPEED_CHANGE_ACK)
+            },
+            Opcode::SMSG_FORCE_RUN_SPEED_CHANGE => {
+                (UnitMoveType::MOVE_RUN, Opcode::CMSG_FORCE_RUN_SPEED_CHANGE_ACK)
+            },
+            Opcode::SMSG_FORCE_RUN_BACK_SPEED_CHANGE => {
+                (UnitMoveType::MOVE_RUN_BACK, Opcode::CMSG_FORCE_RUN_BACK_SPEED_CHANGE_ACK)
+            },
+            Opcode::SMSG_FORCE_SWIM_SPEED_CHANGE => {
+                (UnitMoveType::MOVE_SWIM, Opcode::CMSG_FORCE_SWIM_SPEED_CHANGE_ACK)
+            },
+            Opcode::SMSG_FORCE_SWIM_BACK_SPEED_CHANGE => {
+                (UnitMoveType::MOVE_SWIM_BACK, Opcode::CMSG_FORCE_SWIM_BACK_SPEED_CHANGE_ACK)
+            },
+            Opcode::SMSG_FORCE_TURN_RATE_CHANGE => {
+                (UnitMoveType::MOVE_TURN_RATE, Opcode::CMSG_FORCE_TURN_RATE_CHANGE_ACK)
+            },
+            Opcode::SMSG_FORCE_FLIGHT_SPEED_CHANGE => {
+                (UnitMoveType::MOVE_FLIGHT, Opcode::CMSG_FORCE_FLIGHT_SPEED_CHANGE_ACK)
+            },
+            Opcode::SMSG_FORCE_FLIGHT_BACK_SPEED_CHANGE => {
+                (UnitMoveType::MOVE_FLIGHT_BACK, Opcode::CMSG_FORCE_FLIGHT_BACK_SPEED_CHANGE_ACK)
+            },
+            Opcode::SMSG_FORCE_PITCH_RATE_CHANGE => {
+                (UnitMoveType::MOVE_PITCH_RATE, Opcode::CMSG_FORCE_PITCH_RATE_CHANGE_ACK)
+            },
+            _ => return None,
+        };
+
+        Some(move_type)
+    }
+}
+
+#[async_trait]
+impl PacketHandler for Handler {
+    async fn handle(&mut self, input: &mut HandlerInput) -> HandlerResult {
+        let mut response = Vec::new();
+
+        let ((guid, counter, speed), json) = if input.opcode == Opcode::SMSG_FORCE_RUN_SPEED_CHANGE {
+            let (RunSpeedIncome { guid, counter, speed, .. }, json) = RunSpeedIncome::from_binary(&input.data)?;
+
+            ((guid, counter, speed), json)
+        } else {
+            let (Income { guid, counter, speed }, json) = Income::from_binary(&input.data)?;
+
+            ((guid, counter, speed), json)
+        };
+
+        response.push(HandlerOutput::ResponseMessage(
+            Opcode::get_opcode_name(input.opcode as u32)
+                .unwrap_or(format!("Unknown opcode: {}", input.opcode)),
+            Some(json),
+        ));
+
+        let Some((move_type, ack_opcode)) = Self::get_move_type(input.opcode) else {
+            return Ok(response);
+        };
+
+        let movement_info = {
+            let mut guard = input.session.lock().await;
+            let Some(me) = guard.me.as_mut() else {
+                return Ok(response);
+            };
+
+            if me.guid != guid {
+                return Ok(response);
+            }
+
+            me.movement_speed.insert(move_type, speed);
+            response.push(HandlerOutput::UpdatePlayer(me.clone()));
+
+            get_movement_info(&guard)
+        };
+
+        response.push(HandlerOutput::Data(MovementValueAckOutcome {
+            guid,
+            counter,
+            movement_info,
+            value: speed,
+        }.unpack_with_opcode(ack_opcode)?));
+
+        Ok(response)
+    }
+}
\ No newline at end of file
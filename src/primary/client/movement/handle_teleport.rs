@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+
+use crate::primary::client::Opcode;
+use crate::primary::client::movement::controller::get_client_time;
+use crate::primary::client::movement::packet::TeleportAckOutcome;
+use crate::primary::parsers::movement_parser::types::MovementInfo;
+use crate::primary::shared::session::types::ActionFlags;
+use crate::primary::types::{HandlerInput, HandlerOutput, HandlerResult, PackedGuid};
+use crate::primary::traits::packet_handler::PacketHandler;
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+struct Income {
+    guid: PackedGuid,
+    counter: u32,
+    movement_info: MovementInfo,
+}
+
+// teleport inside the same map
+pub struct Handler;
+#[async_trait]
+impl PacketHandler for Handler {
+    async fn handle(&mut self, input: &mut HandlerInput) -> HandlerResult {
+        let mut response = Vec::new();
+
+        let (Income { guid, counter, movement_info }, json) = Income::from_binary(&input.data)?;
+
+        response.push(HandlerOutput::ResponseMessage(
+            Opcode::get_opcode_name(input.opcode as u32)
+                .unwrap_or(format!("Unknown opcode: {}", input.opcode)),
+            Some(json),
+        ));
+
+        let is_moving = {
+            let mut guard = input.session.lock().await;
+            let Some(me) = guard.me.as_mut() else {
+                return Ok(response);
+            };
+
+            if me.guid != guid {
+                return Ok(response);
+            }
+
+            me.position = Some(movement_info.position);
+            response.push(HandlerOutput::UpdatePlayer(me.clone()));
+
+            guard.action_flags.intersects(ActionFlags::IS_MOVING | ActionFlags::IS_FOLLOWING)
+        };
+
+        let PackedGuid(my_guid) = guid;
+        input.data_storage.lock().unwrap().objects.update_movement(my_guid, &movement_info);
+
+        response.push(HandlerOutput::Data(TeleportAckOutcome {
+            guid,
+            counter,
+            time: get_client_time(),
+        }.unpack()?));
+
+        response.push(HandlerOutput::StopRoute);
+        if is_moving {
+            response.push(HandlerOutput::StopMovement);
+        }
+
+        Ok(response)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,356 @@
+use std::sync::{Arc, Mutex as SyncMutex};
+use std::time::{Duration, Instant};
+use async_broadcast::Sender as BroadcastSender;
+use tokio::sync::Mutex;
+use tokio::task::spawn_blocking;
+use tokio::time::sleep_until;
+
+use crate::primary::client::{MovementFlags, Position, UnitMoveType};
+use crate::primary::client::movement::controller::{get_client_time, DEFAULT_RUN_SPEED, HEARTBEAT_INTERVAL};
+use crate::primary::client::movement::packet::{MovementInfoOutcome, MovementOpcodes};
+use crate::primary::client::movement::types::JumpType;
+use crate::primary::parsers::movement_parser::types::{JumpInfo, MovementInfo};
+use crate::primary::shared::session::Session;
+use crate::primary::shared::session::types::{ActionFlags, StateFlags};
+use crate::primary::terrain::Terrain;
+use crate::primary::types::{HandlerOutput, ObjectGuid, PackedGuid};
+
+// same values as client uses
+pub const GRAVITY: f32 = 19.291_105;
+pub const TERMINAL_VELOCITY: f32 = 60.148_003;
+pub const JUMP_VELOCITY: f32 = 7.955_547;
+
+// ballistic flight from the start position until the landing height is reached,
+// vertical speed is stored the way client sends it, so negative value means upward
+#[derive(Debug, Clone)]
+pub struct Jump {
+    start: Position,
+    jump_info: JumpInfo,
+    land_z: f32,
+    is_jump: bool,
+}
+
+impl Jump {
+    // jumps in the facing direction, horizontal speed is zero for standing jump
+    pub fn new(start: Position, horizontal_speed: f32, land_z: f32) -> Self {
+        Self::with_vertical_speed(start, horizontal_speed, -JUMP_VELOCITY, land_z, true)
+    }
+
+    // falling from the ledge without jump
+    pub fn fall(start: Position, horizontal_speed: f32, land_z: f32) -> Self {
+        Self::with_vertical_speed(start, horizontal_speed, 0.0, land_z, false)
+    }
+
+    fn with_vertical_speed(start: Position, horizontal_speed: f32, vertical_speed: f32, land_z: f32, is_jump: bool) -> Self {
+        let (sin, cos) = start.orientation.sin_cos();
+
+        let jump = Self {
+            start,
+            jump_info: JumpInfo {
+                jump_vertical_speed: vertical_speed,
+                jump_sin_angle: sin,
+                jump_cos_angle: cos,
+                jump_horizontal_speed: horizontal_speed,
+            },
+            land_z,
+            is_jump,
+        };
+
+        jump.with_land_z(land_z)
+    }
+
+    // landing point cannot be higher than the apex of the jump
+    pub fn with_land_z(self, land_z: f32) -> Self {
+        Self { land_z: land_z.min(self.apex_z()), ..self }
+    }
+
+    pub fn apex_z(&self) -> f32 {
+        let velocity = (-self.jump_info.jump_vertical_speed).max(0.0);
+        self.start.z + velocity * velocity / (2.0 * GRAVITY)
+    }
+
+    pub fn jump_info(&self) -> &JumpInfo {
+        &self.jump_info
+    }
+
+    // time of flight until the landing height is reached on the way down
+    pub fn duration(&self) -> Duration {
+        let velocity = -self.jump_info.jump_vertical_speed;
+        let drop = self.start.z - self.land_z;
+
+        let land_at = |drop: f32| -> f32 {
+            // landing height is clamped to the apex, so it can be negative only by rounding
+            let discriminant = (velocity * velocity + 2.0 * GRAVITY * drop).max(0.0);
+
+            let time = (velocity + discriminant.sqrt()) / GRAVITY;
+            let terminal_time = (velocity + TERMINAL_VELOCITY) / GRAVITY;
+            if time <= terminal_time {
+                return time;
+            }
+
+            // the rest of the way is passed with constant speed
+            let terminal_z = self.height_at(terminal_time) - self.start.z;
+            terminal_time + (terminal_z + drop) / TERMINAL_VELOCITY
+        };
+
+        Duration::from_secs_f32(land_at(drop).max(0.0))
+    }
+
+    pub fn position_at(&self, elapsed: Duration) -> Position {
+        let time = elapsed.min(self.duration()).as_secs_f32();
+        let distance = self.jump_info.jump_horizontal_speed * time;
+
+        Position::new(
+            self.start.x + self.jump_info.jump_cos_angle * distance,
+            self.start.y + self.jump_info.jump_sin_angle * distance,
+            self.height_at(time),
+            self.start.orientation,
+        )
+    }
+
+    pub fn landing_position(&self) -> Position {
+        Position { z: self.land_z, ..self.position_at(self.duration()) }
+    }
+
+    fn height_at(&self, time: f32) -> f32 {
+        let velocity = -self.jump_info.jump_vertical_speed;
+        let terminal_time = (velocity + TERMINAL_VELOCITY) / GRAVITY;
+
+        if time <= terminal_time {
+            return self.start.z + velocity * time - GRAVITY * time * time / 2.0;
+        }
+
+        self.height_at(terminal_time) - TERMINAL_VELOCITY * (time - terminal_time)
+    }
+
+    // jump (or first falling heartbeat), airborne heartbeats and landing,
+    // each step contains its offset from the start, time field is set on sending
+    pub fn steps(&self) -> Vec<(Duration, u16, MovementInfo)> {
+        let base_flags = match self.jump_info.jump_horizontal_speed > 0.0 {
+            true => MovementFlags::FORWARD,
+            false => MovementFlags::NONE,
+        };
+
+        let duration = self.duration();
+        let airborne = |elapsed: Duration| MovementInfo {
+            movement_flags: base_flags.clone() | MovementFlags::JUMPING,
+            position: self.position_at(elapsed),
+            fall_time: elapsed.as_millis() as u32,
+            jump_info: self.jump_info.clone(),
+            ..MovementInfo::default()
+        };
+
+        let first_opcode = match self.is_jump {
+            true => MovementOpcodes::MSG_MOVE_JUMP,
+            false => MovementOpcodes::MSG_MOVE_HEARTBEAT,
+        };
+
+        let mut steps = vec![(Duration::ZERO, first_opcode, airborne(Duration::ZERO))];
+
+        let mut elapsed = HEARTBEAT_INTERVAL;
+        while elapsed < duration {
+            steps.push((elapsed, MovementOpcodes::MSG_MOVE_HEARTBEAT, airborne(elapsed)));
+            elapsed += HEARTBEAT_INTERVAL;
+        }
+
+        let landing = MovementInfo {
+            movement_flags: base_flags.clone(),
+            position: self.landing_position(),
+            fall_time: duration.as_millis() as u32,
+            ..MovementInfo::default()
+        };
+        steps.push((duration, MovementOpcodes::MSG_MOVE_FALL_LAND, landing.clone()));
+
+        // bot does not keep running after landing
+        if !base_flags.is_empty() {
+            steps.push((duration, MovementOpcodes::MSG_MOVE_STOP, MovementInfo {
+                movement_flags: MovementFlags::NONE,
+                fall_time: 0,
+                ..landing
+            }));
+        }
+
+        steps
+    }
+}
+
+// prepares jump of current player from its position, landing height is the start one if unknown
+pub async fn prepare_jump(session: &Arc<Mutex<Session>>, jump_type: JumpType) -> Option<(ObjectGuid, u32, Jump)> {
+    let guard = session.lock().await;
+    let me = guard.me.as_ref()?;
+    let position = me.position?;
+
+    let horizontal_speed = match jump_type {
+        JumpType::Standing => 0.0,
+        JumpType::Forward => me.movement_speed.get(&UnitMoveType::MOVE_RUN).copied().unwrap_or(DEFAULT_RUN_SPEED),
+    };
+
+    Some((me.guid, me.map_id, Jump::new(position, horizontal_speed, position.z)))
+}
+
+// lands on the terrain if grids are available, otherwise on the start height
+pub async fn land_on_terrain(terrain: Arc<SyncMutex<Terrain>>, map_id: u32, jump: Jump) -> Jump {
+    let landing = jump.landing_position();
+    let land_z = spawn_blocking(move || {
+        terrain.lock().unwrap().height_at(map_id, landing.x, landing.y).ok().flatten()
+    }).await.ok().flatten();
+
+    match land_z {
+        Some(land_z) => jump.with_land_z(land_z),
+        None => jump,
+    }
+}
+
+// sends jump packets in real time, should be spawned as separate task
+pub async fn perform(
+    session: Arc<Mutex<Session>>,
+    query_sender: BroadcastSender<HandlerOutput>,
+    guid: ObjectGuid,
+    jump: Jump,
+) {
+    {
+        let mut guard = session.lock().await;
+        if guard.movement_flags.contains(MovementFlags::ROOT) {
+            drop(guard);
+            query_sender.broadcast(
+                HandlerOutput::ErrorMessage("Cannot jump: character is rooted".to_string(), None)
+            ).await.unwrap();
+            return;
+        }
+
+        guard.action_flags.set(ActionFlags::IS_MOVING, true);
+        guard.movement_flags.insert(MovementFlags::JUMPING);
+    }
+
+    let started_at = Instant::now();
+    for (offset, opcode, mut movement_info) in jump.steps() {
+        sleep_until((started_at + offset).into()).await;
+
+        movement_info.time = get_client_time();
+        let position = movement_info.position;
+
+        let output = match (MovementInfoOutcome { guid: PackedGuid(guid), movement_info }).unpack_with_opcode(opcode as u32) {
+            Ok(packet) => HandlerOutput::Data(packet),
+            Err(err) => HandlerOutput::ErrorMessage(err.to_string(), None),
+        };
+
+        if let Some(me) = session.lock().await.me.as_mut() {
+            me.position = Some(position);
+        }
+
+        query_sender.broadcast(output).await.unwrap();
+    }
+
+    let mut guard = session.lock().await;
+    guard.movement_flags.remove(MovementFlags::JUMPING);
+    guard.action_flags.set(ActionFlags::IS_MOVING, false);
+    guard.state_flags.set(StateFlags::IS_MOVEMENT_STARTED, false);
+}
+
+// lands the character at the last sent position when jump task was aborted in the air
+pub async fn interrupt(session: &Arc<Mutex<Session>>, query_sender: &BroadcastSender<HandlerOutput>) {
+    let (guid, position) = {
+        let mut guard = session.lock().await;
+        if !guard.movement_flags.contains(MovementFlags::JUMPING) {
+            return;
+        }
+
+        guard.movement_flags.remove(MovementFlags::JUMPING);
+        guard.action_flags.set(ActionFlags::IS_MOVING, false);
+        guard.state_flags.set(StateFlags::IS_MOVEMENT_STARTED, false);
+
+        match guard.me.as_ref().and_then(|me| me.position.map(|position| (me.guid, position))) {
+            Some(current) => current,
+            None => return,
+        }
+    };
+
+    let movement_info = MovementInfo {
+        time: get_client_time(),
+        position,
+        ..MovementInfo::default()
+    };
+
+    let output = match (MovementInfoOutcome { guid: PackedGuid(guid), movement_info })
+        .unpack_with_opcode(MovementOpcodes::MSG_MOVE_FALL_LAND as u32)
+    {
+        Ok(packet) => HandlerOutput::Data(packet),
+        Err(err) => HandlerOutput::ErrorMessage(err.to_string(), None),
+    };
+
+    query_sender.broadcast(output).await.unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::primary::client::{MovementFlags, Position};
+    use crate::primary::client::movement::jump::{Jump, GRAVITY, JUMP_VELOCITY};
+    use crate::primary::client::movement::packet::MovementOpcodes;
+
+    #[test]
+    fn test_jump_forward() {
+        let jump = Jump::new(Position::new(0.0, 0.0, 10.0, std::f32::consts::FRAC_PI_2), 7.0, 10.0);
+
+        let flight_time = 2.0 * JUMP_VELOCITY / GRAVITY;
+        assert!((jump.duration().as_secs_f32() - flight_time).abs() < 1e-3);
+
+        let apex = jump.position_at(Duration::from_secs_f32(flight_time / 2.0));
+        assert!((apex.z - (10.0 + JUMP_VELOCITY * JUMP_VELOCITY / (2.0 * GRAVITY))).abs() < 1e-3);
+        assert!(apex.x.abs() < 1e-4);
+
+        let landing = jump.landing_position();
+        assert!((landing.y - 7.0 * flight_time).abs() < 1e-3);
+        assert_eq!(landing.z, 10.0);
+
+        let steps = jump.steps();
+        let opcodes: Vec<u16> = steps.iter().map(|(_, opcode, _)| *opcode).collect();
+        assert_eq!(opcodes, vec![
+            MovementOpcodes::MSG_MOVE_JUMP,
+            MovementOpcodes::MSG_MOVE_HEARTBEAT,
+            MovementOpcodes::MSG_MOVE_FALL_LAND,
+            MovementOpcodes::MSG_MOVE_STOP,
+        ]);
+
+        let (_, _, jump_info) = &steps[0];
+        assert!(jump_info.movement_flags.contains(MovementFlags::JUMPING | MovementFlags::FORWARD));
+        assert_eq!(jump_info.jump_info.jump_vertical_speed, -JUMP_VELOCITY);
+
+        let (_, _, heartbeat_info) = &steps[1];
+        assert_eq!(heartbeat_info.fall_time, 500);
+        assert!(heartbeat_info.position.z > 10.0);
+
+        let (_, _, land_info) = &steps[2];
+        assert!(!land_info.movement_flags.contains(MovementFlags::JUMPING));
+        assert_eq!(land_info.fall_time, jump.duration().as_millis() as u32);
+    }
+
+    #[test]
+    fn test_fall() {
+        // long enough fall to reach terminal velocity
+        let jump = Jump::fall(Position::new(0.0, 0.0, 500.0, 0.0), 0.0, 0.0);
+
+        let duration = jump.duration();
+        assert!(duration > Duration::from_secs(8));
+        assert!(jump.position_at(duration).z.abs() < 0.01);
+
+        let steps = jump.steps();
+        assert_eq!(steps.first().unwrap().1, MovementOpcodes::MSG_MOVE_HEARTBEAT);
+        assert_eq!(steps.last().unwrap().1, MovementOpcodes::MSG_MOVE_FALL_LAND);
+        assert!(steps.windows(2).all(|pair| pair[0].2.position.z >= pair[1].2.position.z));
+    }
+
+    #[test]
+    fn test_land_above_apex() {
+        let jump = Jump::new(Position::new(0.0, 0.0, 10.0, 0.0), 7.0, 10.0).with_land_z(50.0);
+
+        let apex_time = JUMP_VELOCITY / GRAVITY;
+        assert!((jump.duration().as_secs_f32() - apex_time).abs() < 1e-3);
+        assert_eq!(jump.landing_position().z, jump.apex_z());
+        assert!((jump.position_at(jump.duration()).z - jump.apex_z()).abs() < 1e-3);
+
+        // falling cannot land higher than the start
+        assert_eq!(Jump::fall(Position::new(0.0, 0.0, 10.0, 0.0), 0.0, 20.0).landing_position().z, 10.0);
+    }
+}
\ No newline at end of file
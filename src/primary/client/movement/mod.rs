@@ -1,4 +1,15 @@
+pub mod controller;
 mod detect_motion;
+pub mod follow;
+mod handle_knock_back;
+mod handle_monster_move;
+mod handle_movement_state_change;
+mod handle_new_world;
+mod handle_speed_change;
+mod handle_teleport;
+pub mod jump;
+pub mod navigate;
+pub mod route;
 pub mod types;
 
 use crate::primary::client::opcodes::Opcode;
@@ -33,6 +44,47 @@ impl Processor for MovementProcessor {
             Opcode::MSG_MOVE_SET_FACING => {
                 handlers
             },
+            Opcode::SMSG_MONSTER_MOVE => {
+                vec![
+                    Box::new(handle_monster_move::Handler),
+                ]
+            },
+            Opcode::SMSG_FORCE_WALK_SPEED_CHANGE |
+            Opcode::SMSG_FORCE_RUN_SPEED_CHANGE |
+            Opcode::SMSG_FORCE_RUN_BACK_SPEED_CHANGE |
+            Opcode::SMSG_FORCE_SWIM_SPEED_CHANGE |
+            Opcode::SMSG_FORCE_SWIM_BACK_SPEED_CHANGE |
+            Opcode::SMSG_FORCE_TURN_RATE_CHANGE |
+            Opcode::SMSG_FORCE_FLIGHT_SPEED_CHANGE |
+            Opcode::SMSG_FORCE_FLIGHT_BACK_SPEED_CHANGE |
+            Opcode::SMSG_FORCE_PITCH_RATE_CHANGE => {
+                vec![
+                    Box::new(handle_speed_change::Handler),
+                ]
+            },
+            Opcode::SMSG_FORCE_MOVE_ROOT |
+            Opcode::SMSG_FORCE_MOVE_UNROOT |
+            Opcode::SMSG_MOVE_SET_CAN_FLY |
+            Opcode::SMSG_MOVE_UNSET_CAN_FLY => {
+                vec![
+                    Box::new(handle_movement_state_change::Handler),
+                ]
+            },
+            Opcode::SMSG_MOVE_KNOCK_BACK => {
+                vec![
+                    Box::new(handle_knock_back::Handler),
+                ]
+            },
+            Opcode::MSG_MOVE_TELEPORT_ACK => {
+                vec![
+                    Box::new(handle_teleport::Handler),
+                ]
+            },
+            Opcode::SMSG_NEW_WORLD => {
+                vec![
+                    Box::new(handle_new_world::Handler),
+                ]
+            },
             _ => {
                 vec![]
             },
@@ -44,6 +96,8 @@ impl Processor for MovementProcessor {
 
 pub mod packet {
     use crate::primary::client::Opcode;
+    use crate::primary::macros::with_opcode;
+    use crate::primary::parsers::movement_parser::types::MovementInfo;
     use crate::primary::types::PackedGuid;
 
     #[non_exhaustive]
@@ -86,4 +140,251 @@ pub mod packet {
         pub direction: f32,
         pub unknown: u32,
     }
+
+    // movement packet with full movement info, used for jumps and falls
+    #[derive(WorldPacket, Serialize, Deserialize, Debug)]
+    #[options(no_opcode)]
+    pub struct MovementInfoOutcome {
+        pub guid: PackedGuid,
+        pub movement_info: MovementInfo,
+    }
+
+    // ACK for root/unroot and knockback
+    #[derive(WorldPacket, Serialize, Deserialize, Debug)]
+    #[options(no_opcode)]
+    pub struct MovementAckOutcome {
+        pub guid: PackedGuid,
+        pub counter: u32,
+        pub movement_info: MovementInfo,
+    }
+
+    // ACK for speed changes and can fly toggle
+    #[derive(WorldPacket, Serialize, Deserialize, Debug)]
+    #[options(no_opcode)]
+    pub struct MovementValueAckOutcome {
+        pub guid: PackedGuid,
+        pub counter: u32,
+        pub movement_info: MovementInfo,
+        pub value: f32,
+    }
+
+    with_opcode! {
+        @world_opcode(Opcode::MSG_MOVE_TELEPORT_ACK)
+        #[derive(WorldPacket, Serialize, Deserialize, Debug)]
+        pub struct TeleportAckOutcome {
+            pub guid: PackedGuid,
+            pub counter: u32,
+            pub time: u32,
+        }
+    }
+
+    with_opcode! {
+        @world_opcode(Opcode::MSG_MOVE_WORLDPORT_ACK)
+        #[derive(WorldPacket, Serialize, Deserialize, Debug, Default)]
+        pub struct WorldportAckOutcome {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Read};
+    use std::sync::{Arc, Mutex as SyncMutex};
+    use byteorder::{BigEndian, LittleEndian, ReadBytesExt, WriteBytesExt};
+    use tokio::sync::Mutex;
+
+    use crate::primary::client::{MovementFlags, Opcode, Player, Position};
+    use crate::primary::client::movement::{
+        handle_knock_back, handle_movement_state_change, handle_new_world, handle_speed_change, handle_teleport,
+    };
+    use crate::primary::parsers::movement_parser::MovementParser;
+    use crate::primary::parsers::movement_parser::types::MovementInfo;
+    use crate::primary::shared::session::Session;
+    use crate::primary::shared::storage::DataStorage;
+    use crate::primary::traits::packet_handler::PacketHandler;
+    use crate::primary::types::{HandlerInput, HandlerOutput, ObjectGuid, OutgoingPacket};
+
+    const GUID: u64 = 42;
+    const PACKED_GUID: [u8; 2] = [1, 42];
+    const COUNTER: u32 = 7;
+    const POSITION: [f32; 4] = [1.0, 2.0, 3.0, 0.5];
+
+    fn create_input(opcode: u16, data: Vec<u8>) -> HandlerInput {
+        let mut me = Player::new(ObjectGuid::from(GUID), "Me".to_string(), 1, 1, 0, 1);
+        me.position = Some(Position::new(POSITION[0], POSITION[1], POSITION[2], POSITION[3]));
+
+        let mut session = Session::new();
+        session.me = Some(me);
+
+        HandlerInput {
+            session: Arc::new(Mutex::new(session)),
+            data,
+            data_storage: Arc::new(SyncMutex::new(DataStorage::new())),
+            opcode,
+        }
+    }
+
+    // packed guid and counter, which every server request for ACK starts with
+    fn create_request() -> Vec<u8> {
+        let mut data = PACKED_GUID.to_vec();
+        data.write_u32::<LittleEndian>(COUNTER).unwrap();
+        data
+    }
+
+    fn get_packet(response: &[HandlerOutput]) -> &OutgoingPacket {
+        response.iter()
+            .find_map(|output| match output {
+                HandlerOutput::Data(packet) => Some(packet),
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    // checks the header and returns reader of the body
+    fn read_body(packet: &OutgoingPacket, opcode: u32) -> Cursor<&[u8]> {
+        let mut reader = Cursor::new(packet.data.as_slice());
+        let size = reader.read_u16::<BigEndian>().unwrap();
+        assert_eq!(size as usize, packet.data.len() - 2);
+        assert_eq!(reader.read_u32::<LittleEndian>().unwrap(), opcode);
+        assert_eq!(packet.opcode, opcode);
+
+        reader
+    }
+
+    fn assert_guid_and_counter(reader: &mut Cursor<&[u8]>) {
+        let mut guid = [0; 2];
+        reader.read_exact(&mut guid).unwrap();
+        assert_eq!(guid, PACKED_GUID);
+        assert_eq!(reader.read_u32::<LittleEndian>().unwrap(), COUNTER);
+    }
+
+    fn assert_movement_info(reader: &mut Cursor<&[u8]>, movement_flags: MovementFlags) {
+        assert_eq!(reader.read_u32::<LittleEndian>().unwrap(), movement_flags.bits());
+        assert_eq!(reader.read_u16::<LittleEndian>().unwrap(), 0);
+        // client time
+        reader.read_u32::<LittleEndian>().unwrap();
+        for value in POSITION {
+            assert_eq!(reader.read_f32::<LittleEndian>().unwrap(), value);
+        }
+        // fall time
+        assert_eq!(reader.read_u32::<LittleEndian>().unwrap(), 0);
+    }
+
+    fn assert_end(reader: &Cursor<&[u8]>) {
+        assert_eq!(reader.position() as usize, reader.get_ref().len());
+    }
+
+    #[tokio::test]
+    async fn test_speed_change_ack() {
+        let mut data = create_request();
+        data.write_f32::<LittleEndian>(2.5).unwrap();
+
+        let mut input = create_input(Opcode::SMSG_FORCE_WALK_SPEED_CHANGE, data);
+        let response = handle_speed_change::Handler.handle(&mut input).await.unwrap();
+
+        let mut reader = read_body(get_packet(&response), Opcode::CMSG_FORCE_WALK_SPEED_CHANGE_ACK);
+        assert_guid_and_counter(&mut reader);
+        assert_movement_info(&mut reader, MovementFlags::NONE);
+        assert_eq!(reader.read_f32::<LittleEndian>().unwrap(), 2.5);
+        assert_end(&reader);
+
+        // run speed request has extra byte before the speed, which is not sent back
+        let mut data = create_request();
+        data.write_u8(1).unwrap();
+        data.write_f32::<LittleEndian>(8.0).unwrap();
+
+        let mut input = create_input(Opcode::SMSG_FORCE_RUN_SPEED_CHANGE, data);
+        let response = handle_speed_change::Handler.handle(&mut input).await.unwrap();
+
+        let mut reader = read_body(get_packet(&response), Opcode::CMSG_FORCE_RUN_SPEED_CHANGE_ACK);
+        assert_guid_and_counter(&mut reader);
+        assert_movement_info(&mut reader, MovementFlags::NONE);
+        assert_eq!(reader.read_f32::<LittleEndian>().unwrap(), 8.0);
+        assert_end(&reader);
+    }
+
+    #[tokio::test]
+    async fn test_root_ack() {
+        let mut input = create_input(Opcode::SMSG_FORCE_MOVE_ROOT, create_request());
+        let response = handle_movement_state_change::Handler.handle(&mut input).await.unwrap();
+
+        let mut reader = read_body(get_packet(&response), Opcode::CMSG_FORCE_MOVE_ROOT_ACK);
+        assert_guid_and_counter(&mut reader);
+        assert_movement_info(&mut reader, MovementFlags::ROOT);
+        assert_end(&reader);
+
+        assert!(matches!(response.last(), Some(HandlerOutput::StopMovement)));
+    }
+
+    #[tokio::test]
+    async fn test_can_fly_ack() {
+        let mut input = create_input(Opcode::SMSG_MOVE_SET_CAN_FLY, create_request());
+        let response = handle_movement_state_change::Handler.handle(&mut input).await.unwrap();
+
+        let mut reader = read_body(get_packet(&response), Opcode::CMSG_MOVE_SET_CAN_FLY_ACK);
+        assert_guid_and_counter(&mut reader);
+        assert_movement_info(&mut reader, MovementFlags::CAN_FLY);
+        assert_eq!(reader.read_f32::<LittleEndian>().unwrap(), 0.0);
+        assert_end(&reader);
+    }
+
+    #[tokio::test]
+    async fn test_knock_back_ack() {
+        let mut data = create_request();
+        for value in [0.6, 0.8, 5.0, -7.0] {
+            data.write_f32::<LittleEndian>(value).unwrap();
+        }
+
+        let mut input = create_input(Opcode::SMSG_MOVE_KNOCK_BACK, data);
+        let response = handle_knock_back::Handler.handle(&mut input).await.unwrap();
+
+        let mut reader = read_body(get_packet(&response), Opcode::CMSG_MOVE_KNOCK_BACK_ACK);
+        assert_guid_and_counter(&mut reader);
+        assert_movement_info(&mut reader, MovementFlags::JUMPING);
+        // vertical speed, sin, cos, horizontal speed
+        for value in [-7.0, 0.8, 0.6, 5.0] {
+            assert_eq!(reader.read_f32::<LittleEndian>().unwrap(), value);
+        }
+        assert_end(&reader);
+    }
+
+    #[tokio::test]
+    async fn test_teleport_ack() {
+        let mut data = create_request();
+        MovementParser::write(&mut data, &MovementInfo {
+            position: Position::new(10.0, 20.0, 30.0, 1.0),
+            ..MovementInfo::default()
+        }).unwrap();
+
+        let mut input = create_input(Opcode::MSG_MOVE_TELEPORT_ACK, data);
+        let response = handle_teleport::Handler.handle(&mut input).await.unwrap();
+
+        let mut reader = read_body(get_packet(&response), Opcode::MSG_MOVE_TELEPORT_ACK as u32);
+        assert_guid_and_counter(&mut reader);
+        // client time
+        reader.read_u32::<LittleEndian>().unwrap();
+        assert_end(&reader);
+
+        let position = input.session.lock().await.me.as_ref().unwrap().position.unwrap();
+        assert_eq!((position.x, position.y, position.z), (10.0, 20.0, 30.0));
+    }
+
+    #[tokio::test]
+    async fn test_worldport_ack() {
+        let mut data = Vec::new();
+        data.write_u32::<LittleEndian>(1).unwrap();
+        for value in POSITION {
+            data.write_f32::<LittleEndian>(value).unwrap();
+        }
+
+        let mut input = create_input(Opcode::SMSG_NEW_WORLD, data);
+        input.session.lock().await.movement_flags = MovementFlags::ROOT;
+        let response = handle_new_world::Handler.handle(&mut input).await.unwrap();
+
+        let reader = read_body(get_packet(&response), Opcode::MSG_MOVE_WORLDPORT_ACK as u32);
+        assert_end(&reader);
+
+        let guard = input.session.lock().await;
+        assert_eq!(guard.me.as_ref().unwrap().map_id, 1);
+        assert!(guard.movement_flags.is_empty());
+    }
 }
\ No newline at end of file
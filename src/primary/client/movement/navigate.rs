@@ -0,0 +1,70 @@
+use std::sync::{Arc, Mutex as SyncMutex};
+use async_broadcast::Sender as BroadcastSender;
+use tokio::sync::Mutex;
+use tokio::task::spawn_blocking;
+
+use crate::primary::client::Position;
+use crate::primary::errors::NavigationError;
+use crate::primary::navigation::Navigator;
+use crate::primary::shared::session::Session;
+use crate::primary::terrain::Terrain;
+use crate::primary::types::HandlerOutput;
+
+// tiles and grids are read from disk, so path is searched outside of async runtime
+pub async fn navigate(
+    session: Arc<Mutex<Session>>,
+    navigator: Arc<SyncMutex<Navigator>>,
+    terrain: Arc<SyncMutex<Terrain>>,
+    query_sender: BroadcastSender<HandlerOutput>,
+    destination: Position,
+) {
+    let location = {
+        let guard = session.lock().await;
+        guard.me.as_ref().and_then(|me| me.position.map(|position| (me.map_id, position)))
+    };
+
+    let outputs = match location {
+        Some((map_id, position)) => spawn_blocking(move || {
+            find_waypoints(&navigator, &terrain, map_id, &position, &destination)
+        }).await.unwrap_or_else(|err| vec![
+            HandlerOutput::ErrorMessage(format!("Cannot navigate: {}", err), None),
+        ]),
+        // move_to will report unknown position
+        None => vec![HandlerOutput::MoveTo(vec![destination])],
+    };
+
+    for output in outputs {
+        query_sender.broadcast(output).await.unwrap();
+    }
+}
+
+fn find_waypoints(
+    navigator: &SyncMutex<Navigator>,
+    terrain: &SyncMutex<Terrain>,
+    map_id: u32,
+    position: &Position,
+    destination: &Position,
+) -> Vec<HandlerOutput> {
+    let result = navigator.lock().unwrap().find_path(map_id, position, destination);
+
+    match result {
+        Ok(waypoints) => vec![
+            HandlerOutput::MoveTo(terrain.lock().unwrap().snap_path(map_id, position, waypoints)),
+        ],
+        // without navmesh just go straight
+        Err(err @ (NavigationError::NotConfigured | NavigationError::MapNotFound(_))) => {
+            // destination from the command can be off the ground
+            let mut terrain = terrain.lock().unwrap();
+            let ground_destination = terrain
+                .height_at(map_id, destination.x, destination.y).ok().flatten()
+                .map(|z| Position { z, ..*destination })
+                .unwrap_or(*destination);
+
+            vec![
+                HandlerOutput::DebugMessage(format!("{}, moving without pathfinding", err), None),
+                HandlerOutput::MoveTo(terrain.snap_path(map_id, position, vec![ground_destination])),
+            ]
+        },
+        Err(err) => vec![HandlerOutput::ErrorMessage(format!("Cannot navigate: {}", err), None)],
+    }
+}
\ No newline at end of file
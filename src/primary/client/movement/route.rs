@@ -0,0 +1,305 @@
+use std::fs::{create_dir_all, read_to_string, write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex as SyncMutex};
+use std::time::Duration;
+use async_broadcast::Sender as BroadcastSender;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tokio::time::sleep;
+
+use crate::primary::client::Position;
+use crate::primary::client::movement::controller::{self, HEARTBEAT_INTERVAL};
+use crate::primary::client::movement::types::{FollowTarget, PatrolMode, Route, RoutePoint};
+use crate::primary::errors::RouteError;
+use crate::primary::shared::session::Session;
+use crate::primary::shared::storage::DataStorage;
+use crate::primary::types::{HandlerOutput, ObjectGuid};
+
+pub const DEFAULT_ROUTES_PATH: &str = "routes";
+pub const DEFAULT_RECORD_INTERVAL: Duration = Duration::from_millis(1000);
+pub const DEFAULT_MARK_PAUSE: Duration = Duration::from_secs(5);
+
+// points closer than this to the previous one are not recorded
+const MIN_POINT_DISTANCE: f32 = 1.0;
+// patrol stops if character is further than this from the point after movement
+const REACH_TOLERANCE: f32 = 1.0;
+
+// parses commands like: record <name> [player name] | mark [pause seconds] | save | play <name> [loop|pingpong] | stop
+pub fn parse_command(command: &str) -> Result<HandlerOutput, RouteError> {
+    let mut parts = command.split_whitespace();
+    let action = parts.next().ok_or(RouteError::InvalidCommand)?;
+    let args: Vec<&str> = parts.collect();
+
+    match (action, args.as_slice()) {
+        ("record", [name, player_name @ ..]) => {
+            let source = match player_name.is_empty() {
+                true => None,
+                false => Some(FollowTarget::Name(player_name.join(" "))),
+            };
+
+            validate_name(name)?;
+            Ok(HandlerOutput::RecordRoute(name.to_string(), source))
+        },
+        ("mark", []) => Ok(HandlerOutput::MarkRoutePoint(DEFAULT_MARK_PAUSE.as_millis() as u64)),
+        ("mark", [seconds]) => {
+            let seconds = seconds.parse::<f32>().map_err(|_| RouteError::InvalidCommand)?;
+            Ok(HandlerOutput::MarkRoutePoint((seconds.max(0.0) * 1000.0) as u64))
+        },
+        ("save", []) => Ok(HandlerOutput::StopRecording),
+        ("play", [name, mode @ ..]) => {
+            let mode = match mode {
+                [] | ["loop"] => PatrolMode::Loop,
+                ["pingpong"] => PatrolMode::PingPong,
+                _ => return Err(RouteError::InvalidCommand),
+            };
+
+            validate_name(name)?;
+            Ok(HandlerOutput::PlayRoute(name.to_string(), mode))
+        },
+        ("stop", []) => Ok(HandlerOutput::StopRoute),
+        _ => Err(RouteError::InvalidCommand),
+    }
+}
+
+// name is used as file name, so it cannot point outside of the routes directory
+pub fn validate_name(name: &str) -> Result<(), RouteError> {
+    let is_valid = !name.is_empty()
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+
+    match is_valid {
+        true => Ok(()),
+        false => Err(RouteError::InvalidName(name.to_string())),
+    }
+}
+
+pub fn get_route_path<P: AsRef<Path>>(directory: P, name: &str) -> Result<PathBuf, RouteError> {
+    validate_name(name)?;
+    Ok(directory.as_ref().join(format!("{}.json", name)))
+}
+
+pub fn save_route<P: AsRef<Path>>(directory: P, route: &Route) -> Result<PathBuf, RouteError> {
+    let path = get_route_path(&directory, &route.name)?;
+    let content = serde_json::to_string_pretty(route)
+        .map_err(|err| RouteError::InvalidFormat(err, path.display().to_string()))?;
+
+    create_dir_all(&directory)
+        .and_then(|_| write(&path, content))
+        .map_err(|err| RouteError::CannotWrite(err, path.display().to_string()))?;
+
+    Ok(path)
+}
+
+pub fn load_route<P: AsRef<Path>>(directory: P, name: &str) -> Result<Route, RouteError> {
+    let path = get_route_path(directory, name)?;
+    let content = read_to_string(&path)
+        .map_err(|err| RouteError::CannotRead(err, path.display().to_string()))?;
+
+    serde_json::from_str(&content).map_err(|err| RouteError::InvalidFormat(err, path.display().to_string()))
+}
+
+pub fn validate_route(route: &Route, map_id: u32) -> Result<(), RouteError> {
+    if route.points.is_empty() {
+        return Err(RouteError::Empty(route.name.clone()));
+    }
+
+    if route.map_id != map_id {
+        return Err(RouteError::MapMismatch(route.name.clone(), route.map_id, map_id));
+    }
+
+    Ok(())
+}
+
+// returns false if point was too close to the previous one
+pub fn add_point(route: &mut Route, position: Position) -> bool {
+    let is_far_enough = route.points.last()
+        .map(|point| point.position.distance(&position) >= MIN_POINT_DISTANCE)
+        .unwrap_or(true);
+
+    if is_far_enough {
+        route.points.push(RoutePoint { position, pause: 0 });
+    }
+
+    is_far_enough
+}
+
+// own position or position of the player, which movement is tracked by detect_motion
+async fn get_source_position(
+    session: &Arc<Mutex<Session>>,
+    data_storage: &Arc<SyncMutex<DataStorage>>,
+    source: Option<ObjectGuid>,
+) -> Option<Position> {
+    match source {
+        Some(guid) => data_storage.lock().unwrap().objects.get_world_position(guid),
+        None => session.lock().await.me.as_ref().and_then(|me| me.position),
+    }
+}
+
+pub struct RouteRecorder {
+    route: Arc<SyncMutex<Route>>,
+    source: Option<ObjectGuid>,
+    task: JoinHandle<()>,
+}
+
+impl RouteRecorder {
+    pub fn start(
+        session: Arc<Mutex<Session>>,
+        data_storage: Arc<SyncMutex<DataStorage>>,
+        route: Route,
+        source: Option<ObjectGuid>,
+        interval: Duration,
+    ) -> Self {
+        let route = Arc::new(SyncMutex::new(route));
+
+        let task = tokio::spawn({
+            let route = Arc::clone(&route);
+            async move {
+                loop {
+                    if let Some(position) = get_source_position(&session, &data_storage, source).await {
+                        add_point(&mut route.lock().unwrap(), position);
+                    }
+
+                    sleep(interval).await;
+                }
+            }
+        });
+
+        Self { route, source, task }
+    }
+
+    // adds current position as a point to wait at
+    pub async fn mark(
+        &self,
+        session: &Arc<Mutex<Session>>,
+        data_storage: &Arc<SyncMutex<DataStorage>>,
+        pause: u64,
+    ) -> bool {
+        let position = get_source_position(session, data_storage, self.source).await;
+
+        let mut route = self.route.lock().unwrap();
+        if let Some(position) = position {
+            add_point(&mut route, position);
+        }
+
+        match route.points.last_mut() {
+            Some(point) => {
+                point.pause = pause;
+                true
+            },
+            None => false,
+        }
+    }
+
+    pub fn finish(self) -> Route {
+        self.task.abort();
+        self.route.lock().unwrap().clone()
+    }
+}
+
+// moves through the route until aborted, should be spawned as separate task
+pub async fn patrol(
+    session: Arc<Mutex<Session>>,
+    query_sender: BroadcastSender<HandlerOutput>,
+    route: Route,
+    mode: PatrolMode,
+) {
+    let mut is_reversed = false;
+
+    loop {
+        let points: Vec<&RoutePoint> = match is_reversed {
+            true => route.points.iter().rev().collect(),
+            false => route.points.iter().collect(),
+        };
+
+        let mut segment = Vec::new();
+        for point in points {
+            segment.push(point.position);
+
+            if point.pause > 0 {
+                if !move_through(&session, &query_sender, std::mem::take(&mut segment)).await {
+                    return;
+                }
+
+                sleep(Duration::from_millis(point.pause)).await;
+            }
+        }
+
+        if !move_through(&session, &query_sender, segment).await {
+            return;
+        }
+
+        if mode == PatrolMode::PingPong {
+            is_reversed = !is_reversed;
+        }
+
+        // route with single point should not spin without delay
+        sleep(HEARTBEAT_INTERVAL).await;
+    }
+}
+
+async fn move_through(
+    session: &Arc<Mutex<Session>>,
+    query_sender: &BroadcastSender<HandlerOutput>,
+    waypoints: Vec<Position>,
+) -> bool {
+    let Some(destination) = waypoints.last().copied() else {
+        return true;
+    };
+
+    controller::move_to(Arc::clone(session), query_sender.clone(), waypoints).await;
+
+    // move_to returns early (with error message) when character cannot move
+    session.lock().await.me.as_ref()
+        .and_then(|me| me.position)
+        .map(|position| position.distance(&destination) < REACH_TOLERANCE)
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use tempdir::TempDir;
+
+    use crate::primary::client::Position;
+    use crate::primary::client::movement::route::{add_point, load_route, parse_command, save_route};
+    use crate::primary::client::movement::types::{FollowTarget, PatrolMode, Route};
+    use crate::primary::errors::RouteError;
+    use crate::primary::types::HandlerOutput;
+
+    #[test]
+    fn test_parse_command() {
+        assert!(matches!(
+            parse_command("record market Some Player"),
+            Ok(HandlerOutput::RecordRoute(name, Some(FollowTarget::Name(player)))) if name == "market" && player == "Some Player"
+        ));
+        assert!(matches!(parse_command("mark 2.5"), Ok(HandlerOutput::MarkRoutePoint(2500))));
+        assert!(matches!(
+            parse_command("play market pingpong"),
+            Ok(HandlerOutput::PlayRoute(_, PatrolMode::PingPong))
+        ));
+        assert!(parse_command("play").is_err());
+        assert!(parse_command("play market backwards").is_err());
+        assert!(matches!(parse_command("record ../../x"), Err(RouteError::InvalidName(_))));
+        assert!(matches!(parse_command("play a/b"), Err(RouteError::InvalidName(_))));
+    }
+
+    #[test]
+    fn test_save_and_load_route() {
+        let temp_dir = TempDir::new("_routes").unwrap();
+
+        let mut route = Route::new("market", 571);
+        assert!(add_point(&mut route, Position::new(1.0, 2.0, 3.0, 0.0)));
+        assert!(!add_point(&mut route, Position::new(1.5, 2.0, 3.0, 0.0)));
+        assert!(add_point(&mut route, Position::new(5.0, 2.0, 3.0, 0.0)));
+        route.points[1].pause = 3000;
+
+        let path = save_route(temp_dir.path().join("nested"), &route).unwrap();
+        assert!(path.ends_with("nested/market.json"));
+
+        let loaded = load_route(temp_dir.path().join("nested"), "market").unwrap();
+        assert_eq!(loaded.map_id, 571);
+        assert_eq!(loaded.points.len(), 2);
+        assert_eq!(loaded.points[1].position.x, 5.0);
+        assert_eq!(loaded.points[1].pause, 3000);
+
+        temp_dir.close().unwrap();
+    }
+}
\ No newline at end of file
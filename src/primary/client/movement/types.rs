@@ -1,5 +1,8 @@
 use bitflags::bitflags;
 
+use crate::primary::client::Position;
+use crate::primary::types::ObjectGuid;
+
 bitflags! {
     #[derive(Default, Clone, Debug)]
     pub struct MovementFlags: u32 {
@@ -77,6 +80,7 @@ impl UnitMoveType {
 }
 
 bitflags! {
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
     pub struct SplineFlags: u32 {
         const NONE = 0x00000000;
         const DONE = 0x00000100;
@@ -104,4 +108,63 @@ bitflags! {
         const UNKNOWN12 = 0x40000000;
         const UNKNOWN13 = 0x80000000;
     }
+}
+
+#[derive(Debug, Clone)]
+pub enum FollowTarget {
+    Guid(ObjectGuid),
+    Name(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FollowStopReason {
+    Requested,
+    OutOfRange,
+    TargetLost,
+    // teleport, root or worldport
+    Interrupted,
+}
+
+#[derive(Debug, Clone)]
+pub enum FollowEvent {
+    Started(ObjectGuid),
+    Stopped(ObjectGuid, FollowStopReason),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JumpType {
+    Standing,
+    Forward,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PatrolMode {
+    #[default]
+    Loop,
+    PingPong,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoutePoint {
+    pub position: Position,
+    // milliseconds to wait after point is reached, marked points only
+    #[serde(default)]
+    pub pause: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Route {
+    pub name: String,
+    pub map_id: u32,
+    pub points: Vec<RoutePoint>,
+}
+
+impl Route {
+    pub fn new(name: &str, map_id: u32) -> Self {
+        Self {
+            name: name.to_string(),
+            map_id,
+            points: vec![],
+        }
+    }
 }
\ No newline at end of file
@@ -1,4 +1,10 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::OnceLock;
+
 use crate::opcodes;
+use crate::primary::errors::OpcodeError;
 
 opcodes! {
     pub struct Opcode {
@@ -1320,9 +1326,128 @@ opcodes! {
     }
 }
 
+impl Opcode {
+    // login opcodes share values with world ones and take precedence,
+    // they are declared first, so only few entries are checked before the world table
+    pub fn get_opcode_name(index: u32) -> Option<String> {
+        Self::ENTRIES.iter()
+            .take_while(|(_, _, is_login)| *is_login)
+            .find(|(_, value, _)| *value == index)
+            .map(|(name, _, _)| name.to_string())
+            .or_else(|| WorldOpcode::try_from(index).ok().map(|opcode| opcode.name().to_string()))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OpcodeDirection {
+    ClientToServer,
+    ServerToClient,
+    Bidirectional,
+}
+
+// world opcode resolved from the Opcode table, lookup tables are built once on first use
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WorldOpcode {
+    value: u16,
+    name: &'static str,
+}
+
+impl WorldOpcode {
+    pub fn value(&self) -> u16 {
+        self.value
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn direction(&self) -> OpcodeDirection {
+        if self.name.starts_with("CMSG_") {
+            OpcodeDirection::ClientToServer
+        } else if self.name.starts_with("SMSG_") {
+            OpcodeDirection::ServerToClient
+        } else {
+            OpcodeDirection::Bidirectional
+        }
+    }
+
+    fn names() -> &'static [Option<&'static str>] {
+        static NAMES: OnceLock<Vec<Option<&'static str>>> = OnceLock::new();
+        NAMES.get_or_init(|| {
+            let world_entries = Opcode::ENTRIES.iter().filter(|(_, _, is_login)| !is_login);
+            let size = world_entries.clone().map(|(_, value, _)| *value as usize + 1).max().unwrap_or(0);
+
+            let mut names = vec![None; size];
+            for (name, value, _) in world_entries {
+                names[*value as usize] = Some(*name);
+            }
+
+            names
+        })
+    }
+
+    fn values() -> &'static HashMap<&'static str, u16> {
+        static VALUES: OnceLock<HashMap<&'static str, u16>> = OnceLock::new();
+        VALUES.get_or_init(|| {
+            Opcode::ENTRIES.iter()
+                .filter(|(_, _, is_login)| !is_login)
+                .map(|(name, value, _)| (*name, *value as u16))
+                .collect()
+        })
+    }
+}
+
+impl TryFrom<u16> for WorldOpcode {
+    type Error = OpcodeError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Self::names().get(value as usize)
+            .copied()
+            .flatten()
+            .map(|name| Self { value, name })
+            .ok_or(OpcodeError::UnknownValue(value as u32))
+    }
+}
+
+impl TryFrom<u32> for WorldOpcode {
+    type Error = OpcodeError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        let value = u16::try_from(value).map_err(|_| OpcodeError::UnknownValue(value))?;
+        Self::try_from(value)
+    }
+}
+
+impl FromStr for WorldOpcode {
+    type Err = OpcodeError;
+
+    // names are case-insensitive, so "cmsg_ping" resolves same as "CMSG_PING"
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let name = name.trim().to_uppercase();
+
+        Self::values().get_key_value(name.as_str())
+            .map(|(name, value)| Self { value: *value, name })
+            .ok_or(OpcodeError::UnknownName(name))
+    }
+}
+
+impl Display for WorldOpcode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl From<WorldOpcode> for u32 {
+    fn from(opcode: WorldOpcode) -> Self {
+        opcode.value as u32
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::primary::client::Opcode;
+    use std::str::FromStr;
+
+    use crate::primary::client::{Opcode, OpcodeDirection, WorldOpcode};
 
     fn get_type_of<T>(_: &T) -> &str {
         std::any::type_name::<T>()
@@ -1342,6 +1467,7 @@ mod tests {
             Opcode::get_opcode_name(Opcode::MSG_MOVE_START_FORWARD as u32),
             Some("MSG_MOVE_START_FORWARD".to_string()),
         );
+        assert_eq!(Opcode::get_opcode_name(u32::MAX), None);
     }
 
     #[test]
@@ -1357,4 +1483,21 @@ mod tests {
         assert_eq!(get_type_of(&Opcode::CMSG_CHAR_ENUM), "u32");
         assert_eq!(get_type_of(&Opcode::MSG_MOVE_START_FORWARD), "u16");
     }
+
+    #[test]
+    fn test_world_opcode_lookup() {
+        let opcode = WorldOpcode::try_from(Opcode::CMSG_CHAR_ENUM as u16).unwrap();
+        assert_eq!(opcode.name(), "CMSG_CHAR_ENUM");
+        assert_eq!(opcode.direction(), OpcodeDirection::ClientToServer);
+        assert_eq!(opcode.to_string(), "CMSG_CHAR_ENUM");
+
+        let opcode = WorldOpcode::from_str("msg_move_start_forward").unwrap();
+        assert_eq!(opcode.value(), Opcode::MSG_MOVE_START_FORWARD);
+        assert_eq!(opcode.direction(), OpcodeDirection::Bidirectional);
+
+        // login opcodes share values with world opcodes, so they should not be resolved
+        assert_eq!(WorldOpcode::try_from(0u16).unwrap().name(), "MSG_NULL_ACTION");
+        assert!(WorldOpcode::from_str("LOGIN_PROOF").is_err());
+        assert!(WorldOpcode::try_from(u16::MAX).is_err());
+    }
 }
\ No newline at end of file
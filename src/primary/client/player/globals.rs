@@ -1,11 +1,12 @@
 use crate::primary::macros::with_opcode;
 use crate::primary::client::opcodes::Opcode;
+use crate::primary::types::ObjectGuid;
 
 with_opcode! {
     @world_opcode(Opcode::CMSG_NAME_QUERY)
     #[derive(WorldPacket, Serialize, Deserialize, Debug)]
     pub struct NameQueryOutcome {
-        pub guid: u64,
+        pub guid: ObjectGuid,
     }
 }
 
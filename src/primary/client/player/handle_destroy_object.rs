@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+
+use crate::primary::client::Opcode;
+use crate::primary::types::{HandlerInput, HandlerOutput, HandlerResult, ObjectGuid};
+use crate::primary::traits::packet_handler::PacketHandler;
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+struct Income {
+    guid: ObjectGuid,
+    is_death: u8,
+}
+
+pub struct Handler;
+#[async_trait]
+impl PacketHandler for Handler {
+    async fn handle(&mut self, input: &mut HandlerInput) -> HandlerResult {
+        let mut response = Vec::new();
+
+        let (Income { guid, .. }, json) = Income::from_binary(&input.data)?;
+
+        response.push(HandlerOutput::ResponseMessage(
+            Opcode::get_opcode_name(input.opcode as u32)
+                .unwrap_or(format!("Unknown opcode: {}", input.opcode)),
+            Some(json),
+        ));
+
+        if let Some(event) = input.data_storage.lock().unwrap().objects.remove(guid) {
+            response.push(HandlerOutput::ObjectEvent(event));
+        }
+
+        Ok(response)
+    }
+}
\ No newline at end of file
@@ -29,7 +29,10 @@ impl PacketHandler for Handler {
     async fn handle(&mut self, input: &mut HandlerInput) -> HandlerResult {
         let mut response = Vec::new();
 
-        let (CheckEmptyIncome { unknown, .. }, _) = CheckEmptyIncome::from_binary(&input.data)?;
+        let (CheckEmptyIncome { packed_guid, unknown }, _) = CheckEmptyIncome::from_binary(&input.data)?;
+
+        let PackedGuid(guid) = packed_guid;
+        input.data_storage.lock().unwrap().complete_name_query(guid);
 
         if unknown == 1 {
             response.push(HandlerOutput::ErrorMessage("Player not exists".to_string(), None));
@@ -38,7 +41,6 @@ impl PacketHandler for Handler {
         }
 
         let (Income {
-            packed_guid,
             name,
             race,
             class,
@@ -52,8 +54,6 @@ impl PacketHandler for Handler {
             Some(json),
         ));
 
-        let PackedGuid(guid) = packed_guid;
-
         let my_guid = {
             input.session.lock().await.me.as_ref().unwrap().guid
         };
@@ -1,10 +1,13 @@
+use std::collections::BTreeSet;
+
 use async_trait::async_trait;
 
-use crate::primary::client::{FieldValue, ObjectField, Player};
+use crate::primary::client::Player;
 use crate::primary::client::opcodes::Opcode;
 use crate::primary::client::player::globals::NameQueryOutcome;
 use crate::primary::client::player::types::Gender;
-use crate::primary::parsers::update_block_parser::types::{ObjectTypeMask, ParsedBlock};
+use crate::primary::parsers::update_block_parser::types::ParsedBlock;
+use crate::primary::shared::storage::objects::ObjectType;
 use crate::primary::types::{HandlerInput, HandlerOutput, HandlerResult};
 use crate::primary::traits::packet_handler::PacketHandler;
 
@@ -21,6 +24,21 @@ struct CompressedIncome {
 }
 
 pub struct Handler;
+
+impl Handler {
+    fn apply_block(player: &mut Player, parsed_block: &ParsedBlock) {
+        if let Some(movement_data) = &parsed_block.movement_data {
+            if let Some(movement_info) = &movement_data.movement_info {
+                player.position = Some(movement_info.position);
+            }
+
+            if !movement_data.movement_speed.is_empty() {
+                player.movement_speed = movement_data.movement_speed.clone();
+            }
+        }
+    }
+}
+
 #[async_trait]
 impl PacketHandler for Handler {
     async fn handle(&mut self, input: &mut HandlerInput) -> HandlerResult {
@@ -48,114 +66,213 @@ impl PacketHandler for Handler {
             input.session.lock().await.me.as_ref().unwrap().guid
         };
 
-        let mut players_map = {
-            let guard = input.data_storage.lock().unwrap();
-            guard.players_map.clone()
-        };
+        // blocks contain only changed fields, full set is kept by object manager
+        // and decoded once per packet for every updated player
+        let mut my_fields = None;
+        let mut name_queries = Vec::new();
+        {
+            let mut guard = input.data_storage.lock().unwrap();
+            let mut updated_guids = BTreeSet::new();
+            for parsed_block in parsed_blocks.iter() {
+                let events = guard.objects.apply_block(parsed_block);
+                response.extend(events.into_iter().map(HandlerOutput::ObjectEvent));
 
-        for parsed_block in parsed_blocks {
-            if parsed_block.guid.is_none() {
-                continue;
-            }
+                let Some(guid) = parsed_block.guid else {
+                    continue;
+                };
+
+                let is_player = guard.objects.get(guid)
+                    .map(|object| object.object_type == ObjectType::Player)
+                    .unwrap_or(false);
 
-            let guid = parsed_block.guid.unwrap();
-
-            if my_guid != guid {
-                match parsed_block.update_fields.get(&ObjectField::TYPE) {
-                    Some(type_mask) => {
-
-                        if let FieldValue::Integer(mask) = type_mask {
-                            match *mask {
-                                ObjectTypeMask::IS_PLAYER => {
-                                    if players_map.get(&guid).is_none() {
-                                        let mut player = Player {
-                                            guid,
-                                            .. Player::default()
-                                        };
-
-                                        if let Some(movement_data) = parsed_block.movement_data {
-                                            if let Some(movement_info) = movement_data.movement_info {
-                                                player.position = Some(movement_info.position);
-                                            }
-
-                                            if !movement_data.movement_speed.is_empty() {
-                                                player.movement_speed = movement_data.movement_speed;
-                                            }
-                                        }
-
-                                        if !parsed_block.update_fields.is_empty() {
-                                            player.fields = parsed_block.update_fields;
-                                        }
-
-                                        input.data_storage.lock()
-                                            .unwrap().players_map.insert(guid, player);
-
-                                        return Ok(
-                                            vec![HandlerOutput::Data(NameQueryOutcome { guid }.unpack()?)]
-                                        );
-                                    }
-                                },
-                                ObjectTypeMask::IS_UNIT => {},
-                                _ => {},
-                            }
-                        }
-                    },
-                    None => {
-                        if players_map.get(&guid).is_none() {
-                            let mut player = Player::new(guid, String::new(), 0, 0, Gender::GENDER_NONE, 1);
-
-                            if let Some(movement_data) = parsed_block.movement_data {
-                                if let Some(movement_info) = movement_data.movement_info {
-                                    player.position = Some(movement_info.position);
-                                }
-
-                                if !movement_data.movement_speed.is_empty() {
-                                    player.movement_speed = movement_data.movement_speed;
-                                }
-                            }
-
-                            if !parsed_block.update_fields.is_empty() {
-                                player.fields = parsed_block.update_fields;
-                            }
-
-                            input.data_storage.lock().unwrap().players_map.insert(guid, player);
-
-                            return Ok(vec![HandlerOutput::Data(NameQueryOutcome { guid }.unpack()?)]);
-                        } else {
-                            players_map.entry(guid).and_modify(|p| {
-                                if let Some(movement_data) = parsed_block.movement_data {
-                                    if let Some(movement_info) = movement_data.movement_info {
-                                        p.position = Some(movement_info.position);
-                                    }
-
-                                    if !movement_data.movement_speed.is_empty() {
-                                        p.movement_speed = movement_data.movement_speed;
-                                    }
-                                }
-                            });
-                        }
-                    },
+                if (guid == my_guid || is_player) && !parsed_block.raw_fields.is_empty() {
+                    updated_guids.insert(guid);
                 }
-            } else {
-                if let Some(movement_data) = parsed_block.movement_data {
-                    if let Some(movement_info) = movement_data.movement_info {
-                        input.session.lock().await.me.as_mut().unwrap().position = Some(movement_info.position);
-                    }
-
-                    if !movement_data.movement_speed.is_empty() {
-                        input.session.lock().await.me.as_mut().unwrap().movement_speed = movement_data.movement_speed;
-                    }
+
+                // current player stored inside Session instance
+                if guid == my_guid || !is_player {
+                    continue;
                 }
 
-                if !parsed_block.update_fields.is_empty() {
-                    input.session.lock().await.me.as_mut().unwrap().fields = parsed_block.update_fields;
+                let player = guard.players_map.entry(guid).or_insert_with(|| {
+                    Player::new(guid, String::new(), 0, 0, Gender::GENDER_NONE, 1)
+                });
+
+                Self::apply_block(player, parsed_block);
+
+                if player.name.is_empty() && guard.add_name_query(guid) {
+                    name_queries.push(guid);
                 }
+            }
 
-                let me = input.session.lock().await.me.clone().unwrap();
-                response.push(HandlerOutput::UpdatePlayer(me));
+            for guid in updated_guids {
+                let Some(fields) = guard.objects.get(guid).map(|object| object.fields.to_fields()) else {
+                    continue;
+                };
+
+                if guid == my_guid {
+                    my_fields = Some(fields);
+                } else if let Some(player) = guard.players_map.get_mut(&guid) {
+                    player.fields = fields;
+                }
+            }
+        }
+
+        if parsed_blocks.iter().any(|block| block.guid == Some(my_guid)) {
+            let mut guard = input.session.lock().await;
+            let me = guard.me.as_mut().unwrap();
+            for parsed_block in parsed_blocks.iter().filter(|block| block.guid == Some(my_guid)) {
+                Self::apply_block(me, parsed_block);
+            }
+
+            if let Some(fields) = my_fields {
+                me.fields = fields;
             }
+
+            response.push(HandlerOutput::UpdatePlayer(me.clone()));
+        }
+
+        for guid in name_queries {
+            response.push(HandlerOutput::Data(NameQueryOutcome { guid }.unpack()?));
         }
 
         Ok(response)
     }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::{Arc, Mutex as SyncMutex};
+    use tokio::sync::Mutex;
+
+    use crate::primary::client::{FieldValue, MovementInfo, ObjectField, Opcode, Player, Position, UnitField};
+    use crate::primary::client::player::handle_update_data::Handler;
+    use crate::primary::parsers::update_block_parser::UpdateBlocksParser;
+    use crate::primary::parsers::update_block_parser::types::{
+        encode_update_fields, MovementData, ObjectTypeMask, ParsedBlock,
+    };
+    use crate::primary::shared::session::Session;
+    use crate::primary::shared::storage::DataStorage;
+    use crate::primary::traits::packet_handler::PacketHandler;
+    use crate::primary::types::{HandlerInput, HandlerOutput, ObjectGuid};
+
+    const MY_GUID: u64 = 1;
+    const OTHER_GUID: u64 = 2;
+
+    fn create_player_block(guid: u64, position: Position) -> ParsedBlock {
+        let mut update_fields = BTreeMap::new();
+        update_fields.insert(ObjectField::TYPE, FieldValue::Integer(ObjectTypeMask::IS_PLAYER));
+
+        ParsedBlock {
+            guid: Some(ObjectGuid::new(guid)),
+            raw_fields: encode_update_fields(&update_fields),
+            movement_data: Some(MovementData {
+                movement_info: Some(MovementInfo { position, ..MovementInfo::default() }),
+                ..MovementData::new()
+            }),
+            ..ParsedBlock::new()
+        }
+    }
+
+    fn create_values_block(guid: u64, index: u32, value: u32) -> ParsedBlock {
+        let mut update_fields = BTreeMap::new();
+        update_fields.insert(index, FieldValue::Integer(value));
+
+        ParsedBlock {
+            guid: Some(ObjectGuid::new(guid)),
+            raw_fields: encode_update_fields(&update_fields),
+            ..ParsedBlock::new()
+        }
+    }
+
+    async fn handle(input: &mut HandlerInput, parsed_blocks: &[ParsedBlock]) -> Vec<HandlerOutput> {
+        let mut data = Vec::new();
+        UpdateBlocksParser::write(&mut data, parsed_blocks).unwrap();
+        input.data = data;
+
+        Handler.handle(input).await.unwrap()
+    }
+
+    fn count_name_queries(response: &[HandlerOutput]) -> usize {
+        response.iter()
+            .filter(|output| matches!(output, HandlerOutput::Data(packet) if packet.opcode == Opcode::CMSG_NAME_QUERY))
+            .count()
+    }
+
+    fn create_input() -> HandlerInput {
+        let mut session = Session::new();
+        session.me = Some(Player::new(ObjectGuid::new(MY_GUID), "Me".to_string(), 1, 1, 0, 1));
+
+        HandlerInput {
+            session: Arc::new(Mutex::new(session)),
+            data: Vec::new(),
+            data_storage: Arc::new(SyncMutex::new(DataStorage::new())),
+            opcode: Opcode::SMSG_UPDATE_OBJECT,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_multiple_blocks() {
+        let mut input = create_input();
+
+        let response = handle(&mut input, &[
+            create_player_block(MY_GUID, Position::new(1.0, 2.0, 3.0, 0.0)),
+            create_player_block(OTHER_GUID, Position::new(4.0, 5.0, 6.0, 0.0)),
+            create_values_block(OTHER_GUID, UnitField::HEALTH, 100),
+            create_values_block(MY_GUID, UnitField::LEVEL, 10),
+        ]).await;
+
+        {
+            let guard = input.session.lock().await;
+            let me = guard.me.as_ref().unwrap();
+            assert_eq!(me.position.unwrap().x, 1.0);
+            assert_eq!(me.fields.get(&UnitField::LEVEL), Some(&FieldValue::Integer(10)));
+        }
+
+        {
+            let guard = input.data_storage.lock().unwrap();
+            assert_eq!(guard.objects.len(), 2);
+
+            // fields of both blocks are merged
+            let player = guard.players_map.get(&ObjectGuid::new(OTHER_GUID)).unwrap();
+            assert_eq!(player.position.unwrap().x, 4.0);
+            assert!(player.fields.contains_key(&ObjectField::TYPE));
+            assert_eq!(player.fields.get(&UnitField::HEALTH), Some(&FieldValue::Integer(100)));
+            assert!(!guard.players_map.contains_key(&ObjectGuid::new(MY_GUID)));
+        }
+
+        assert_eq!(response.iter().filter(|output| matches!(output, HandlerOutput::UpdatePlayer(_))).count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_name_query_dedup() {
+        let mut input = create_input();
+
+        // second block of the same player does not send one more query
+        let response = handle(&mut input, &[
+            create_player_block(OTHER_GUID, Position::new(4.0, 5.0, 6.0, 0.0)),
+            create_values_block(OTHER_GUID, UnitField::HEALTH, 100),
+        ]).await;
+        assert_eq!(count_name_queries(&response), 1);
+
+        // query is still pending
+        let response = handle(&mut input, &[create_values_block(OTHER_GUID, UnitField::HEALTH, 50)]).await;
+        assert_eq!(count_name_queries(&response), 0);
+
+        // worldport or logout drops pending queries, so player is queried again
+        input.data_storage.lock().unwrap().clear_name_queries();
+        let response = handle(&mut input, &[create_values_block(OTHER_GUID, UnitField::HEALTH, 50)]).await;
+        assert_eq!(count_name_queries(&response), 1);
+
+        // player with known name is not queried
+        {
+            let mut guard = input.data_storage.lock().unwrap();
+            guard.players_map.get_mut(&ObjectGuid::new(OTHER_GUID)).unwrap().name = "Other".to_string();
+            guard.clear_name_queries();
+        }
+        let response = handle(&mut input, &[create_values_block(OTHER_GUID, UnitField::HEALTH, 10)]).await;
+        assert_eq!(count_name_queries(&response), 0);
+    }
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
 pub mod globals;
+mod handle_destroy_object;
 mod handle_name_query_response;
 mod handle_update_data;
 pub mod types;
@@ -6,6 +7,7 @@ pub mod get_characters_list;
 pub mod player_login;
 mod check_character_create_status;
 mod traits;
+pub mod views;
 
 use crate::primary::client::opcodes::Opcode;
 use crate::primary::traits::processor::Processor;
@@ -22,29 +24,16 @@ impl Processor for PlayerProcessor {
                     Box::new(handle_update_data::Handler),
                 ]
             },
-            Opcode::SMSG_GROUP_INVITE => {
-                vec![]
+            Opcode::SMSG_DESTROY_OBJECT => {
+                vec![
+                    Box::new(handle_destroy_object::Handler),
+                ]
             },
             Opcode::SMSG_NAME_QUERY_RESPONSE => {
                 vec![
                     Box::new(handle_name_query_response::Handler),
                 ]
             },
-            Opcode::SMSG_SET_PCT_SPELL_MODIFIER => {
-                vec![]
-            },
-            Opcode::SMSG_TALENT_UPDATE => {
-                vec![]
-            },
-            Opcode::MSG_SET_DUNGEON_DIFFICULTY => {
-                vec![]
-            },
-            Opcode::SMSG_QUESTGIVER_STATUS_MULTIPLE => {
-                vec![]
-            },
-            Opcode::SMSG_ACHIEVEMENT_EARNED => {
-                vec![]
-            },
             Opcode::SMSG_CHAR_ENUM => {
                 vec![
                     Box::new(get_characters_list::Handler),
@@ -4,14 +4,14 @@ use async_trait::async_trait;
 use crate::primary::macros::with_opcode;
 use crate::primary::client::opcodes::Opcode;
 use crate::primary::errors::CharacterListError;
-use crate::primary::types::{HandlerInput, HandlerOutput, HandlerResult};
+use crate::primary::types::{HandlerInput, HandlerOutput, HandlerResult, ObjectGuid};
 use crate::primary::traits::packet_handler::PacketHandler;
 
 with_opcode! {
     @world_opcode(Opcode::CMSG_PLAYER_LOGIN)
     #[derive(WorldPacket, Serialize, Deserialize, Debug)]
     struct Outcome {
-        guid: u64,
+        guid: ObjectGuid,
     }
 }
 
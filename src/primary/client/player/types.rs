@@ -4,12 +4,14 @@ use bitflags::bitflags;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde::ser::{SerializeStruct, SerializeTuple};
 
+use crate::primary::client::player::views::PlayerView;
 use crate::primary::parsers::position_parser::types::Position;
 use crate::primary::parsers::update_block_parser::types::UpdateFields;
+use crate::primary::types::ObjectGuid;
 
 #[derive(Clone, Default)]
 pub struct Player {
-    pub guid: u64,
+    pub guid: ObjectGuid,
     pub name: String,
     pub race: u8,
     pub class: u8,
@@ -18,10 +20,11 @@ pub struct Player {
     pub fields: UpdateFields,
     pub movement_speed: BTreeMap<u8, f32>,
     pub position: Option<Position>,
+    pub map_id: u32,
 }
 
 impl Player {
-    pub fn new(guid: u64, name: String, race: u8, class: u8, gender: u8, level: u8) -> Self {
+    pub fn new(guid: ObjectGuid, name: String, race: u8, class: u8, gender: u8, level: u8) -> Self {
         Self {
             guid,
             name,
@@ -32,8 +35,13 @@ impl Player {
             fields: BTreeMap::new(),
             movement_speed: BTreeMap::new(),
             position: None,
+            map_id: 0,
         }
     }
+
+    pub fn view(&self) -> PlayerView<'_> {
+        PlayerView::new(&self.fields)
+    }
 }
 
 impl Debug for Player {
@@ -41,12 +49,13 @@ impl Debug for Player {
         write!(
             f,
             "guid: {:?}, name: {:?}, race: {:?}, class: {:?}\n \
-            position: {:?}\nFields: \n{:?}\nMovement speed:\n{:?}",
+            position: {:?}, map_id: {:?}\nFields: \n{:?}\nMovement speed:\n{:?}",
             self.guid,
             self.name,
             self.race,
             self.class,
             self.position,
+            self.map_id,
             self.fields,
             self.movement_speed,
         )
@@ -54,14 +63,42 @@ impl Debug for Player {
 }
 
 impl<'de> Deserialize<'de> for Player {
-    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
-        todo!()
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        #[derive(Deserialize)]
+        struct RawPlayer {
+            guid: ObjectGuid,
+            name: String,
+            race: u8,
+            class: u8,
+            gender: u8,
+            level: u8,
+            position: Option<Position>,
+            #[serde(default)]
+            map_id: u32,
+        }
+
+        let RawPlayer {
+            guid, name, race, class, gender, level, position, map_id,
+        } = RawPlayer::deserialize(deserializer)?;
+
+        Ok(Self {
+            guid,
+            name,
+            race,
+            class,
+            gender,
+            level,
+            fields: BTreeMap::new(),
+            movement_speed: BTreeMap::new(),
+            position,
+            map_id,
+        })
     }
 }
 
 impl Serialize for Player {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
-        const FIELDS_AMOUNT: usize = 7;
+        const FIELDS_AMOUNT: usize = 8;
         let mut state = serializer.serialize_struct("Character", FIELDS_AMOUNT)?;
         state.serialize_field("guid", &self.guid)?;
         state.serialize_field("name", &self.name)?;
@@ -70,6 +107,7 @@ impl Serialize for Player {
         state.serialize_field("gender", &self.gender)?;
         state.serialize_field("level", &self.level)?;
         state.serialize_field("position", &self.position)?;
+        state.serialize_field("map_id", &self.map_id)?;
         state.end()
     }
 }
@@ -139,7 +177,7 @@ pub enum FieldType {
     None,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum FieldValue {
     Integer(u32),
     Long(u64),
@@ -854,6 +892,9 @@ impl PlayerField {
     pub const GLYPHS_ENABLED: u32 = 1324;
     pub const PET_SPELL_POWER: u32 = 1325;
 
+    // for check
+    pub const LIMIT: u32 = 1326;
+
     pub fn get_field_type(field_index: u32) -> FieldType {
         match field_index {
             PlayerField::DUEL_ARBITER => FieldType::Long,
@@ -1140,6 +1181,9 @@ impl PlayerField {
             PlayerField::GLYPHS_6 => FieldType::Integer,
             PlayerField::GLYPHS_ENABLED => FieldType::Integer,
             PlayerField::PET_SPELL_POWER => FieldType::Integer,
+            // every slot takes two fields, only first slot of each range has a name
+            index if (PlayerField::PACK_SLOT_1..PlayerField::FARSIGHT).contains(&index)
+                && (index - PlayerField::PACK_SLOT_1).is_multiple_of(2) => FieldType::Long,
             _ => FieldType::None,
         }
     }
@@ -1605,4 +1649,32 @@ impl CharacterCreateResponseCode {
     pub const CHAR_CREATE_CHARACTER_RACE_ONLY: u8 = 67;
     pub const CHAR_CREATE_CHARACTER_GOLD_LIMIT: u8 = 68;
     pub const CHAR_CREATE_FORCE_LOGIN: u8 = 69;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primary::client::Position;
+    use crate::primary::client::player::types::{Gender, Player, Race};
+    use crate::primary::types::ObjectGuid;
+
+    #[test]
+    fn test_json_round_trip() {
+        let mut player = Player::new(ObjectGuid::new(42), "Name".to_string(), Race::ORC, 1, Gender::GENDER_FEMALE, 80);
+        player.position = Some(Position::new(1.0, 2.0, 3.0, 0.5));
+        player.map_id = 571;
+
+        let json = serde_json::to_string(&player).unwrap();
+        let restored: Player = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.guid, player.guid);
+        assert_eq!(restored.name, player.name);
+        assert_eq!(restored.gender, Gender::GENDER_FEMALE);
+        assert_eq!(restored.map_id, 571);
+        assert_eq!(restored.position.unwrap().orientation, 0.5);
+        assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+
+        // map id is absent in older dumps
+        let json = json.replace(",\"map_id\":571", "");
+        assert_eq!(serde_json::from_str::<Player>(&json).unwrap().map_id, 0);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,245 @@
+use std::ops::Deref;
+
+use crate::primary::client::{FieldValue, PlayerField, UnitField};
+use crate::primary::client::player::types::{PlayerFlags, UnitFlags, UnitFlags2};
+use crate::primary::shared::storage::fields::FieldSource;
+use crate::primary::types::ObjectGuid;
+
+// equipment (19) + bags (4) + backpack (16)
+pub const INVENTORY_SLOTS_AMOUNT: u8 = 39;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerType {
+    Mana = 0,
+    Rage = 1,
+    Focus = 2,
+    Energy = 3,
+    Happiness = 4,
+    Rune = 5,
+    RunicPower = 6,
+}
+
+impl PowerType {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Mana),
+            1 => Some(Self::Rage),
+            2 => Some(Self::Focus),
+            3 => Some(Self::Energy),
+            4 => Some(Self::Happiness),
+            5 => Some(Self::Rune),
+            6 => Some(Self::RunicPower),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stat {
+    Strength = 0,
+    Agility = 1,
+    Stamina = 2,
+    Intellect = 3,
+    Spirit = 4,
+}
+
+// getters return None until the field is received
+#[derive(Debug, Clone, Copy)]
+pub struct UnitView<'a> {
+    fields: &'a dyn FieldSource,
+}
+
+impl<'a> UnitView<'a> {
+    pub fn new(fields: &'a dyn FieldSource) -> Self {
+        Self { fields }
+    }
+
+    pub fn health(&self) -> Option<u32> {
+        self.get_u32(UnitField::HEALTH)
+    }
+
+    pub fn max_health(&self) -> Option<u32> {
+        self.get_u32(UnitField::MAXHEALTH)
+    }
+
+    pub fn power(&self, power_type: PowerType) -> Option<u32> {
+        self.get_u32(UnitField::POWER1 + power_type as u32)
+    }
+
+    pub fn max_power(&self, power_type: PowerType) -> Option<u32> {
+        self.get_u32(UnitField::MAXPOWER1 + power_type as u32)
+    }
+
+    pub fn level(&self) -> Option<u32> {
+        self.get_u32(UnitField::LEVEL)
+    }
+
+    pub fn faction_template(&self) -> Option<u32> {
+        self.get_u32(UnitField::FACTIONTEMPLATE)
+    }
+
+    pub fn race(&self) -> Option<u8> {
+        self.get_byte(UnitField::BYTES_0, 0)
+    }
+
+    pub fn class(&self) -> Option<u8> {
+        self.get_byte(UnitField::BYTES_0, 1)
+    }
+
+    pub fn gender(&self) -> Option<u8> {
+        self.get_byte(UnitField::BYTES_0, 2)
+    }
+
+    pub fn power_type(&self) -> Option<PowerType> {
+        self.get_byte(UnitField::BYTES_0, 3).and_then(PowerType::from_u8)
+    }
+
+    pub fn unit_flags(&self) -> Option<UnitFlags> {
+        self.get_u32(UnitField::FLAGS).map(UnitFlags::from_bits_retain)
+    }
+
+    pub fn unit_flags2(&self) -> Option<UnitFlags2> {
+        self.get_u32(UnitField::FLAGS_2).map(UnitFlags2::from_bits_retain)
+    }
+
+    pub fn target(&self) -> Option<ObjectGuid> {
+        self.get_u64(UnitField::TARGET).map(ObjectGuid::new)
+    }
+
+    pub fn display_id(&self) -> Option<u32> {
+        self.get_u32(UnitField::DISPLAYID)
+    }
+
+    pub fn native_display_id(&self) -> Option<u32> {
+        self.get_u32(UnitField::NATIVEDISPLAYID)
+    }
+
+    pub fn mount_display_id(&self) -> Option<u32> {
+        self.get_u32(UnitField::MOUNTDISPLAYID)
+    }
+
+    pub fn stat(&self, stat: Stat) -> Option<u32> {
+        self.get_u32(UnitField::STAT0 + stat as u32)
+    }
+
+    pub fn get_u32(&self, index: u32) -> Option<u32> {
+        match self.fields.get_field(index)? {
+            FieldValue::Integer(value) | FieldValue::Bytes(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn get_u64(&self, index: u32) -> Option<u64> {
+        match self.fields.get_field(index)? {
+            FieldValue::Long(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn get_f32(&self, index: u32) -> Option<f32> {
+        match self.fields.get_field(index)? {
+            FieldValue::Float(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn get_byte(&self, index: u32, offset: u8) -> Option<u8> {
+        self.get_u32(index).map(|value| (value >> (offset * 8)) as u8)
+    }
+}
+
+// unit getters are available through deref
+#[derive(Debug, Clone, Copy)]
+pub struct PlayerView<'a> {
+    unit: UnitView<'a>,
+}
+
+impl<'a> PlayerView<'a> {
+    pub fn new(fields: &'a dyn FieldSource) -> Self {
+        Self { unit: UnitView::new(fields) }
+    }
+
+    pub fn player_flags(&self) -> Option<PlayerFlags> {
+        self.get_u32(PlayerField::FLAGS).map(PlayerFlags::from_bits_retain)
+    }
+
+    pub fn xp(&self) -> Option<u32> {
+        self.get_u32(PlayerField::XP)
+    }
+
+    pub fn next_level_xp(&self) -> Option<u32> {
+        self.get_u32(PlayerField::NEXT_LEVEL_XP)
+    }
+
+    // in copper
+    pub fn money(&self) -> Option<u32> {
+        self.get_u32(PlayerField::COINAGE)
+    }
+
+    pub fn inventory_slot(&self, slot: u8) -> Option<ObjectGuid> {
+        if slot >= INVENTORY_SLOTS_AMOUNT {
+            return None;
+        }
+
+        self.get_u64(PlayerField::INV_SLOT_HEAD + slot as u32 * 2).map(ObjectGuid::new)
+    }
+
+    // returns only non-empty slots
+    pub fn inventory_slots(&self) -> Vec<(u8, ObjectGuid)> {
+        (0..INVENTORY_SLOTS_AMOUNT)
+            .filter_map(|slot| self.inventory_slot(slot).map(|guid| (slot, guid)))
+            .filter(|(_, guid)| !guid.is_empty())
+            .collect()
+    }
+}
+
+impl<'a> Deref for PlayerView<'a> {
+    type Target = UnitView<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.unit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::primary::client::{FieldValue, PlayerField, UnitField};
+    use crate::primary::client::player::types::{PlayerFlags, UnitFlags};
+    use crate::primary::client::player::views::{PlayerView, PowerType, Stat};
+    use crate::primary::types::ObjectGuid;
+
+    #[test]
+    fn test_player_view() {
+        let mut fields = BTreeMap::new();
+        fields.insert(UnitField::HEALTH, FieldValue::Integer(80));
+        fields.insert(UnitField::MAXHEALTH, FieldValue::Integer(100));
+        fields.insert(UnitField::POWER1 + 1, FieldValue::Integer(30));
+        fields.insert(UnitField::BYTES_0, FieldValue::Bytes(0x01_00_01_02));
+        fields.insert(UnitField::FLAGS, FieldValue::Integer(UnitFlags::IN_COMBAT.bits()));
+        fields.insert(UnitField::TARGET, FieldValue::Long(42));
+        fields.insert(UnitField::STAT0 + 2, FieldValue::Integer(25));
+        fields.insert(PlayerField::FLAGS, FieldValue::Integer(PlayerFlags::AFK.bits()));
+        fields.insert(PlayerField::COINAGE, FieldValue::Integer(12345));
+        fields.insert(PlayerField::INV_SLOT_HEAD + 2, FieldValue::Long(7));
+        fields.insert(PlayerField::PACK_SLOT_1 + 4, FieldValue::Long(9));
+
+        let player = PlayerView::new(&fields);
+
+        assert_eq!(player.health(), Some(80));
+        assert_eq!(player.max_health(), Some(100));
+        assert_eq!(player.power(PowerType::Rage), Some(30));
+        assert_eq!(player.power(PowerType::Mana), None);
+        assert_eq!(player.race(), Some(2));
+        assert_eq!(player.class(), Some(1));
+        assert_eq!(player.power_type(), Some(PowerType::Rage));
+        assert!(player.unit_flags().unwrap().contains(UnitFlags::IN_COMBAT));
+        assert_eq!(player.target(), Some(ObjectGuid::new(42)));
+        assert_eq!(player.stat(Stat::Stamina), Some(25));
+        assert_eq!(player.player_flags(), Some(PlayerFlags::AFK));
+        assert_eq!(player.money(), Some(12345));
+        assert_eq!(player.inventory_slots(), vec![(1, ObjectGuid::new(7)), (25, ObjectGuid::new(9))]);
+        assert_eq!(player.inventory_slot(39), None);
+    }
+}
\ No newline at end of file
@@ -7,7 +7,11 @@ use crate::types::HandlerOutput;
 pub struct Handler;
 #[async_trait]
 impl PacketHandler for Handler {
-    async fn handle(&mut self, _: &mut HandlerInput) -> HandlerResult {
+    async fn handle(&mut self, input: &mut HandlerInput) -> HandlerResult {
+        input.data_storage.lock().unwrap().clear_name_queries();
+        // next login should report location again, even on the same map
+        input.session.lock().await.location = None;
+
         let response = vec![HandlerOutput::ExitConfirmed];
 
         Ok(response)
@@ -30,39 +30,15 @@ impl Processor for RealmProcessor {
                     Box::new(realm_split::Handler),
                 ]
             },
-            Opcode::SMSG_ADDON_INFO => {
-                vec![]
-            },
-            Opcode::SMSG_CLIENTCACHE_VERSION => {
-                vec![]
-            },
-            Opcode::SMSG_TUTORIAL_FLAGS => {
-                vec![]
-            },
             Opcode::SMSG_LOGIN_VERIFY_WORLD => {
                 vec![
                     Box::new(join_channels::Handler),
                     Box::new(set_in_world::Handler),
                 ]
             },
-            Opcode::SMSG_CHAR_ENUM => {
-                vec![]
-            },
-            Opcode::SMSG_ACCOUNT_DATA_TIMES => {
-                vec![]
-            },
-            Opcode::SMSG_REALM_SPLIT => {
-                vec![]
-            },
             Opcode::SMSG_MOTD => {
                 vec![Box::new(parse_motd::Handler)]
             },
-            Opcode::SMSG_LOGIN_SETTIMESPEED => {
-                vec![]
-            },
-            Opcode::SMSG_SET_FORCED_REACTIONS => {
-                vec![]
-            },
             Opcode::SMSG_LOGOUT_COMPLETE => {
                 vec![Box::new(logout::Handler)]
             }
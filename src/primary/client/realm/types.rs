@@ -29,14 +29,31 @@ impl Debug for Realm {
 }
 
 impl<'de> Deserialize<'de> for Realm {
-    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
-        todo!()
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        #[derive(Deserialize)]
+        struct RawRealm {
+            icon: u8,
+            lock: u8,
+            flags: u8,
+            name: String,
+            address: String,
+            population: f32,
+            characters: u8,
+            timezone: u8,
+            server_id: u8,
+        }
+
+        let RawRealm {
+            icon, lock, flags, name, address, population, characters, timezone, server_id,
+        } = RawRealm::deserialize(deserializer)?;
+
+        Ok(Self { icon, lock, flags, name, address, population, characters, timezone, server_id })
     }
 }
 
 impl Serialize for Realm {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
-        const FIELDS_AMOUNT: usize = 8;
+        const FIELDS_AMOUNT: usize = 9;
         let mut state = serializer.serialize_struct("Realm", FIELDS_AMOUNT)?;
         state.serialize_field("icon", &self.icon)?;
         state.serialize_field("lock", &self.lock)?;
@@ -49,4 +66,32 @@ impl Serialize for Realm {
         state.serialize_field("server_id", &self.server_id)?;
         state.end()
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primary::client::realm::types::Realm;
+
+    #[test]
+    fn test_json_round_trip() {
+        let realm = Realm {
+            icon: 1,
+            lock: 0,
+            flags: 2,
+            name: "Test Realm".to_string(),
+            address: "127.0.0.1:8085".to_string(),
+            population: 0.5,
+            characters: 3,
+            timezone: 8,
+            server_id: 1,
+        };
+
+        let json = serde_json::to_string(&realm).unwrap();
+        let restored: Realm = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.name, realm.name);
+        assert_eq!(restored.address, realm.address);
+        assert_eq!(restored.population, 0.5);
+        assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+    }
 }
\ No newline at end of file
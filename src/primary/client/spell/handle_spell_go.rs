@@ -2,7 +2,7 @@ use async_trait::async_trait;
 
 use crate::primary::client::Opcode;
 use crate::primary::shared::session::types::ActionFlags;
-use crate::primary::types::{HandlerInput, HandlerOutput, HandlerResult, PackedGuid};
+use crate::primary::types::{HandlerInput, HandlerOutput, HandlerResult, ObjectGuid, PackedGuid};
 use crate::primary::traits::packet_handler::PacketHandler;
 
 #[derive(WorldPacket, Serialize, Deserialize, Debug)]
@@ -26,7 +26,7 @@ impl PacketHandler for Handler {
             Some(json),
         ));
 
-        let my_guid: Option<u64> = {
+        let my_guid: Option<ObjectGuid> = {
             let guard = input.session.lock().await;
             guard.me.as_ref().map(|player| player.guid)
         };
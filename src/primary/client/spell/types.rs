@@ -254,8 +254,14 @@ pub struct Spell {
 }
 
 impl<'de> Deserialize<'de> for Spell {
-    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
-        todo!()
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        #[derive(Deserialize)]
+        struct RawSpell {
+            spell_id: u32,
+        }
+
+        let RawSpell { spell_id } = RawSpell::deserialize(deserializer)?;
+        Ok(Self { spell_id })
     }
 }
 
@@ -278,8 +284,21 @@ pub struct CooldownInfo {
 }
 
 impl<'de> Deserialize<'de> for CooldownInfo {
-    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
-        todo!()
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        #[derive(Deserialize)]
+        struct RawCooldownInfo {
+            spell_id: u32,
+            item_id: u16,
+            spell_category: u16,
+            cooldown_duration: u32,
+            cooldown_category: u32,
+        }
+
+        let RawCooldownInfo {
+            spell_id, item_id, spell_category, cooldown_duration, cooldown_category,
+        } = RawCooldownInfo::deserialize(deserializer)?;
+
+        Ok(Self { spell_id, item_id, spell_category, cooldown_duration, cooldown_category })
     }
 }
 
@@ -1,4 +1,3 @@
-use std::io::{BufRead};
 use async_trait::async_trait;
 
 use crate::primary::macros::with_opcode;
@@ -28,19 +27,11 @@ struct ModuleUseIncome {
 #[options(no_opcode)]
 struct ModuleCacheIncome {
     partial_size: u16,
-    #[dynamic_field]
+    #[length_from = "partial_size"]
     #[serde(serialize_with = "crate::primary::serializers::array_serializer::serialize_array")]
     partial: Vec<u8>,
 }
 
-impl ModuleCacheIncome {
-    fn partial<R: BufRead>(mut reader: R, initial: &mut Self) -> Vec<u8> {
-        let mut buffer = vec![0u8; initial.partial_size as usize];
-        reader.read_exact(&mut buffer).unwrap();
-        buffer
-    }
-}
-
 #[derive(WorldPacket, Serialize, Deserialize, Debug, Default)]
 #[options(no_opcode)]
 struct HashRequestIncome {
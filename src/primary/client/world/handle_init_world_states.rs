@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+
+use crate::primary::client::Opcode;
+use crate::primary::client::world::types::{WorldEvent, WorldLocation};
+use crate::primary::types::{HandlerInput, HandlerOutput, HandlerResult};
+use crate::primary::traits::packet_handler::PacketHandler;
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+struct Income {
+    map_id: u32,
+    zone_id: u32,
+    area_id: u32,
+    count: u16,
+    // pairs of (state, value)
+    #[count_from = "count * 2"]
+    states: Vec<u32>,
+}
+
+// sent on login and on every zone change
+pub struct Handler;
+#[async_trait]
+impl PacketHandler for Handler {
+    async fn handle(&mut self, input: &mut HandlerInput) -> HandlerResult {
+        let mut response = Vec::new();
+
+        let (Income { map_id, zone_id, area_id, states, .. }, json) = Income::from_binary(&input.data)?;
+
+        response.push(HandlerOutput::ResponseMessage(
+            Opcode::get_opcode_name(input.opcode as u32)
+                .unwrap_or(format!("Unknown opcode: {}", input.opcode)),
+            Some(json),
+        ));
+
+        let world_states: HashMap<u32, u32> = states.chunks_exact(2)
+            .map(|pair| (pair[0], pair[1]))
+            .collect();
+
+        let mut guard = input.session.lock().await;
+        let instance_id = guard.instance_binds.get(&map_id).copied();
+
+        if let Some(event) = guard.set_location(WorldLocation { map_id, zone_id, area_id, instance_id }) {
+            response.push(HandlerOutput::WorldEvent(event));
+        }
+
+        guard.world_states = world_states.clone();
+        response.push(HandlerOutput::WorldEvent(WorldEvent::WorldStatesInitialized(world_states)));
+
+        Ok(response)
+    }
+}
\ No newline at end of file
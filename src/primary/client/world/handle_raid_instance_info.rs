@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+
+use crate::primary::client::{InstanceBind, Opcode};
+use crate::primary::types::{HandlerInput, HandlerOutput, HandlerResult};
+use crate::primary::traits::packet_handler::PacketHandler;
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+struct Income {
+    binds: Vec<InstanceBind>,
+}
+
+pub struct Handler;
+#[async_trait]
+impl PacketHandler for Handler {
+    async fn handle(&mut self, input: &mut HandlerInput) -> HandlerResult {
+        let mut response = Vec::new();
+
+        let (Income { binds }, json) = Income::from_binary(&input.data)?;
+
+        response.push(HandlerOutput::ResponseMessage(
+            Opcode::get_opcode_name(input.opcode as u32)
+                .unwrap_or(format!("Unknown opcode: {}", input.opcode)),
+            Some(json),
+        ));
+
+        let mut guard = input.session.lock().await;
+        guard.instance_binds = binds.into_iter()
+            .map(|bind| (bind.map_id, bind.instance_id))
+            .collect();
+
+        if let Some(mut location) = guard.location {
+            location.instance_id = guard.instance_binds.get(&location.map_id).copied();
+            if let Some(event) = guard.set_location(location) {
+                response.push(HandlerOutput::WorldEvent(event));
+            }
+        }
+
+        Ok(response)
+    }
+}
\ No newline at end of file
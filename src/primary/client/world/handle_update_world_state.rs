@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+
+use crate::primary::client::Opcode;
+use crate::primary::client::world::types::WorldEvent;
+use crate::primary::types::{HandlerInput, HandlerOutput, HandlerResult};
+use crate::primary::traits::packet_handler::PacketHandler;
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+struct Income {
+    state: u32,
+    value: u32,
+}
+
+pub struct Handler;
+#[async_trait]
+impl PacketHandler for Handler {
+    async fn handle(&mut self, input: &mut HandlerInput) -> HandlerResult {
+        let mut response = Vec::new();
+
+        let (Income { state, value }, json) = Income::from_binary(&input.data)?;
+
+        response.push(HandlerOutput::ResponseMessage(
+            Opcode::get_opcode_name(input.opcode as u32)
+                .unwrap_or(format!("Unknown opcode: {}", input.opcode)),
+            Some(json),
+        ));
+
+        input.session.lock().await.world_states.insert(state, value);
+        response.push(HandlerOutput::WorldEvent(WorldEvent::WorldStateUpdated(state, value)));
+
+        Ok(response)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,88 @@
+use async_trait::async_trait;
+
+use crate::primary::client::{Opcode, Position};
+use crate::primary::types::{HandlerInput, HandlerOutput, HandlerResult};
+use crate::primary::traits::packet_handler::PacketHandler;
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+struct Income {
+    map_id: u32,
+    x: f32,
+    y: f32,
+    z: f32,
+    orientation: f32,
+}
+
+pub struct Handler;
+#[async_trait]
+impl PacketHandler for Handler {
+    async fn handle(&mut self, input: &mut HandlerInput) -> HandlerResult {
+        let mut response = Vec::new();
+
+        let (Income { map_id, x, y, z, orientation }, json) = Income::from_binary(&input.data)?;
+
+        response.push(HandlerOutput::ResponseMessage(
+            Opcode::get_opcode_name(input.opcode as u32)
+                .unwrap_or(format!("Unknown opcode: {}", input.opcode)),
+            Some(json),
+        ));
+
+        let mut guard = input.session.lock().await;
+        if let Some(me) = guard.me.as_mut() {
+            me.map_id = map_id;
+            me.position = Some(Position::new(x, y, z, orientation));
+        }
+
+        if let Some(event) = guard.set_map(map_id) {
+            response.push(HandlerOutput::WorldEvent(event));
+        }
+
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex as SyncMutex};
+    use byteorder::{LittleEndian, WriteBytesExt};
+    use tokio::sync::Mutex;
+
+    use crate::primary::client::{Opcode, Player, WorldEvent, WorldLocation};
+    use crate::primary::client::world::handle_verify_world::Handler;
+    use crate::primary::shared::session::Session;
+    use crate::primary::shared::storage::DataStorage;
+    use crate::primary::traits::packet_handler::PacketHandler;
+    use crate::primary::types::{HandlerInput, HandlerOutput, ObjectGuid};
+
+    #[tokio::test]
+    async fn test_first_map_changes_location() {
+        let mut session = Session::new();
+        session.me = Some(Player::new(ObjectGuid::new(1), "Me".to_string(), 1, 1, 0, 1));
+
+        // eastern kingdoms has id 0, which used to match default location
+        let mut data = Vec::new();
+        data.write_u32::<LittleEndian>(0).unwrap();
+        for value in [1.0, 2.0, 3.0, 0.5] {
+            data.write_f32::<LittleEndian>(value).unwrap();
+        }
+
+        let mut input = HandlerInput {
+            session: Arc::new(Mutex::new(session)),
+            data,
+            data_storage: Arc::new(SyncMutex::new(DataStorage::new())),
+            opcode: Opcode::SMSG_LOGIN_VERIFY_WORLD,
+        };
+
+        let response = Handler.handle(&mut input).await.unwrap();
+        assert!(response.iter().any(|output| matches!(
+            output,
+            HandlerOutput::WorldEvent(WorldEvent::LocationChanged(None, location))
+                if *location == WorldLocation::default()
+        )));
+
+        let session = input.session.lock().await;
+        assert_eq!(session.location, Some(WorldLocation::default()));
+        assert_eq!(session.me.as_ref().unwrap().position.unwrap().z, 3.0);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,41 @@
+mod handle_init_world_states;
+mod handle_raid_instance_info;
+mod handle_update_world_state;
+mod handle_verify_world;
+pub mod types;
+
+use crate::primary::client::opcodes::Opcode;
+use crate::primary::traits::processor::Processor;
+use crate::primary::types::{HandlerInput, ProcessorResult};
+
+pub struct WorldProcessor;
+
+impl Processor for WorldProcessor {
+    fn get_handlers(input: &mut HandlerInput) -> ProcessorResult {
+        let handlers: ProcessorResult = match input.opcode {
+            Opcode::SMSG_LOGIN_VERIFY_WORLD => {
+                vec![
+                    Box::new(handle_verify_world::Handler),
+                ]
+            },
+            Opcode::SMSG_INIT_WORLD_STATES => {
+                vec![
+                    Box::new(handle_init_world_states::Handler),
+                ]
+            },
+            Opcode::SMSG_UPDATE_WORLD_STATE => {
+                vec![
+                    Box::new(handle_update_world_state::Handler),
+                ]
+            },
+            Opcode::SMSG_RAID_INSTANCE_INFO => {
+                vec![
+                    Box::new(handle_raid_instance_info::Handler),
+                ]
+            },
+            _ => vec![],
+        };
+
+        handlers
+    }
+}
\ No newline at end of file
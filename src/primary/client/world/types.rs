@@ -0,0 +1,31 @@
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorldLocation {
+    pub map_id: u32,
+    pub zone_id: u32,
+    pub area_id: u32,
+    // known only for instances the character is saved to
+    pub instance_id: Option<u64>,
+}
+
+#[derive(Debug, Clone)]
+pub enum WorldEvent {
+    // previous (None right after login) and current location
+    LocationChanged(Option<WorldLocation>, WorldLocation),
+    WorldStatesInitialized(HashMap<u32, u32>),
+    // state id and new value
+    WorldStateUpdated(u32, u32),
+}
+
+// entry of SMSG_RAID_INSTANCE_INFO
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct InstanceBind {
+    pub map_id: u32,
+    pub difficulty: u32,
+    pub instance_id: u64,
+    pub locked: bool,
+    pub extended: bool,
+    // seconds until reset
+    pub reset_time: u32,
+}
\ No newline at end of file
@@ -8,11 +8,22 @@ use yaml_rust::{Yaml, YamlLoader};
 
 pub mod types;
 
+use crate::primary::client::movement::follow::DEFAULT_FOLLOW_DISTANCE;
+use crate::primary::client::movement::route::{DEFAULT_RECORD_INTERVAL, DEFAULT_ROUTES_PATH};
 use crate::primary::config::types::{AddonInfo, ChannelLabels, CommonOptions, ConnectionData};
 use crate::primary::errors::{ConfigError};
 
 const CONFIG_CONTENT: &str = r##"common:
   auto_create_character_for_new_account: false
+  follow_distance: 3.0
+  # directory with server-extracted .map files, used for terrain height lookup
+  maps_path: ""
+  # directory with server-extracted .mmap/.mmtile files, used for pathfinding
+  mmaps_path: ""
+  # directory where recorded routes are stored
+  routes_path: "routes"
+  # milliseconds between recorded route points
+  route_record_interval: 1000
 
 connection_data:
   127.0.0.1:
@@ -158,8 +169,26 @@ impl Config {
             config["auto_create_character_for_new_account"].as_bool().unwrap()
         };
 
+        // older configs have no follow_distance
+        let follow_distance = config["follow_distance"].as_f64()
+            .map(|distance| distance as f32)
+            .unwrap_or(DEFAULT_FOLLOW_DISTANCE);
+
+        let get_path = |key: &str| config[key].as_str()
+            .filter(|path| !path.is_empty())
+            .map(|path| path.to_string());
+
+        let route_record_interval = config["route_record_interval"].as_i64()
+            .map(|interval| interval.max(1) as u64)
+            .unwrap_or(DEFAULT_RECORD_INTERVAL.as_millis() as u64);
+
         CommonOptions {
             auto_create_character_for_new_account,
+            follow_distance,
+            maps_path: get_path("maps_path"),
+            mmaps_path: get_path("mmaps_path"),
+            routes_path: get_path("routes_path").unwrap_or(DEFAULT_ROUTES_PATH.to_string()),
+            route_record_interval,
         }
     }
 }
@@ -233,6 +262,11 @@ mod tests {
 
         let common_options = Config::parse_common_options(&docs[0]["common"]);
         assert_eq!(common_options.auto_create_character_for_new_account, false);
+        assert_eq!(common_options.follow_distance, 3.0);
+        assert_eq!(common_options.maps_path, None);
+        assert_eq!(common_options.mmaps_path, None);
+        assert_eq!(common_options.routes_path, "routes");
+        assert_eq!(common_options.route_record_interval, 1000);
 
         temp_dir.close().unwrap();
     }
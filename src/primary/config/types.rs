@@ -4,6 +4,11 @@ use byteorder::{LittleEndian, WriteBytesExt};
 #[derive(Clone, Debug)]
 pub struct CommonOptions {
     pub auto_create_character_for_new_account: bool,
+    pub follow_distance: f32,
+    pub maps_path: Option<String>,
+    pub mmaps_path: Option<String>,
+    pub routes_path: String,
+    pub route_record_interval: u64,
 }
 
 #[derive(Clone, Debug)]
@@ -0,0 +1,182 @@
+use crate::primary::types::{ObjectGuid, PackedGuid, TerminatedString};
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+pub struct TutorialFlags {
+    #[count_from = "8"]
+    pub tutorials: Vec<u32>,
+}
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+pub struct AccountDataTimes {
+    pub time: u32,
+    pub unknown: u8,
+    pub mask: u32,
+    // one timestamp per each bit set in mask
+    #[until_end]
+    pub times: Vec<u32>,
+}
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+pub struct ForcedReactions {
+    pub count: u32,
+    // pairs of (faction, reaction)
+    #[count_from = "count * 2"]
+    pub reactions: Vec<u32>,
+}
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+pub struct ClientCacheVersion {
+    pub version: u32,
+}
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+pub struct LoginSetTimeSpeed {
+    pub game_time: u32,
+    pub speed: f32,
+    pub unknown: u32,
+}
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+pub struct RealmSplit {
+    pub unknown: u32,
+    pub split_state: u32,
+    pub split_date: TerminatedString,
+}
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+pub struct TextEmote {
+    pub guid: ObjectGuid,
+    pub text_emote: u32,
+    pub emote_num: u32,
+    pub name_length: u32,
+    #[length_from = "name_length"]
+    pub name: TerminatedString,
+}
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+pub struct Emote {
+    pub emote: u32,
+    pub guid: ObjectGuid,
+}
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+pub struct GroupInvite {
+    pub in_group: u8,
+    pub name: TerminatedString,
+    pub unknown1: u32,
+    pub unknown2: u8,
+    pub unknown3: u32,
+}
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+pub struct SpellModifier {
+    pub effect_index: u8,
+    pub operation: u8,
+    pub value: i32,
+}
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+pub struct DungeonDifficulty {
+    pub difficulty: u32,
+    pub unknown: u32,
+    pub is_in_group: u32,
+}
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+pub struct AchievementEarned {
+    pub guid: PackedGuid,
+    pub achievement_id: u32,
+    pub date: u32,
+    pub unknown: u32,
+}
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+pub struct TimeSyncRequest {
+    pub counter: u32,
+}
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+pub struct InitWorldStates {
+    pub map_id: u32,
+    pub zone_id: u32,
+    pub area_id: u32,
+    pub count: u16,
+    // pairs of (state, value)
+    #[count_from = "count * 2"]
+    pub states: Vec<u32>,
+}
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+pub struct BindPointUpdate {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub map_id: u32,
+    pub area_id: u32,
+}
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+pub struct ActionButtons {
+    pub state: u8,
+    #[until_end]
+    pub buttons: Vec<u32>,
+}
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+pub struct PowerUpdate {
+    pub guid: PackedGuid,
+    pub power: u8,
+    pub value: u32,
+}
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+pub struct Weather {
+    pub state: u32,
+    pub grade: f32,
+    pub abrupt: u8,
+}
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+pub struct FeatureSystemStatus {
+    pub unknown: u8,
+    pub voice_enabled: u8,
+}
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+pub struct LearnedDanceMoves {
+    pub dance_moves: u32,
+    pub unknown: u32,
+}
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+pub struct SetProficiency {
+    pub item_class: u8,
+    pub item_subclass_mask: u32,
+}
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+pub struct Pong {
+    pub ping: u32,
+}
\ No newline at end of file
@@ -0,0 +1,81 @@
+mod layouts;
+
+use anyhow::{Result as AnyResult};
+
+use crate::primary::client::Opcode;
+use layouts::*;
+
+pub type Dissector = fn(&[u8]) -> AnyResult<String>;
+
+macro_rules! layout {
+    ($layout:ty) => {
+        |data: &[u8]| -> AnyResult<String> {
+            let (_, details) = <$layout>::from_binary(data)?;
+            Ok(details)
+        }
+    };
+}
+
+// layouts of known packets without handler, to show JSON details instead of hex dump
+pub struct DissectorRegistry;
+
+impl DissectorRegistry {
+    pub fn get_dissector(opcode: u16) -> Option<Dissector> {
+        let dissector: Dissector = match opcode {
+            Opcode::SMSG_TUTORIAL_FLAGS => layout!(TutorialFlags),
+            Opcode::SMSG_ACCOUNT_DATA_TIMES => layout!(AccountDataTimes),
+            Opcode::SMSG_SET_FORCED_REACTIONS => layout!(ForcedReactions),
+            Opcode::SMSG_CLIENTCACHE_VERSION => layout!(ClientCacheVersion),
+            Opcode::SMSG_LOGIN_SETTIMESPEED => layout!(LoginSetTimeSpeed),
+            Opcode::SMSG_REALM_SPLIT => layout!(RealmSplit),
+            Opcode::SMSG_TEXT_EMOTE => layout!(TextEmote),
+            Opcode::SMSG_EMOTE => layout!(Emote),
+            Opcode::SMSG_GROUP_INVITE => layout!(GroupInvite),
+            Opcode::SMSG_SET_PCT_SPELL_MODIFIER |
+            Opcode::SMSG_SET_FLAT_SPELL_MODIFIER => layout!(SpellModifier),
+            Opcode::MSG_SET_DUNGEON_DIFFICULTY => layout!(DungeonDifficulty),
+            Opcode::SMSG_ACHIEVEMENT_EARNED => layout!(AchievementEarned),
+            Opcode::SMSG_TIME_SYNC_REQ => layout!(TimeSyncRequest),
+            Opcode::SMSG_INIT_WORLD_STATES => layout!(InitWorldStates),
+            Opcode::SMSG_BINDPOINTUPDATE => layout!(BindPointUpdate),
+            Opcode::SMSG_ACTION_BUTTONS => layout!(ActionButtons),
+            Opcode::SMSG_POWER_UPDATE => layout!(PowerUpdate),
+            Opcode::SMSG_WEATHER => layout!(Weather),
+            Opcode::SMSG_FEATURE_SYSTEM_STATUS => layout!(FeatureSystemStatus),
+            Opcode::SMSG_LEARNED_DANCE_MOVES => layout!(LearnedDanceMoves),
+            Opcode::SMSG_SET_PROFICIENCY => layout!(SetProficiency),
+            Opcode::SMSG_PONG => layout!(Pong),
+            _ => return None,
+        };
+
+        Some(dissector)
+    }
+
+    pub fn dissect(opcode: u16, data: &[u8]) -> Option<AnyResult<String>> {
+        Self::get_dissector(opcode).map(|dissector| dissector(data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primary::client::Opcode;
+    use crate::primary::dissector::DissectorRegistry;
+
+    #[test]
+    fn test_dissect_known_opcode() {
+        let data = [2, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0];
+        let details = DissectorRegistry::dissect(Opcode::SMSG_SET_FORCED_REACTIONS, &data)
+            .unwrap()
+            .unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&details).unwrap();
+        assert_eq!(json["count"], 2);
+        assert_eq!(json["reactions"], serde_json::json!([1, 3, 2, 4]));
+    }
+
+    #[test]
+    fn test_dissect_unknown_opcode() {
+        assert!(DissectorRegistry::dissect(Opcode::SMSG_UPDATE_OBJECT, &[]).is_none());
+        assert!(DissectorRegistry::dissect(Opcode::SMSG_TIME_SYNC_REQ, &[1]).unwrap().is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+#[derive(Error, Debug)]
+pub enum InjectorError {
+    #[error("Inject command should be in format: <opcode> <hex or json body>")]
+    InvalidCommand,
+    #[error("Unknown opcode '{0}'")]
+    UnknownOpcode(String),
+    #[error("Invalid hex body: '{0}'")]
+    InvalidHex(String),
+    #[error("No known packet structure for opcode '{0}', use hex body instead")]
+    UnknownStructure(String),
+}
\ No newline at end of file
@@ -1,9 +1,19 @@
 mod character_error;
 mod config_error;
 mod field_error;
+mod injector_error;
+mod navigation_error;
+mod opcode_error;
 mod realm_error;
+mod route_error;
+mod terrain_error;
 
 pub use character_error::CharacterListError;
 pub use config_error::ConfigError;
 pub use field_error::FieldError;
-pub use realm_error::RealmListError;
\ No newline at end of file
+pub use injector_error::InjectorError;
+pub use navigation_error::NavigationError;
+pub use opcode_error::OpcodeError;
+pub use realm_error::RealmListError;
+pub use route_error::RouteError;
+pub use terrain_error::TerrainError;
\ No newline at end of file
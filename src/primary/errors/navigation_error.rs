@@ -0,0 +1,13 @@
+#[derive(Error, Debug)]
+pub enum NavigationError {
+    #[error("Navmesh path is not configured")]
+    NotConfigured,
+    #[error("Navmesh for map {0} is not found")]
+    MapNotFound(u32),
+    #[error("Cannot read navmesh file '{1}'")]
+    CannotRead(#[source] std::io::Error, String),
+    #[error("No navmesh polygon near x: {0}, y: {1}, z: {2}")]
+    PositionNotOnMesh(f32, f32, f32),
+    #[error("Path is not found")]
+    PathNotFound,
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+#[derive(Error, Debug)]
+pub enum OpcodeError {
+    #[error("Unknown opcode value: {0}")]
+    UnknownValue(u32),
+    #[error("Unknown opcode name: '{0}'")]
+    UnknownName(String),
+}
\ No newline at end of file
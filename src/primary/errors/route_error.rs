@@ -0,0 +1,17 @@
+#[derive(Error, Debug)]
+pub enum RouteError {
+    #[error("Route command should be in format: record <name> [player name] | mark [pause seconds] | save | play <name> [loop|pingpong] | stop")]
+    InvalidCommand,
+    #[error("Route name '{0}' should contain only letters, digits, '_' and '-'")]
+    InvalidName(String),
+    #[error("Cannot read route file '{1}'")]
+    CannotRead(#[source] std::io::Error, String),
+    #[error("Cannot write route file '{1}'")]
+    CannotWrite(#[source] std::io::Error, String),
+    #[error("Invalid route file '{1}'")]
+    InvalidFormat(#[source] serde_json::Error, String),
+    #[error("Route '{0}' is recorded on map {1}, but current map is {2}")]
+    MapMismatch(String, u32, u32),
+    #[error("Route '{0}' has no points")]
+    Empty(String),
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+#[derive(Error, Debug)]
+pub enum TerrainError {
+    #[error("Maps path is not configured")]
+    NotConfigured,
+    #[error("Cannot read map file '{1}'")]
+    CannotRead(#[source] std::io::Error, String),
+}
\ No newline at end of file
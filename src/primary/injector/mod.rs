@@ -0,0 +1,167 @@
+use std::str::FromStr;
+
+use anyhow::{Result as AnyResult};
+use serde::de::DeserializeOwned;
+
+use crate::primary::client::{Opcode, WorldOpcode};
+use crate::primary::client::chat::globals::JoinChannelOutcome;
+use crate::primary::client::chat::packet::{ChatOutcome, EmoteOutcome, TextEmoteOutcome};
+use crate::primary::client::movement::packet::{MovementOpcodes, MovementOutcome};
+use crate::primary::client::player::globals::{CharacterEnumOutcome, NameQueryOutcome};
+use crate::primary::client::player::packet::CharCreateOutcome;
+use crate::primary::client::realm::packet::LogoutOutcome;
+use crate::primary::errors::InjectorError;
+use crate::primary::types::OutgoingPacket;
+use crate::primary::utils::decode_hex;
+
+const MOVEMENT_OPCODES: [u16; 19] = [
+    MovementOpcodes::MSG_MOVE_START_FORWARD,
+    MovementOpcodes::MSG_MOVE_START_BACKWARD,
+    MovementOpcodes::MSG_MOVE_STOP,
+    MovementOpcodes::MSG_MOVE_START_STRAFE_LEFT,
+    MovementOpcodes::MSG_MOVE_START_STRAFE_RIGHT,
+    MovementOpcodes::MSG_MOVE_STOP_STRAFE,
+    MovementOpcodes::MSG_MOVE_JUMP,
+    MovementOpcodes::MSG_MOVE_START_TURN_LEFT,
+    MovementOpcodes::MSG_MOVE_START_TURN_RIGHT,
+    MovementOpcodes::MSG_MOVE_STOP_TURN,
+    MovementOpcodes::MSG_MOVE_START_PITCH_UP,
+    MovementOpcodes::MSG_MOVE_START_PITCH_DOWN,
+    MovementOpcodes::MSG_MOVE_STOP_PITCH,
+    MovementOpcodes::MSG_MOVE_FALL_LAND,
+    MovementOpcodes::MSG_MOVE_START_SWIM,
+    MovementOpcodes::MSG_MOVE_STOP_SWIM,
+    MovementOpcodes::MSG_MOVE_SET_FACING,
+    MovementOpcodes::MSG_MOVE_SET_PITCH,
+    MovementOpcodes::MSG_MOVE_HEARTBEAT,
+];
+
+#[derive(WorldPacket, Serialize, Deserialize, Debug)]
+#[options(no_opcode)]
+struct RawOutcome {
+    data: Vec<u8>,
+}
+
+// builds world packets from opcode (name, decimal or hex) and body (hex or JSON)
+pub struct PacketInjector;
+
+impl PacketInjector {
+    // parses "<opcode> <body>", for example: "CMSG_NAME_QUERY {"guid": 1}"
+    pub fn from_command(command: &str) -> AnyResult<OutgoingPacket> {
+        let command = command.trim();
+        if command.is_empty() {
+            return Err(InjectorError::InvalidCommand.into());
+        }
+
+        let (opcode, body) = command.split_once(char::is_whitespace).unwrap_or((command, ""));
+
+        Self::build(opcode, body)
+    }
+
+    pub fn build(opcode: &str, body: &str) -> AnyResult<OutgoingPacket> {
+        let opcode = Self::resolve_opcode(opcode)?;
+        let body = body.trim();
+
+        if body.starts_with('{') {
+            Self::from_json(opcode, body)
+        } else {
+            Self::from_hex(opcode, body)
+        }
+    }
+
+    pub fn resolve_opcode(opcode: &str) -> Result<u32, InjectorError> {
+        let opcode = opcode.trim();
+
+        let value = if let Some(hex) = opcode.strip_prefix("0x").or(opcode.strip_prefix("0X")) {
+            u32::from_str_radix(hex, 16).ok()
+        } else if let Ok(value) = opcode.parse::<u32>() {
+            Some(value)
+        } else {
+            WorldOpcode::from_str(opcode).ok().map(u32::from)
+        };
+
+        value.ok_or_else(|| InjectorError::UnknownOpcode(opcode.to_string()))
+    }
+
+    fn from_hex(opcode: u32, body: &str) -> AnyResult<OutgoingPacket> {
+        // body may be copied from multi-line dumps, so tabs and line breaks are allowed as well
+        let hex: String = body.chars().filter(|c| !c.is_whitespace()).collect();
+        if !hex.is_ascii() || !hex.len().is_multiple_of(2) {
+            return Err(InjectorError::InvalidHex(body.to_string()).into());
+        }
+
+        let data = decode_hex(&hex).map_err(|_| InjectorError::InvalidHex(body.to_string()))?;
+
+        RawOutcome { data }.unpack_with_opcode(opcode)
+    }
+
+    fn from_json(opcode: u32, json: &str) -> AnyResult<OutgoingPacket> {
+        match opcode {
+            Opcode::CMSG_MESSAGECHAT => Self::parse_json::<ChatOutcome>(json)?.unpack(),
+            Opcode::CMSG_EMOTE => Self::parse_json::<EmoteOutcome>(json)?.unpack(),
+            Opcode::CMSG_TEXT_EMOTE => Self::parse_json::<TextEmoteOutcome>(json)?.unpack(),
+            Opcode::CMSG_JOIN_CHANNEL => Self::parse_json::<JoinChannelOutcome>(json)?.unpack(),
+            Opcode::CMSG_NAME_QUERY => Self::parse_json::<NameQueryOutcome>(json)?.unpack(),
+            Opcode::CMSG_CHAR_ENUM => Self::parse_json::<CharacterEnumOutcome>(json)?.unpack(),
+            Opcode::CMSG_CHAR_CREATE => Self::parse_json::<CharCreateOutcome>(json)?.unpack(),
+            Opcode::CMSG_LOGOUT_REQUEST => Self::parse_json::<LogoutOutcome>(json)?.unpack(),
+            _ if MOVEMENT_OPCODES.iter().any(|value| *value as u32 == opcode) => {
+                Self::parse_json::<MovementOutcome>(json)?.unpack_with_opcode(opcode)
+            },
+            _ => {
+                let name = WorldOpcode::try_from(opcode)
+                    .map(|opcode| opcode.to_string())
+                    .unwrap_or(opcode.to_string());
+                Err(InjectorError::UnknownStructure(name).into())
+            },
+        }
+    }
+
+    fn parse_json<T: DeserializeOwned>(json: &str) -> AnyResult<T> {
+        serde_json::from_str(json).map_err(|e| e.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primary::client::Opcode;
+    use crate::primary::client::player::globals::NameQueryOutcome;
+    use crate::primary::injector::PacketInjector;
+    use crate::primary::types::ObjectGuid;
+
+    #[test]
+    fn test_inject_hex_body() {
+        let packet = PacketInjector::from_command("CMSG_PING 01 00 00 00 02 00 00 00").unwrap();
+
+        assert_eq!(packet.opcode, Opcode::CMSG_PING);
+        assert_eq!(
+            packet.data,
+            vec![0, 12, 0xDC, 0x01, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0],
+        );
+    }
+
+    #[test]
+    fn test_inject_multiline_hex_body() {
+        let packet = PacketInjector::from_command("CMSG_PING 01 00\t00 00\n02 00\r\n00 00").unwrap();
+
+        assert_eq!(packet.data, vec![0, 12, 0xDC, 0x01, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_inject_json_body() {
+        let packet = PacketInjector::from_command(r#"0x50 {"guid": 12345}"#).unwrap();
+        let expected = NameQueryOutcome { guid: ObjectGuid::new(12345) }.unpack().unwrap();
+
+        assert_eq!(packet.opcode, expected.opcode);
+        assert_eq!(packet.data, expected.data);
+    }
+
+    #[test]
+    fn test_inject_invalid_input() {
+        assert!(PacketInjector::from_command("").is_err());
+        assert!(PacketInjector::from_command("CMSG_UNKNOWN_OPCODE 00").is_err());
+        assert!(PacketInjector::from_command("CMSG_PING 0").is_err());
+        assert!(PacketInjector::from_command("CMSG_PING 00é").is_err());
+        assert!(PacketInjector::from_command(r#"CMSG_PING {"ping": 1}"#).is_err());
+    }
+}
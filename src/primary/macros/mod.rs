@@ -1,4 +1,58 @@
 mod with_opcode;
 mod opcodes;
 
-pub(crate) use with_opcode::with_opcode;
\ No newline at end of file
+pub(crate) use with_opcode::with_opcode;
+
+#[cfg(test)]
+mod tests {
+    use crate::primary::types::TerminatedString;
+
+    #[derive(WorldPacket, Serialize, Deserialize, Debug, Default)]
+    #[options(no_opcode)]
+    struct Income {
+        kind: u8,
+        name_length: u8,
+        #[length_from = "name_length"]
+        name: TerminatedString,
+        #[skip(2)]
+        #[if_expr = "kind == 1"]
+        extra: u32,
+        items_count: u8,
+        #[count_from = "items_count"]
+        items: Vec<u16>,
+        #[until_end]
+        tail: Vec<u8>,
+    }
+
+    #[test]
+    fn test_declarative_fields() {
+        let data = [
+            1, 5, b'n', b'a', b'm', b'e', 0,
+            0, 0, 10, 0, 0, 0,
+            2, 1, 0, 2, 0,
+            7, 8, 9,
+        ];
+
+        let (mut income, _) = Income::from_binary(&data).unwrap();
+        assert_eq!(income.name.to_string(), "name");
+        assert_eq!(income.extra, 10);
+        assert_eq!(income.items, vec![1, 2]);
+        assert_eq!(income.tail, vec![7, 8, 9]);
+
+        let packet = income.unpack_with_opcode(0).unwrap();
+        assert_eq!(packet.data[6..], data);
+    }
+
+    #[test]
+    fn test_declarative_fields_with_false_condition() {
+        let data = [0, 1, 0, 0, 0, 0];
+
+        let (mut income, _) = Income::from_binary(&data).unwrap();
+        assert_eq!(income.extra, 0);
+        assert!(income.items.is_empty());
+        assert!(income.tail.is_empty());
+
+        let packet = income.unpack_with_opcode(0).unwrap();
+        assert_eq!(packet.data[6..], data);
+    }
+}
\ No newline at end of file
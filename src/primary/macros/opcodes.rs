@@ -11,20 +11,23 @@ macro_rules! opcodes {
         pub struct $struct_name;
 
         impl $struct_name {
+            // (name, value, is_login_opcode), login opcodes are declared as u8
+            #[allow(dead_code)]
+            pub const ENTRIES: &'static [(&'static str, u32, bool)] = &[
+                $(
+                    (
+                        stringify!($const_name),
+                        $const_value as u32,
+                        std::mem::size_of::<$const_type>() == 1,
+                    ),
+                )*
+            ];
+
             $(
                 $(#[$attrs])*
                 #[allow(dead_code)]
                 pub const $const_name: $const_type = $const_value;
             )*
-
-            pub fn get_opcode_name(index: u32) -> Option<String> {
-                match index {
-                    $(
-                        $const_value if $const_value == $const_value as u32 => Some(stringify!($const_name).to_string()),
-                    )*
-                    _ => None,
-                }
-            }
         }
     };
 }
\ No newline at end of file
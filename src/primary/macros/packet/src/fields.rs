@@ -0,0 +1,241 @@
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{quote};
+use syn::{Expr, ExprLit, Fields, GenericArgument, Lit, LitInt, Meta, PathArguments, Type};
+
+// every field is read into local variable with same name, so attributes can refer
+// to previously read fields, for example: #[length_from = "g_len"] or #[if_expr = "size > 0"]
+pub struct FieldOptions {
+    pub ident: Ident,
+    pub ty: Type,
+    pub is_dynamic: bool,
+    pub length_from: Option<Expr>,
+    pub count_from: Option<Expr>,
+    pub if_expr: Option<Expr>,
+    pub skip: Option<LitInt>,
+    pub until_end: bool,
+}
+
+impl FieldOptions {
+    pub fn parse_all(fields: &Fields) -> syn::Result<Vec<Self>> {
+        fields.iter().map(|field| {
+            let mut options = Self {
+                ident: field.ident.clone().unwrap(),
+                ty: field.ty.clone(),
+                is_dynamic: false,
+                length_from: None,
+                count_from: None,
+                if_expr: None,
+                skip: None,
+                until_end: false,
+            };
+
+            for attr in field.attrs.iter() {
+                let path = attr.path();
+
+                if path.is_ident("dynamic_field") {
+                    options.is_dynamic = true;
+                } else if path.is_ident("length_from") {
+                    options.length_from = Some(Self::parse_expr(&attr.meta)?);
+                } else if path.is_ident("count_from") {
+                    options.count_from = Some(Self::parse_expr(&attr.meta)?);
+                } else if path.is_ident("if_expr") {
+                    options.if_expr = Some(Self::parse_expr(&attr.meta)?);
+                } else if path.is_ident("skip") {
+                    options.skip = Some(attr.parse_args::<LitInt>()?);
+                } else if path.is_ident("until_end") {
+                    attr.meta.require_path_only()?;
+                    options.until_end = true;
+                }
+            }
+
+            let readers_amount = [
+                options.is_dynamic,
+                options.length_from.is_some(),
+                options.count_from.is_some(),
+                options.until_end,
+            ].iter().filter(|value| **value).count();
+
+            if readers_amount > 1 {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "only one of dynamic_field, length_from, count_from, until_end can be used per field",
+                ));
+            }
+
+            if options.count_from.is_some() || options.until_end {
+                Self::get_item_type(&options.ty)?;
+            }
+
+            Ok(options)
+        }).collect()
+    }
+
+    fn parse_expr(meta: &Meta) -> syn::Result<Expr> {
+        let name_value = meta.require_name_value()?;
+        match &name_value.value {
+            Expr::Lit(ExprLit { lit: Lit::Str(value), .. }) => value.parse::<Expr>(),
+            value => Err(syn::Error::new_spanned(value, "expected string literal")),
+        }
+    }
+
+    // returns T for Vec<T>
+    fn get_item_type(ty: &Type) -> syn::Result<Type> {
+        if let Type::Path(type_path) = ty {
+            if let Some(segment) = type_path.path.segments.last() {
+                if segment.ident == "Vec" {
+                    if let PathArguments::AngleBracketed(arguments) = &segment.arguments {
+                        if let Some(GenericArgument::Type(item_type)) = arguments.args.first() {
+                            return Ok(item_type.clone());
+                        }
+                    }
+                }
+            }
+        }
+
+        Err(syn::Error::new_spanned(ty, "count_from and until_end can be used only with Vec<T>"))
+    }
+}
+
+pub struct FieldsBuilder<'a> {
+    fields: &'a [FieldOptions],
+    binary_converter: &'a TokenStream2,
+}
+
+impl<'a> FieldsBuilder<'a> {
+    pub fn new(fields: &'a [FieldOptions], binary_converter: &'a TokenStream2) -> Self {
+        Self { fields, binary_converter }
+    }
+
+    // reads all fields from `__reader` in a single pass and returns the instance
+    pub fn build_reader(&self) -> TokenStream2 {
+        let binary_converter = self.binary_converter;
+        let field_names = self.fields.iter().map(|f| &f.ident).collect::<Vec<&Ident>>();
+
+        let readers = self.fields.iter().enumerate().map(|(index, field)| {
+            let FieldOptions { ident, ty, .. } = field;
+
+            let value = if field.is_dynamic {
+                let initializers = self.fields.iter().enumerate().map(|(i, f)| {
+                    let name = &f.ident;
+                    if i < index {
+                        quote! { #name: #name.clone() }
+                    } else {
+                        quote! { #name: Default::default() }
+                    }
+                });
+
+                quote! {
+                    {
+                        let mut __initial = Self { #(#initializers),* };
+                        Self::#ident(&mut __reader, &mut __initial)
+                    }
+                }
+            } else if let Some(length) = &field.length_from {
+                quote! {
+                    {
+                        let mut __buffer = vec![0u8; (#length) as usize];
+                        std::io::Read::read_exact(&mut __reader, &mut __buffer)?;
+                        <#ty as #binary_converter>::read_from(&__buffer[..])?
+                    }
+                }
+            } else if let Some(count) = &field.count_from {
+                let item_type = FieldOptions::get_item_type(ty).unwrap();
+                quote! {
+                    {
+                        let mut __items = Vec::new();
+                        for _ in 0..(#count) as usize {
+                            __items.push(<#item_type as #binary_converter>::read_from(&mut __reader)?);
+                        }
+                        __items
+                    }
+                }
+            } else if field.until_end {
+                let item_type = FieldOptions::get_item_type(ty).unwrap();
+                quote! {
+                    {
+                        let mut __items = Vec::new();
+                        while (__reader.position() as usize) < __reader.get_ref().len() {
+                            __items.push(<#item_type as #binary_converter>::read_from(&mut __reader)?);
+                        }
+                        __items
+                    }
+                }
+            } else {
+                quote! { <#ty as #binary_converter>::read_from(&mut __reader)? }
+            };
+
+            let value = match &field.if_expr {
+                Some(condition) => quote! {
+                    if #condition { #value } else { Default::default() }
+                },
+                None => value,
+            };
+
+            // skipped bytes precede the field and do not depend on if_expr
+            let skip = field.skip.as_ref().map(|amount| quote! {
+                std::io::Read::read_exact(&mut __reader, &mut [0u8; #amount])?;
+            });
+
+            quote! {
+                #skip
+                let #ident: #ty = #value;
+            }
+        });
+
+        quote! {
+            #(#readers)*
+            Self { #(#field_names),* }
+        }
+    }
+
+    // writes all fields into `body`
+    pub fn build_writer(&self) -> TokenStream2 {
+        let binary_converter = self.binary_converter;
+
+        let writers = self.fields.iter().enumerate().map(|(index, field)| {
+            let ident = &field.ident;
+
+            let value = if field.count_from.is_some() || field.until_end {
+                quote! {
+                    for __item in self.#ident.iter_mut() {
+                        #binary_converter::write_into(__item, &mut body)?;
+                    }
+                }
+            } else {
+                quote! {
+                    #binary_converter::write_into(&mut self.#ident, &mut body)?;
+                }
+            };
+
+            let skip = field.skip.as_ref().map(|amount| quote! {
+                body.extend_from_slice(&[0u8; #amount]);
+            });
+
+            let value = match &field.if_expr {
+                Some(condition) => {
+                    // condition refers to previous fields same way as on read
+                    let previous = self.fields[..index].iter().map(|f| &f.ident);
+                    quote! {
+                        if {
+                            #(
+                                #[allow(unused_variables)]
+                                let #previous = self.#previous.clone();
+                            )*
+                            #condition
+                        } {
+                            #value
+                        }
+                    }
+                },
+                None => value,
+            };
+
+            quote! {
+                #skip
+                #value
+            }
+        });
+
+        quote! { #(#writers)* }
+    }
+}
\ No newline at end of file
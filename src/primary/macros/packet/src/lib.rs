@@ -1,14 +1,15 @@
 use proc_macro::{TokenStream};
-use proc_macro2::{Ident};
 use syn::ItemStruct;
 use syn::{parse_macro_input};
-use quote::{quote, format_ident};
+use quote::{quote};
 
+mod fields;
 mod types;
 
+use fields::{FieldOptions, FieldsBuilder};
 use types::{Attributes, Imports};
 
-#[proc_macro_derive(LoginPacket, attributes(dynamic_field))]
+#[proc_macro_derive(LoginPacket, attributes(dynamic_field, length_from, count_from, if_expr, skip, until_end))]
 pub fn derive_login_packet(input: TokenStream) -> TokenStream {
     let ItemStruct { ident, fields, .. } = parse_macro_input!(input);
     let Imports {
@@ -22,61 +23,20 @@ pub fn derive_login_packet(input: TokenStream) -> TokenStream {
         ..
     } = Imports::get();
 
-    let field_names = fields.iter().map(|f| {
-        f.ident.clone()
-    }).collect::<Vec<Option<Ident>>>();
-
-    let mut dynamic_fields: Vec<Option<Ident>> = vec![];
-    for field in fields.iter() {
-        let ident = format_ident!("{}", field.ident.as_ref().unwrap());
-
-        if field.attrs.iter().any(|attr| attr.path().is_ident("dynamic_field")) {
-            dynamic_fields.push(Some(ident));
-        }
-    }
-
-    let initial_initializers = fields
-        .iter()
-        .map(|f| {
-            let field_name = f.ident.clone();
-
-            if dynamic_fields.contains(&field_name) {
-                quote!{ Default::default() }
-            } else {
-                quote! { #binary_converter::read_from(&mut initial_reader)? }
-            }
-        });
-
-    let initializers = fields
-        .iter()
-        .map(|f| {
-            let field_name = f.ident.clone();
-            let field_type = f.ty.clone();
-
-            if dynamic_fields.contains(&field_name) {
-                quote!{ Self::#field_name(&mut reader, &mut initial) }
-            } else {
-                quote! {
-                    {
-                        let value: #field_type = #binary_converter::read_from(&mut reader)?;
-                        initial.#field_name = value.clone();
-                        value
-                    }
-                }
-            }
-        });
+    let fields = match FieldOptions::parse_all(&fields) {
+        Ok(fields) => fields,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+    let builder = FieldsBuilder::new(&fields, &binary_converter);
+    let reader = builder.build_reader();
+    let writer = builder.build_writer();
 
     let output = quote! {
         impl #ident {
             pub fn from_binary(buffer: &[u8]) -> #result<(Self, String)> {
-                let mut initial_reader = #cursor::new(buffer.to_vec());
-                let mut initial = Self {
-                    #(#field_names: #initial_initializers),*
-                };
-
-                let mut reader = #cursor::new(buffer.to_vec());
-                let mut instance = Self {
-                    #(#field_names: #initializers),*
+                let mut __reader = #cursor::new(buffer.to_vec());
+                let mut instance = {
+                    #reader
                 };
                 let details = instance.get_json_details()?;
 
@@ -106,12 +66,7 @@ pub fn derive_login_packet(input: TokenStream) -> TokenStream {
 
             fn _build_body(&mut self) -> #result<Vec<u8>> {
                 let mut body = Vec::new();
-                #(
-                    #binary_converter::write_into(
-                        &mut self.#field_names,
-                        &mut body
-                    )?;
-                )*
+                #writer
 
                 Ok(body)
             }
@@ -131,7 +86,7 @@ pub fn derive_login_packet(input: TokenStream) -> TokenStream {
     TokenStream::from(output)
 }
 
-#[proc_macro_derive(WorldPacket, attributes(options, dynamic_field))]
+#[proc_macro_derive(WorldPacket, attributes(options, dynamic_field, length_from, count_from, if_expr, skip, until_end))]
 pub fn derive_world_packet(input: TokenStream) -> TokenStream {
     let ItemStruct { ident, fields, attrs, .. } = parse_macro_input!(input);
     let Imports {
@@ -164,54 +119,18 @@ pub fn derive_world_packet(input: TokenStream) -> TokenStream {
         }
     }
 
-    let field_names = fields.iter().map(|f| {
-        f.ident.clone()
-    }).collect::<Vec<Option<Ident>>>();
-
-    let mut dynamic_fields: Vec<Option<Ident>> = vec![];
-    for field in fields.iter() {
-        let ident = format_ident!("{}", field.ident.as_ref().unwrap());
-
-        if field.attrs.iter().any(|attr| attr.path().is_ident("dynamic_field")) {
-            dynamic_fields.push(Some(ident));
-        }
-    }
-
-    let initial_initializers = fields
-        .iter()
-        .map(|f| {
-            let field_name = f.ident.clone();
-
-            if dynamic_fields.contains(&field_name) {
-                quote!{ Default::default() }
-            } else {
-                quote! { #binary_converter::read_from(&mut initial_reader)? }
-            }
-        });
-
-    let initializers = fields
-        .iter()
-        .map(|f| {
-            let field_name = f.ident.clone();
-            let field_type = f.ty.clone();
-
-            if dynamic_fields.contains(&field_name) {
-                quote!{ Self::#field_name(&mut reader, &mut initial) }
-            } else {
-                quote! {
-                    {
-                        let value: #field_type = #binary_converter::read_from(&mut reader)?;
-                        initial.#field_name = value.clone();
-                        value
-                    }
-                }
-            }
-        });
+    let fields = match FieldOptions::parse_all(&fields) {
+        Ok(fields) => fields,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+    let builder = FieldsBuilder::new(&fields, &binary_converter);
+    let reader = builder.build_reader();
+    let writer = builder.build_writer();
 
     let mut output = quote! {
         impl #ident {
             pub fn from_binary(buffer: &[u8]) -> #result<(Self, String)> {
-                let mut buffer = match #is_compressed {
+                let buffer = match #is_compressed {
                     true => {
                         let mut internal_buffer: Vec<u8> = Vec::new();
                         // 4 bytes uncompressed + 2 bytes used by zlib
@@ -225,14 +144,9 @@ pub fn derive_world_packet(input: TokenStream) -> TokenStream {
                     false => buffer.to_vec(),
                 };
 
-                let mut initial_reader = #cursor::new(buffer.to_vec());
-                let mut initial = Self {
-                    #(#field_names: #initial_initializers),*
-                };
-
-                let mut reader = #cursor::new(buffer);
-                let mut instance = Self {
-                    #(#field_names: #initializers),*
+                let mut __reader = #cursor::new(buffer);
+                let mut instance = {
+                    #reader
                 };
                 let details = instance.get_json_details()?;
 
@@ -262,12 +176,7 @@ pub fn derive_world_packet(input: TokenStream) -> TokenStream {
 
             fn _build_body(&mut self) -> #result<Vec<u8>> {
                 let mut body = Vec::new();
-                #(
-                    #binary_converter::write_into(
-                        &mut self.#field_names,
-                        &mut body
-                    )?;
-                )*
+                #writer
 
                 Ok(body)
             }
@@ -316,7 +225,7 @@ pub fn derive_world_packet(input: TokenStream) -> TokenStream {
     TokenStream::from(output)
 }
 
-#[proc_macro_derive(FieldsSerializer, attributes(dynamic_field))]
+#[proc_macro_derive(FieldsSerializer, attributes(dynamic_field, length_from, count_from, if_expr, skip, until_end))]
 pub fn derive_fields_serializer(input: TokenStream) -> TokenStream {
     let ItemStruct { ident, fields, attrs, .. } = parse_macro_input!(input);
     let Imports {
@@ -328,61 +237,20 @@ pub fn derive_fields_serializer(input: TokenStream) -> TokenStream {
         ..
     } = Imports::get();
 
-    let field_names = fields.iter().map(|f| {
-        f.ident.clone()
-    }).collect::<Vec<Option<Ident>>>();
-
-    let mut dynamic_fields: Vec<Option<Ident>> = vec![];
-    for field in fields.iter() {
-        let ident = format_ident!("{}", field.ident.as_ref().unwrap());
-
-        if field.attrs.iter().any(|attr| attr.path().is_ident("dynamic_field")) {
-            dynamic_fields.push(Some(ident));
-        }
-    }
-
-    let initial_initializers = fields
-        .iter()
-        .map(|f| {
-            let field_name = f.ident.clone();
-
-            if dynamic_fields.contains(&field_name) {
-                quote!{ Default::default() }
-            } else {
-                quote! { #binary_converter::read_from(&mut initial_reader)? }
-            }
-        });
-
-    let initializers = fields
-        .iter()
-        .map(|f| {
-            let field_name = f.ident.clone();
-            let field_type = f.ty.clone();
-
-            if dynamic_fields.contains(&field_name) {
-                quote!{ Self::#field_name(&mut reader, &mut initial) }
-            } else {
-                quote! {
-                    {
-                        let value: #field_type = #binary_converter::read_from(&mut reader)?;
-                        initial.#field_name = value.clone();
-                        value
-                    }
-                }
-            }
-        });
+    let fields = match FieldOptions::parse_all(&fields) {
+        Ok(fields) => fields,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+    let builder = FieldsBuilder::new(&fields, &binary_converter);
+    let reader = builder.build_reader();
+    let writer = builder.build_writer();
 
     let mut output = quote! {
         impl #ident {
             pub fn from_binary(buffer: &[u8]) -> #result<(Self, String)> {
-                let mut initial_reader = #cursor::new(buffer.to_vec());
-                let mut initial = Self {
-                    #(#field_names: #initial_initializers),*
-                };
-
-                let mut reader = #cursor::new(buffer);
-                let mut instance = Self {
-                    #(#field_names: #initializers),*
+                let mut __reader = #cursor::new(buffer);
+                let mut instance = {
+                    #reader
                 };
                 let details = instance.get_json_details()?;
 
@@ -397,12 +265,7 @@ pub fn derive_fields_serializer(input: TokenStream) -> TokenStream {
 
             pub fn to_binary(&mut self) -> #result<Vec<u8>> {
                 let mut body = Vec::new();
-                #(
-                    #binary_converter::write_into(
-                        &mut self.#field_names,
-                        &mut body
-                    )?;
-                )*
+                #writer
 
                 Ok(body)
             }
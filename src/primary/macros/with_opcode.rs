@@ -6,7 +6,7 @@ macro_rules! with_opcode {
         $(#[$outer:meta])*
         $vis:vis struct $PacketStruct:ident {
             $(
-                $(#[$field_attr: meta])? $field_vis:vis $field_name:ident: $field_type:ty
+                $(#[$field_attr: meta])* $field_vis:vis $field_name:ident: $field_type:ty
             ),*$(,)?
         }
 
@@ -14,7 +14,7 @@ macro_rules! with_opcode {
     ) => {
         $(#[$outer])*
         $vis struct $PacketStruct {
-            $($(#[$field_attr])? $field_vis $field_name: $field_type),*
+            $($(#[$field_attr])* $field_vis $field_name: $field_type),*
         }
 
          $($PacketStructImpl)*
@@ -1,12 +1,16 @@
 pub mod client;
 mod config;
 mod crypto;
+pub mod dissector;
 pub mod errors;
+pub mod injector;
+pub mod navigation;
 pub mod shared;
 mod macros;
 mod network;
 mod parsers;
 mod serializers;
+pub mod terrain;
 pub mod traits;
 pub mod types;
 mod utils;
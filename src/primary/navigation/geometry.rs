@@ -0,0 +1,78 @@
+// detour coordinates: x, height, z
+pub type Vector = [f32; 3];
+
+pub fn distance(a: &Vector, b: &Vector) -> f32 {
+    ((b[0] - a[0]).powi(2) + (b[1] - a[1]).powi(2) + (b[2] - a[2]).powi(2)).sqrt()
+}
+
+pub fn distance_2d_sqr(a: &Vector, b: &Vector) -> f32 {
+    (b[0] - a[0]).powi(2) + (b[2] - a[2]).powi(2)
+}
+
+pub fn lerp(a: &Vector, b: &Vector, t: f32) -> Vector {
+    [a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t, a[2] + (b[2] - a[2]) * t]
+}
+
+// signed area on xz plane, negative when c is to the right of ab
+pub fn tri_area_2d(a: &Vector, b: &Vector, c: &Vector) -> f32 {
+    let (abx, abz) = (b[0] - a[0], b[2] - a[2]);
+    let (acx, acz) = (c[0] - a[0], c[2] - a[2]);
+    acx * abz - abx * acz
+}
+
+pub fn point_in_polygon(point: &Vector, verts: &[Vector]) -> bool {
+    let mut is_inside = false;
+    let mut j = verts.len().saturating_sub(1);
+
+    for (i, vi) in verts.iter().enumerate() {
+        let vj = &verts[j];
+        if (vi[2] > point[2]) != (vj[2] > point[2])
+            && point[0] < (vj[0] - vi[0]) * (point[2] - vi[2]) / (vj[2] - vi[2]) + vi[0]
+        {
+            is_inside = !is_inside;
+        }
+        j = i;
+    }
+
+    is_inside
+}
+
+// height is interpolated between segment ends
+pub fn closest_point_on_segment_2d(point: &Vector, a: &Vector, b: &Vector) -> Vector {
+    let (dx, dz) = (b[0] - a[0], b[2] - a[2]);
+    let length = dx * dx + dz * dz;
+
+    let t = match length > 0.0 {
+        true => (((point[0] - a[0]) * dx + (point[2] - a[2]) * dz) / length).clamp(0.0, 1.0),
+        false => 0.0,
+    };
+
+    lerp(a, b, t)
+}
+
+pub fn closest_height_on_triangle(point: &Vector, a: &Vector, b: &Vector, c: &Vector) -> Option<f32> {
+    const EPSILON: f32 = 1e-6;
+
+    let v0 = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+    let v1 = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+    let v2 = [point[0] - a[0], point[1] - a[1], point[2] - a[2]];
+
+    let mut denominator = v0[0] * v1[2] - v0[2] * v1[0];
+    if denominator.abs() < EPSILON {
+        return None;
+    }
+
+    let mut u = v1[2] * v2[0] - v1[0] * v2[2];
+    let mut v = v0[0] * v2[2] - v0[2] * v2[0];
+    if denominator < 0.0 {
+        denominator = -denominator;
+        u = -u;
+        v = -v;
+    }
+
+    if u >= 0.0 && v >= 0.0 && u + v <= denominator {
+        return Some(a[1] + (v0[1] * u + v1[1] * v) / denominator);
+    }
+
+    None
+}
\ No newline at end of file
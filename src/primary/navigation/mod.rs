@@ -0,0 +1,712 @@
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::cmp::Ordering;
+use std::fs::File;
+use std::io::{BufReader, Error, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+mod geometry;
+
+pub use crate::primary::parsers::mmap_parser::MmapParser;
+pub use crate::primary::parsers::mmap_parser::types::{
+    MeshHeader, MeshTile, MmapTileHeader, NavMeshParams, Poly, PolyDetail,
+};
+use crate::primary::client::Position;
+use crate::primary::errors::NavigationError;
+use crate::primary::navigation::geometry::{
+    closest_height_on_triangle, closest_point_on_segment_2d, distance, distance_2d_sqr, lerp, point_in_polygon,
+    tri_area_2d, Vector,
+};
+use crate::primary::parsers::mmap_parser::types::{DT_EXT_LINK, DT_POLYTYPE_OFFMESH_CONNECTION};
+
+// half size of the box in which nearest polygon is searched, in detour coordinates
+const SEARCH_EXTENTS: Vector = [3.0, 5.0, 3.0];
+// stops the search on huge maps when target is unreachable
+const MAX_SEARCH_NODES: usize = 4096;
+// edges shorter than this are not treated as connection between tiles
+const MIN_PORTAL_WIDTH: f32 = 0.01;
+
+type TileCoords = (i32, i32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct PolyRef {
+    tile: TileCoords,
+    index: usize,
+}
+
+struct Neighbour {
+    poly: PolyRef,
+    left: Vector,
+    right: Vector,
+}
+
+struct SearchNode {
+    cost: f32,
+    position: Vector,
+    parent: Option<PolyRef>,
+    // edge crossed to enter this polygon
+    portal: (Vector, Vector),
+}
+
+struct OpenEntry {
+    total: f32,
+    poly: PolyRef,
+}
+
+impl PartialEq for OpenEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.total == other.total
+    }
+}
+
+impl Eq for OpenEntry {}
+
+impl PartialOrd for OpenEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OpenEntry {
+    // reversed, so BinaryHeap pops the cheapest entry first
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.total.total_cmp(&self.total)
+    }
+}
+
+// navmesh of single map, tiles are loaded when path query reaches them
+pub struct NavMesh {
+    directory: PathBuf,
+    map_id: u32,
+    params: NavMeshParams,
+    tiles: HashMap<TileCoords, Arc<MeshTile>>,
+}
+
+impl NavMesh {
+    pub fn open<P: AsRef<Path>>(directory: P, map_id: u32) -> Result<Self, NavigationError> {
+        let directory = directory.as_ref();
+        let params_path = directory.join(format!("{:03}.mmap", map_id));
+        if !params_path.exists() {
+            return Err(NavigationError::MapNotFound(map_id));
+        }
+
+        let params = Self::read_file(&params_path, MmapParser::parse_params)?;
+
+        Ok(Self {
+            directory: directory.to_path_buf(),
+            map_id,
+            params,
+            tiles: HashMap::new(),
+        })
+    }
+
+    pub fn loaded_tiles_amount(&self) -> usize {
+        self.tiles.len()
+    }
+
+    // returns waypoints from the start (not included) to the destination
+    pub fn find_path(&mut self, from: &Position, to: &Position) -> Result<Vec<Position>, NavigationError> {
+        let (start_ref, start) = self.find_nearest_poly(&Self::to_detour(from))?;
+        let (end_ref, end) = self.find_nearest_poly(&Self::to_detour(to))?;
+
+        let mut portals = self.find_portals(start_ref, end_ref, &start, &end)?;
+        portals.push((end, end));
+
+        let points = Self::string_pull(&start, &portals);
+
+        let mut path: Vec<Position> = Vec::with_capacity(points.len());
+        let mut previous = Self::from_detour(&start);
+        for point in points.iter().skip(1) {
+            let mut position = Self::from_detour(point);
+            position.orientation = previous.angle_to(&position);
+            previous = position;
+            path.push(position);
+        }
+
+        if let Some(last) = path.last_mut() {
+            last.orientation = to.orientation;
+        }
+
+        Ok(path)
+    }
+
+    fn read_file<T, F>(path: &Path, parse: F) -> Result<T, NavigationError>
+    where
+        F: FnOnce(&mut BufReader<File>) -> std::io::Result<T>,
+    {
+        let file = File::open(path)
+            .map_err(|err| NavigationError::CannotRead(err, path.display().to_string()))?;
+
+        parse(&mut BufReader::new(file))
+            .map_err(|err| NavigationError::CannotRead(err, path.display().to_string()))
+    }
+
+    fn get_tile(&mut self, coords: TileCoords) -> Result<Option<Arc<MeshTile>>, NavigationError> {
+        if let Some(tile) = self.tiles.get(&coords) {
+            return Ok(Some(Arc::clone(tile)));
+        }
+
+        let Some(path) = self.tile_path(coords) else {
+            return Ok(None);
+        };
+
+        let tile = Self::read_file(&path, MmapParser::parse_tile)?;
+        if (tile.header.x, tile.header.y) != coords {
+            return Err(NavigationError::CannotRead(
+                Error::new(ErrorKind::InvalidData, "Tile coords do not match the file name"),
+                path.display().to_string(),
+            ));
+        }
+
+        let tile = Arc::new(tile);
+        self.tiles.insert(coords, Arc::clone(&tile));
+
+        Ok(Some(tile))
+    }
+
+    // grid coords inside the file name count from the opposite corner of the map
+    // and go in (y, x) order, so they are mirrored detour tile coords
+    fn tile_path(&self, (x, y): TileCoords) -> Option<PathBuf> {
+        let grid_x = (31.0 - self.params.origin[2] / self.params.tile_height).round() as i32 - y;
+        let grid_y = (31.0 - self.params.origin[0] / self.params.tile_width).round() as i32 - x;
+        if !(0..64).contains(&grid_x) || !(0..64).contains(&grid_y) {
+            return None;
+        }
+
+        let path = self.directory.join(format!("{:03}{:02}{:02}.mmtile", self.map_id, grid_x, grid_y));
+
+        path.exists().then_some(path)
+    }
+
+    fn tile_coords_at(&self, point: &Vector) -> TileCoords {
+        (
+            ((point[0] - self.params.origin[0]) / self.params.tile_width).floor() as i32,
+            ((point[2] - self.params.origin[2]) / self.params.tile_height).floor() as i32,
+        )
+    }
+
+    fn find_nearest_poly(&mut self, point: &Vector) -> Result<(PolyRef, Vector), NavigationError> {
+        let (min_x, min_y) = self.tile_coords_at(&[point[0] - SEARCH_EXTENTS[0], 0.0, point[2] - SEARCH_EXTENTS[2]]);
+        let (max_x, max_y) = self.tile_coords_at(&[point[0] + SEARCH_EXTENTS[0], 0.0, point[2] + SEARCH_EXTENTS[2]]);
+
+        let mut nearest: Option<(f32, PolyRef, Vector)> = None;
+        for x in min_x..=max_x {
+            for y in min_y..=max_y {
+                let Some(tile) = self.get_tile((x, y))? else {
+                    continue;
+                };
+
+                for index in 0..tile.polys.len() {
+                    if !Self::is_walkable(&tile, index) {
+                        continue;
+                    }
+
+                    let closest = Self::closest_point_on_poly(&tile, index, point);
+                    let is_inside = (0..3).all(|i| (closest[i] - point[i]).abs() <= SEARCH_EXTENTS[i]);
+                    if !is_inside {
+                        continue;
+                    }
+
+                    let distance = distance(&closest, point);
+                    if nearest.map(|(nearest, _, _)| distance < nearest).unwrap_or(true) {
+                        nearest = Some((distance, PolyRef { tile: (x, y), index }, closest));
+                    }
+                }
+            }
+        }
+
+        nearest
+            .map(|(_, poly, closest)| (poly, closest))
+            .ok_or(NavigationError::PositionNotOnMesh(point[2], point[0], point[1]))
+    }
+
+    // A* over polygons, returns edges between polygons of found corridor
+    fn find_portals(
+        &mut self,
+        start_ref: PolyRef,
+        end_ref: PolyRef,
+        start: &Vector,
+        end: &Vector,
+    ) -> Result<Vec<(Vector, Vector)>, NavigationError> {
+        let mut nodes = HashMap::from([(start_ref, SearchNode {
+            cost: 0.0,
+            position: *start,
+            parent: None,
+            portal: (*start, *start),
+        })]);
+        let mut closed = HashSet::new();
+        let mut open = BinaryHeap::from([OpenEntry { total: distance(start, end), poly: start_ref }]);
+
+        while let Some(OpenEntry { poly, .. }) = open.pop() {
+            if poly == end_ref {
+                break;
+            }
+
+            if !closed.insert(poly) {
+                continue;
+            }
+
+            if closed.len() > MAX_SEARCH_NODES {
+                return Err(NavigationError::PathNotFound);
+            }
+
+            let (cost, position) = {
+                let node = &nodes[&poly];
+                (node.cost, node.position)
+            };
+
+            for Neighbour { poly: neighbour, left, right } in self.get_neighbours(poly)? {
+                if closed.contains(&neighbour) {
+                    continue;
+                }
+
+                let next_position = lerp(&left, &right, 0.5);
+                let mut next_cost = cost + distance(&position, &next_position);
+                if neighbour == end_ref {
+                    next_cost += distance(&next_position, end);
+                }
+
+                if nodes.get(&neighbour).map(|node| node.cost <= next_cost).unwrap_or(false) {
+                    continue;
+                }
+
+                nodes.insert(neighbour, SearchNode {
+                    cost: next_cost,
+                    position: next_position,
+                    parent: Some(poly),
+                    portal: (left, right),
+                });
+
+                // heuristic is scaled a bit to prefer nodes closer to the end
+                open.push(OpenEntry { total: next_cost + distance(&next_position, end) * 0.999, poly: neighbour });
+            }
+        }
+
+        if !nodes.contains_key(&end_ref) {
+            return Err(NavigationError::PathNotFound);
+        }
+
+        let mut portals = Vec::new();
+        let mut current = end_ref;
+        while let Some(parent) = nodes[&current].parent {
+            portals.push(nodes[&current].portal);
+            current = parent;
+        }
+        portals.reverse();
+
+        Ok(portals)
+    }
+
+    fn get_neighbours(&mut self, poly_ref: PolyRef) -> Result<Vec<Neighbour>, NavigationError> {
+        let Some(tile) = self.get_tile(poly_ref.tile)? else {
+            return Ok(vec![]);
+        };
+
+        let poly = &tile.polys[poly_ref.index];
+        let vert_count = poly.vert_count as usize;
+        let mut neighbours = Vec::new();
+
+        for edge in 0..vert_count {
+            let nei = poly.neis[edge];
+            if nei == 0 {
+                continue;
+            }
+
+            let va = tile.verts[poly.verts[edge] as usize];
+            let vb = tile.verts[poly.verts[(edge + 1) % vert_count] as usize];
+
+            if nei & DT_EXT_LINK == 0 {
+                let index = (nei - 1) as usize;
+                if Self::is_walkable(&tile, index) {
+                    neighbours.push(Neighbour {
+                        poly: PolyRef { tile: poly_ref.tile, index },
+                        left: va,
+                        right: vb,
+                    });
+                }
+                continue;
+            }
+
+            let side = (nei & 0xff) as usize;
+            let (offset_x, offset_y) = Self::side_offset(side);
+            let neighbour_coords = (poly_ref.tile.0 + offset_x, poly_ref.tile.1 + offset_y);
+            let Some(neighbour_tile) = self.get_tile(neighbour_coords)? else {
+                continue;
+            };
+
+            // side edges lie along z axis, top and bottom ones along x axis
+            let axis = if side == 0 || side == 4 { 2 } else { 0 };
+            let opposite = DT_EXT_LINK | ((side + 4) % 8) as u16;
+
+            for (index, other) in neighbour_tile.polys.iter().enumerate() {
+                if !Self::is_walkable(&neighbour_tile, index) {
+                    continue;
+                }
+
+                let other_count = other.vert_count as usize;
+                for other_edge in 0..other_count {
+                    if other.neis[other_edge] != opposite {
+                        continue;
+                    }
+
+                    let oa = neighbour_tile.verts[other.verts[other_edge] as usize][axis];
+                    let ob = neighbour_tile.verts[other.verts[(other_edge + 1) % other_count] as usize][axis];
+
+                    let (a, b) = (va[axis], vb[axis]);
+                    let low = a.min(b).max(oa.min(ob));
+                    let high = a.max(b).min(oa.max(ob));
+                    if high - low < MIN_PORTAL_WIDTH {
+                        continue;
+                    }
+
+                    let t0 = (low - a) / (b - a);
+                    let t1 = (high - a) / (b - a);
+                    neighbours.push(Neighbour {
+                        poly: PolyRef { tile: neighbour_coords, index },
+                        left: lerp(&va, &vb, t0.min(t1)),
+                        right: lerp(&va, &vb, t0.max(t1)),
+                    });
+                }
+            }
+        }
+
+        Ok(neighbours)
+    }
+
+    // simple stupid funnel algorithm, same as detour's findStraightPath
+    fn string_pull(start: &Vector, portals: &[(Vector, Vector)]) -> Vec<Vector> {
+        let mut points = vec![*start];
+
+        let mut apex = *start;
+        let mut portal_left = *start;
+        let mut portal_right = *start;
+        let mut left_index = 0;
+        let mut right_index = 0;
+
+        let mut i = 0;
+        while i < portals.len() {
+            let (left, right) = portals[i];
+
+            if tri_area_2d(&apex, &portal_right, &right) <= 0.0 {
+                if Self::is_same_point(&apex, &portal_right) || tri_area_2d(&apex, &portal_left, &right) > 0.0 {
+                    portal_right = right;
+                    right_index = i;
+                } else {
+                    apex = portal_left;
+                    Self::push_point(&mut points, apex);
+
+                    let apex_index = left_index;
+                    (portal_left, portal_right) = (apex, apex);
+                    (left_index, right_index) = (apex_index, apex_index);
+                    i = apex_index + 1;
+                    continue;
+                }
+            }
+
+            if tri_area_2d(&apex, &portal_left, &left) >= 0.0 {
+                if Self::is_same_point(&apex, &portal_left) || tri_area_2d(&apex, &portal_right, &left) < 0.0 {
+                    portal_left = left;
+                    left_index = i;
+                } else {
+                    apex = portal_right;
+                    Self::push_point(&mut points, apex);
+
+                    let apex_index = right_index;
+                    (portal_left, portal_right) = (apex, apex);
+                    (left_index, right_index) = (apex_index, apex_index);
+                    i = apex_index + 1;
+                    continue;
+                }
+            }
+
+            i += 1;
+        }
+
+        if let Some((end, _)) = portals.last() {
+            Self::push_point(&mut points, *end);
+        }
+
+        points
+    }
+
+    fn push_point(points: &mut Vec<Vector>, point: Vector) {
+        if !points.last().map(|last| Self::is_same_point(last, &point)).unwrap_or(false) {
+            points.push(point);
+        }
+    }
+
+    fn is_same_point(a: &Vector, b: &Vector) -> bool {
+        const THRESHOLD: f32 = 1.0 / 16384.0;
+        distance(a, b) < THRESHOLD
+    }
+
+    fn is_walkable(tile: &MeshTile, index: usize) -> bool {
+        tile.polys.get(index)
+            .map(|poly| poly.flags != 0 && poly.poly_type() != DT_POLYTYPE_OFFMESH_CONNECTION)
+            .unwrap_or(false)
+    }
+
+    fn side_offset(side: usize) -> (i32, i32) {
+        match side {
+            0 => (1, 0),
+            1 => (1, 1),
+            2 => (0, 1),
+            3 => (-1, 1),
+            4 => (-1, 0),
+            5 => (-1, -1),
+            6 => (0, -1),
+            _ => (1, -1),
+        }
+    }
+
+    fn poly_verts(tile: &MeshTile, index: usize) -> Vec<Vector> {
+        let poly = &tile.polys[index];
+        poly.verts[..poly.vert_count as usize].iter().map(|&vert| tile.verts[vert as usize]).collect()
+    }
+
+    fn closest_point_on_poly(tile: &MeshTile, index: usize, point: &Vector) -> Vector {
+        let verts = Self::poly_verts(tile, index);
+
+        if point_in_polygon(point, &verts) {
+            if let Some(height) = Self::get_poly_height(tile, index, point) {
+                return [point[0], height, point[2]];
+            }
+        }
+
+        (0..verts.len())
+            .map(|i| closest_point_on_segment_2d(point, &verts[i], &verts[(i + 1) % verts.len()]))
+            .min_by(|a, b| distance_2d_sqr(a, point).total_cmp(&distance_2d_sqr(b, point)))
+            .unwrap_or(*point)
+    }
+
+    // uses detail mesh if exists, otherwise triangulates the polygon itself
+    fn get_poly_height(tile: &MeshTile, index: usize, point: &Vector) -> Option<f32> {
+        let poly = &tile.polys[index];
+        let vert_count = poly.vert_count as usize;
+
+        let Some(detail) = tile.detail_meshes.get(index) else {
+            let verts = Self::poly_verts(tile, index);
+            return (1..vert_count.saturating_sub(1))
+                .find_map(|i| closest_height_on_triangle(point, &verts[0], &verts[i], &verts[i + 1]));
+        };
+
+        let get_vert = |vert: u8| -> Vector {
+            let vert = vert as usize;
+            match vert < vert_count {
+                true => tile.verts[poly.verts[vert] as usize],
+                false => tile.detail_verts[detail.vert_base as usize + vert - vert_count],
+            }
+        };
+
+        (0..detail.tri_count as usize).find_map(|i| {
+            let tri = tile.detail_tris[detail.tri_base as usize + i];
+            closest_height_on_triangle(point, &get_vert(tri[0]), &get_vert(tri[1]), &get_vert(tri[2]))
+        })
+    }
+
+    fn to_detour(position: &Position) -> Vector {
+        [position.y, position.z, position.x]
+    }
+
+    fn from_detour(point: &Vector) -> Position {
+        Position::new(point[2], point[0], point[1], 0.0)
+    }
+}
+
+// keeps navmeshes of all visited maps
+pub struct Navigator {
+    directory: Option<PathBuf>,
+    meshes: HashMap<u32, NavMesh>,
+}
+
+impl Navigator {
+    pub fn new(directory: Option<PathBuf>) -> Self {
+        Self {
+            directory,
+            meshes: HashMap::new(),
+        }
+    }
+
+    pub fn find_path(
+        &mut self,
+        map_id: u32,
+        from: &Position,
+        to: &Position,
+    ) -> Result<Vec<Position>, NavigationError> {
+        if !self.meshes.contains_key(&map_id) {
+            let directory = self.directory.as_ref().ok_or(NavigationError::NotConfigured)?;
+            self.meshes.insert(map_id, NavMesh::open(directory, map_id)?);
+        }
+
+        self.meshes.get_mut(&map_id).unwrap().find_path(from, to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+    use tempdir::TempDir;
+
+    use crate::primary::client::Position;
+    use crate::primary::errors::NavigationError;
+    use crate::primary::navigation::{NavMesh, Navigator};
+    use crate::primary::parsers::mmap_parser::MmapParser;
+    use crate::primary::parsers::mmap_parser::types::{
+        DT_EXT_LINK, DT_POLYTYPE_OFFMESH_CONNECTION, MeshHeader, MeshTile, NavMeshParams, Poly, PolyDetail,
+    };
+
+    const HEIGHT: f32 = 5.0;
+
+    // each quad is (x0, z0, x1, z1) in detour coordinates with neighbours for edges -x, +z, +x, -z
+    fn write_tile(path: &Path, x: i32, y: i32, quads: &[([f32; 4], [u16; 4])]) {
+        save_tile(path, &build_tile(x, y, quads));
+    }
+
+    fn build_tile(x: i32, y: i32, quads: &[([f32; 4], [u16; 4])]) -> MeshTile {
+        let mut tile = MeshTile {
+            header: MeshHeader { x, y, ..MeshHeader::default() },
+            ..MeshTile::default()
+        };
+
+        for (i, ([x0, z0, x1, z1], neis)) in quads.iter().enumerate() {
+            let base = tile.verts.len() as u16;
+            tile.verts.extend([[*x0, HEIGHT, *z0], [*x0, HEIGHT, *z1], [*x1, HEIGHT, *z1], [*x1, HEIGHT, *z0]]);
+            tile.polys.push(Poly {
+                verts: [base, base + 1, base + 2, base + 3, 0, 0],
+                neis: [neis[0], neis[1], neis[2], neis[3], 0, 0],
+                flags: 1,
+                vert_count: 4,
+                ..Poly::default()
+            });
+            tile.detail_meshes.push(PolyDetail { vert_base: 0, tri_base: i as u32 * 2, vert_count: 0, tri_count: 2 });
+            tile.detail_tris.extend([[0, 1, 2, 0], [0, 2, 3, 0]]);
+        }
+
+        tile
+    }
+
+    fn save_tile(path: &Path, tile: &MeshTile) {
+        let mut buffer = Vec::new();
+        MmapParser::write_tile(&mut buffer, tile).unwrap();
+        fs::write(path, buffer).unwrap();
+    }
+
+    #[test]
+    fn test_find_path() {
+        let temp_dir = TempDir::new("_mmaps").unwrap();
+        let directory = temp_dir.path();
+
+        let mut params = Vec::new();
+        MmapParser::write_params(&mut params, &NavMeshParams {
+            tile_width: 10.0,
+            tile_height: 10.0,
+            max_tiles: 4,
+            max_polys: 16,
+            ..NavMeshParams::default()
+        }).unwrap();
+        fs::write(directory.join("001.mmap"), params).unwrap();
+
+        // L-shaped corridor inside first tile, which continues into the next one
+        write_tile(&directory.join("0013131.mmtile"), 0, 0, &[
+            ([0.0, 0.0, 8.0, 2.0], [0, 0, 2, 0]),
+            ([8.0, 0.0, 10.0, 2.0], [1, 3, 0, 0]),
+            ([8.0, 2.0, 10.0, 10.0], [0, DT_EXT_LINK | 2, 0, 2]),
+        ]);
+        write_tile(&directory.join("0013031.mmtile"), 0, 1, &[
+            ([8.0, 10.0, 10.0, 20.0], [0, 0, 0, DT_EXT_LINK | 6]),
+        ]);
+
+        let mut mesh = NavMesh::open(directory, 1).unwrap();
+        assert_eq!(mesh.loaded_tiles_amount(), 0);
+
+        let path = mesh.find_path(
+            &Position::new(1.0, 1.0, 0.0, 0.0),
+            &Position::new(15.0, 9.0, 0.0, 1.5),
+        ).unwrap();
+
+        assert_eq!(path.len(), 2);
+        assert_eq!((path[0].x, path[0].y, path[0].z), (2.0, 8.0, HEIGHT));
+        assert_eq!((path[1].x, path[1].y, path[1].z), (15.0, 9.0, HEIGHT));
+        assert_eq!(path[1].orientation, 1.5);
+        assert_eq!(mesh.loaded_tiles_amount(), 2);
+
+        assert!(matches!(
+            mesh.find_path(&Position::new(1.0, 1.0, 0.0, 0.0), &Position::new(50.0, 50.0, 0.0, 0.0)),
+            Err(NavigationError::PositionNotOnMesh(..)),
+        ));
+
+        let mut navigator = Navigator::new(Some(directory.to_path_buf()));
+        assert!(matches!(
+            navigator.find_path(2, &Position::default(), &Position::default()),
+            Err(NavigationError::MapNotFound(2)),
+        ));
+
+        temp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn test_off_mesh_poly() {
+        let temp_dir = TempDir::new("_mmaps").unwrap();
+        let directory = temp_dir.path();
+
+        let mut params = Vec::new();
+        MmapParser::write_params(&mut params, &NavMeshParams {
+            tile_width: 10.0,
+            tile_height: 10.0,
+            ..NavMeshParams::default()
+        }).unwrap();
+        fs::write(directory.join("001.mmap"), params).unwrap();
+
+        // off-mesh link (for example, jump down from a ledge) has only two vertices and no detail mesh
+        let mut tile = build_tile(0, 0, &[([0.0, 0.0, 8.0, 2.0], [0, 0, 0, 0])]);
+        let base = tile.verts.len() as u16;
+        tile.verts.extend([[1.0, HEIGHT, 1.0], [7.0, HEIGHT - 3.0, 1.0]]);
+        tile.polys.push(Poly {
+            verts: [base, base + 1, 0, 0, 0, 0],
+            flags: 1,
+            vert_count: 2,
+            area_and_type: DT_POLYTYPE_OFFMESH_CONNECTION << 6,
+            ..Poly::default()
+        });
+        save_tile(&directory.join("0013131.mmtile"), &tile);
+
+        let mut mesh = NavMesh::open(directory, 1).unwrap();
+        let path = mesh.find_path(
+            &Position::new(1.0, 1.0, 0.0, 0.0),
+            &Position::new(1.0, 7.0, 0.0, 0.0),
+        ).unwrap();
+
+        assert_eq!(path.len(), 1);
+        assert_eq!((path[0].x, path[0].y, path[0].z), (1.0, 7.0, HEIGHT));
+
+        temp_dir.close().unwrap();
+    }
+
+    #[test]
+    fn test_invalid_tile() {
+        let temp_dir = TempDir::new("_mmaps").unwrap();
+        let directory = temp_dir.path();
+
+        let mut params = Vec::new();
+        MmapParser::write_params(&mut params, &NavMeshParams {
+            tile_width: 10.0,
+            tile_height: 10.0,
+            ..NavMeshParams::default()
+        }).unwrap();
+        fs::write(directory.join("001.mmap"), params).unwrap();
+
+        // neighbour index points outside of the tile
+        write_tile(&directory.join("0013131.mmtile"), 0, 0, &[
+            ([0.0, 0.0, 8.0, 2.0], [0, 0, 5, 0]),
+        ]);
+
+        let mut mesh = NavMesh::open(directory, 1).unwrap();
+        assert!(matches!(
+            mesh.find_path(&Position::new(1.0, 1.0, 0.0, 0.0), &Position::new(1.0, 5.0, 0.0, 0.0)),
+            Err(NavigationError::CannotRead(..)),
+        ));
+
+        temp_dir.close().unwrap();
+    }
+}
\ No newline at end of file
@@ -0,0 +1,218 @@
+use std::io::{Error, ErrorKind, Read, Seek, SeekFrom};
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+
+pub mod types;
+
+use crate::primary::parsers::map_parser::types::{
+    GridLiquid, GridMap, HeightMap, LiquidFlags, MAP_AREA_MAGIC, MAP_AREA_NO_AREA, MAP_CELLS, MAP_HEIGHT_AS_INT16,
+    MAP_HEIGHT_AS_INT8, MAP_HEIGHT_MAGIC, MAP_HEIGHT_NO_HEIGHT, MAP_LIQUID_MAGIC, MAP_LIQUID_NO_HEIGHT,
+    MAP_LIQUID_NO_TYPE, MAP_MAGIC, MAP_RESOLUTION,
+};
+
+const V9_SIZE: usize = (MAP_RESOLUTION + 1) * (MAP_RESOLUTION + 1);
+const V8_SIZE: usize = MAP_RESOLUTION * MAP_RESOLUTION;
+const CELLS_SIZE: usize = MAP_CELLS * MAP_CELLS;
+
+// file header size, each section follows it in the order: area, height, liquid
+const MAP_HEADER_SIZE: u32 = 44;
+
+pub struct MapParser;
+
+impl MapParser {
+    pub fn parse<R: Read + Seek>(reader: &mut R) -> Result<GridMap, Error> {
+        Self::read_magic(reader, MAP_MAGIC)?;
+        let _version = reader.read_u32::<LittleEndian>()?;
+        let _build = reader.read_u32::<LittleEndian>()?;
+
+        let area_offset = reader.read_u32::<LittleEndian>()?;
+        let _area_size = reader.read_u32::<LittleEndian>()?;
+        let height_offset = reader.read_u32::<LittleEndian>()?;
+        let _height_size = reader.read_u32::<LittleEndian>()?;
+        let liquid_offset = reader.read_u32::<LittleEndian>()?;
+        let _liquid_size = reader.read_u32::<LittleEndian>()?;
+
+        let mut grid = GridMap::default();
+
+        if area_offset != 0 {
+            reader.seek(SeekFrom::Start(area_offset as u64))?;
+            Self::read_magic(reader, MAP_AREA_MAGIC)?;
+
+            let flags = reader.read_u16::<LittleEndian>()?;
+            grid.area_id = reader.read_u16::<LittleEndian>()?;
+
+            if flags & MAP_AREA_NO_AREA == 0 {
+                let mut area_map = vec![0; CELLS_SIZE];
+                reader.read_u16_into::<LittleEndian>(&mut area_map)?;
+                grid.area_map = Some(area_map);
+            }
+        }
+
+        if height_offset != 0 {
+            reader.seek(SeekFrom::Start(height_offset as u64))?;
+            Self::read_magic(reader, MAP_HEIGHT_MAGIC)?;
+
+            let flags = reader.read_u32::<LittleEndian>()?;
+            grid.height = reader.read_f32::<LittleEndian>()?;
+            let max_height = reader.read_f32::<LittleEndian>()?;
+
+            if flags & MAP_HEIGHT_NO_HEIGHT == 0 {
+                grid.height_map = Some(Self::read_height_map(reader, flags, grid.height, max_height)?);
+            }
+        }
+
+        if liquid_offset != 0 {
+            reader.seek(SeekFrom::Start(liquid_offset as u64))?;
+            Self::read_magic(reader, MAP_LIQUID_MAGIC)?;
+
+            let flags = reader.read_u8()?;
+            let mut liquid = GridLiquid {
+                flags: LiquidFlags::from_bits_truncate(reader.read_u8()?),
+                entry: reader.read_u16::<LittleEndian>()?,
+                offset_x: reader.read_u8()?,
+                offset_y: reader.read_u8()?,
+                width: reader.read_u8()?,
+                height: reader.read_u8()?,
+                level: reader.read_f32::<LittleEndian>()?,
+                ..GridLiquid::default()
+            };
+
+            if flags & MAP_LIQUID_NO_TYPE == 0 {
+                let mut entries = vec![0; CELLS_SIZE];
+                reader.read_u16_into::<LittleEndian>(&mut entries)?;
+                let mut cell_flags = vec![0; CELLS_SIZE];
+                reader.read_exact(&mut cell_flags)?;
+
+                liquid.entries = Some(entries);
+                liquid.cell_flags = Some(cell_flags);
+            }
+
+            if flags & MAP_LIQUID_NO_HEIGHT == 0 {
+                let mut heights = vec![0.0; liquid.width as usize * liquid.height as usize];
+                reader.read_f32_into::<LittleEndian>(&mut heights)?;
+                liquid.heights = Some(heights);
+            }
+
+            grid.liquid = Some(liquid);
+        }
+
+        Ok(grid)
+    }
+
+    // writes heights as floats, flight bounds and holes are not supported
+    pub fn write(buffer: &mut Vec<u8>, grid: &GridMap) -> Result<(), Error> {
+        let mut area = Vec::new();
+        area.extend_from_slice(&MAP_AREA_MAGIC);
+        area.write_u16::<LittleEndian>(if grid.area_map.is_some() { 0 } else { MAP_AREA_NO_AREA })?;
+        area.write_u16::<LittleEndian>(grid.area_id)?;
+        for area_id in grid.area_map.iter().flatten() {
+            area.write_u16::<LittleEndian>(*area_id)?;
+        }
+
+        let mut height = Vec::new();
+        height.extend_from_slice(&MAP_HEIGHT_MAGIC);
+        height.write_u32::<LittleEndian>(if grid.height_map.is_some() { 0 } else { MAP_HEIGHT_NO_HEIGHT })?;
+        height.write_f32::<LittleEndian>(grid.height)?;
+        height.write_f32::<LittleEndian>(grid.height)?;
+        if let Some(HeightMap { v9, v8 }) = grid.height_map.as_ref() {
+            for value in v9.iter().chain(v8.iter()) {
+                height.write_f32::<LittleEndian>(*value)?;
+            }
+        }
+
+        let mut liquid = Vec::new();
+        if let Some(grid_liquid) = grid.liquid.as_ref() {
+            let mut flags = 0;
+            if grid_liquid.entries.is_none() || grid_liquid.cell_flags.is_none() {
+                flags |= MAP_LIQUID_NO_TYPE;
+            }
+            if grid_liquid.heights.is_none() {
+                flags |= MAP_LIQUID_NO_HEIGHT;
+            }
+
+            liquid.extend_from_slice(&MAP_LIQUID_MAGIC);
+            liquid.write_u8(flags)?;
+            liquid.write_u8(grid_liquid.flags.bits())?;
+            liquid.write_u16::<LittleEndian>(grid_liquid.entry)?;
+            liquid.write_u8(grid_liquid.offset_x)?;
+            liquid.write_u8(grid_liquid.offset_y)?;
+            liquid.write_u8(grid_liquid.width)?;
+            liquid.write_u8(grid_liquid.height)?;
+            liquid.write_f32::<LittleEndian>(grid_liquid.level)?;
+
+            if let (Some(entries), Some(cell_flags)) = (&grid_liquid.entries, &grid_liquid.cell_flags) {
+                for entry in entries {
+                    liquid.write_u16::<LittleEndian>(*entry)?;
+                }
+                liquid.extend_from_slice(cell_flags);
+            }
+
+            for value in grid_liquid.heights.iter().flatten() {
+                liquid.write_f32::<LittleEndian>(*value)?;
+            }
+        }
+
+        let area_offset = MAP_HEADER_SIZE;
+        let height_offset = area_offset + area.len() as u32;
+        let liquid_offset = height_offset + height.len() as u32;
+
+        buffer.extend_from_slice(&MAP_MAGIC);
+        buffer.write_u32::<LittleEndian>(0)?;
+        buffer.write_u32::<LittleEndian>(0)?;
+        buffer.write_u32::<LittleEndian>(area_offset)?;
+        buffer.write_u32::<LittleEndian>(area.len() as u32)?;
+        buffer.write_u32::<LittleEndian>(height_offset)?;
+        buffer.write_u32::<LittleEndian>(height.len() as u32)?;
+        buffer.write_u32::<LittleEndian>(if liquid.is_empty() { 0 } else { liquid_offset })?;
+        buffer.write_u32::<LittleEndian>(liquid.len() as u32)?;
+        // holes
+        buffer.write_u32::<LittleEndian>(0)?;
+        buffer.write_u32::<LittleEndian>(0)?;
+
+        buffer.extend_from_slice(&area);
+        buffer.extend_from_slice(&height);
+        buffer.extend_from_slice(&liquid);
+
+        Ok(())
+    }
+
+    fn read_height_map<R: Read>(
+        reader: &mut R,
+        flags: u32,
+        min_height: f32,
+        max_height: f32,
+    ) -> Result<HeightMap, Error> {
+        let mut v9 = vec![0.0; V9_SIZE];
+        let mut v8 = vec![0.0; V8_SIZE];
+
+        if flags & MAP_HEIGHT_AS_INT16 != 0 {
+            let multiplier = (max_height - min_height) / 65535.0;
+            for value in v9.iter_mut().chain(v8.iter_mut()) {
+                *value = reader.read_u16::<LittleEndian>()? as f32 * multiplier + min_height;
+            }
+        } else if flags & MAP_HEIGHT_AS_INT8 != 0 {
+            let multiplier = (max_height - min_height) / 255.0;
+            for value in v9.iter_mut().chain(v8.iter_mut()) {
+                *value = reader.read_u8()? as f32 * multiplier + min_height;
+            }
+        } else {
+            reader.read_f32_into::<LittleEndian>(&mut v9)?;
+            reader.read_f32_into::<LittleEndian>(&mut v8)?;
+        }
+
+        Ok(HeightMap { v9, v8 })
+    }
+
+    fn read_magic<R: Read>(reader: &mut R, expected: [u8; 4]) -> Result<(), Error> {
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+
+        if magic != expected {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("expected '{}' magic", String::from_utf8_lossy(&expected)),
+            ));
+        }
+
+        Ok(())
+    }
+}
\ No newline at end of file
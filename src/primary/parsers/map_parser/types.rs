@@ -0,0 +1,179 @@
+use bitflags::bitflags;
+
+pub const MAP_MAGIC: [u8; 4] = *b"MAPS";
+pub const MAP_AREA_MAGIC: [u8; 4] = *b"AREA";
+pub const MAP_HEIGHT_MAGIC: [u8; 4] = *b"MHGT";
+pub const MAP_LIQUID_MAGIC: [u8; 4] = *b"MLIQ";
+
+pub const MAP_AREA_NO_AREA: u16 = 0x0001;
+
+pub const MAP_HEIGHT_NO_HEIGHT: u32 = 0x0001;
+pub const MAP_HEIGHT_AS_INT16: u32 = 0x0002;
+pub const MAP_HEIGHT_AS_INT8: u32 = 0x0004;
+
+pub const MAP_LIQUID_NO_TYPE: u8 = 0x01;
+pub const MAP_LIQUID_NO_HEIGHT: u8 = 0x02;
+
+// amount of height cells per grid side
+pub const MAP_RESOLUTION: usize = 128;
+// amount of area (and liquid type) cells per grid side
+pub const MAP_CELLS: usize = 16;
+pub const SIZE_OF_GRIDS: f32 = 533.333_3;
+pub const CENTER_GRID_ID: f32 = 32.0;
+pub const MAX_NUMBER_OF_GRIDS: i32 = 64;
+
+bitflags! {
+    #[derive(Default, Clone, Copy, Debug, PartialEq)]
+    pub struct LiquidFlags: u8 {
+        const WATER = 0x01;
+        const OCEAN = 0x02;
+        const MAGMA = 0x04;
+        const SLIME = 0x08;
+        const DARK_WATER = 0x10;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LiquidInfo {
+    pub level: f32,
+    pub entry: u16,
+    pub flags: LiquidFlags,
+}
+
+impl LiquidInfo {
+    pub fn is_under_surface(&self, z: f32) -> bool {
+        z < self.level
+    }
+}
+
+// heights are stored unpacked, even if file contains them as integers
+#[derive(Debug, Clone, Default)]
+pub struct HeightMap {
+    // 129 x 129 grid corners
+    pub v9: Vec<f32>,
+    // 128 x 128 cell centers
+    pub v8: Vec<f32>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct GridLiquid {
+    pub entry: u16,
+    pub flags: LiquidFlags,
+    pub level: f32,
+    pub offset_x: u8,
+    pub offset_y: u8,
+    pub width: u8,
+    pub height: u8,
+    pub entries: Option<Vec<u16>>,
+    pub cell_flags: Option<Vec<u8>>,
+    pub heights: Option<Vec<f32>>,
+}
+
+// content of the maps/<map><x><y>.map file extracted by the server tools
+#[derive(Debug, Clone, Default)]
+pub struct GridMap {
+    pub area_id: u16,
+    pub area_map: Option<Vec<u16>>,
+    pub height: f32,
+    pub height_map: Option<HeightMap>,
+    pub liquid: Option<GridLiquid>,
+}
+
+impl GridMap {
+    // returns grid index and offset inside the grid in cells of given resolution
+    pub fn get_grid_coords(x: f32, y: f32) -> Option<(u32, u32)> {
+        let grid_x = (CENTER_GRID_ID - x / SIZE_OF_GRIDS).floor() as i32;
+        let grid_y = (CENTER_GRID_ID - y / SIZE_OF_GRIDS).floor() as i32;
+
+        match (0..MAX_NUMBER_OF_GRIDS).contains(&grid_x) && (0..MAX_NUMBER_OF_GRIDS).contains(&grid_y) {
+            true => Some((grid_x as u32, grid_y as u32)),
+            false => None,
+        }
+    }
+
+    fn get_cell(x: f32, y: f32, resolution: usize) -> (f32, f32) {
+        (
+            resolution as f32 * (CENTER_GRID_ID - x / SIZE_OF_GRIDS),
+            resolution as f32 * (CENTER_GRID_ID - y / SIZE_OF_GRIDS),
+        )
+    }
+
+    pub fn area_id_at(&self, x: f32, y: f32) -> u16 {
+        let Some(area_map) = self.area_map.as_ref() else {
+            return self.area_id;
+        };
+
+        let (x, y) = Self::get_cell(x, y, MAP_CELLS);
+        let cell_x = x as usize & (MAP_CELLS - 1);
+        let cell_y = y as usize & (MAP_CELLS - 1);
+
+        area_map[cell_x * MAP_CELLS + cell_y]
+    }
+
+    // each cell is split into 4 triangles by its center (v8) and corners (v9),
+    // height is interpolated inside the triangle which contains the point
+    pub fn height_at(&self, x: f32, y: f32) -> f32 {
+        let Some(HeightMap { v9, v8 }) = self.height_map.as_ref() else {
+            return self.height;
+        };
+
+        let (x, y) = Self::get_cell(x, y, MAP_RESOLUTION);
+        let (x_int, y_int) = (x as usize, y as usize);
+        let (x, y) = (x - x_int as f32, y - y_int as f32);
+        let (x_int, y_int) = (x_int & (MAP_RESOLUTION - 1), y_int & (MAP_RESOLUTION - 1));
+
+        let corner = |dx: usize, dy: usize| v9[(x_int + dx) * (MAP_RESOLUTION + 1) + y_int + dy];
+        let h5 = 2.0 * v8[x_int * MAP_RESOLUTION + y_int];
+
+        let (a, b, c) = if x + y < 1.0 {
+            if x > y {
+                let (h1, h2) = (corner(0, 0), corner(1, 0));
+                (h2 - h1, h5 - h1 - h2, h1)
+            } else {
+                let (h1, h3) = (corner(0, 0), corner(0, 1));
+                (h5 - h1 - h3, h3 - h1, h1)
+            }
+        } else if x > y {
+            let (h2, h4) = (corner(1, 0), corner(1, 1));
+            (h2 + h4 - h5, h4 - h2, h5 - h4)
+        } else {
+            let (h3, h4) = (corner(0, 1), corner(1, 1));
+            (h4 - h3, h3 + h4 - h5, h5 - h4)
+        };
+
+        a * x + b * y + c
+    }
+
+    pub fn liquid_at(&self, x: f32, y: f32) -> Option<LiquidInfo> {
+        let liquid = self.liquid.as_ref()?;
+
+        let (x, y) = Self::get_cell(x, y, MAP_RESOLUTION);
+        let x_int = x as usize & (MAP_RESOLUTION - 1);
+        let y_int = y as usize & (MAP_RESOLUTION - 1);
+
+        // liquid covers only the part of the grid, heights are stored for this part only
+        let liquid_x = x_int as isize - liquid.offset_y as isize;
+        let liquid_y = y_int as isize - liquid.offset_x as isize;
+        if !(0..liquid.height as isize).contains(&liquid_x) || !(0..liquid.width as isize).contains(&liquid_y) {
+            return None;
+        }
+
+        let cell = (x_int >> 3) * MAP_CELLS + (y_int >> 3);
+        let flags = liquid.cell_flags.as_ref()
+            .map(|flags| LiquidFlags::from_bits_truncate(flags[cell]))
+            .unwrap_or(liquid.flags);
+
+        if flags.is_empty() {
+            return None;
+        }
+
+        let entry = liquid.entries.as_ref().map(|entries| entries[cell]).unwrap_or(liquid.entry);
+
+        let level = match liquid.heights.as_ref() {
+            Some(heights) => heights[liquid_x as usize * liquid.width as usize + liquid_y as usize],
+            None => liquid.level,
+        };
+
+        Some(LiquidInfo { level, entry, flags })
+    }
+}
\ No newline at end of file
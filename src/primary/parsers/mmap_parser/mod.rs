@@ -0,0 +1,309 @@
+use std::io::{Error, ErrorKind, Read, sink};
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+
+pub mod types;
+
+use crate::primary::parsers::mmap_parser::types::{
+    DT_BV_NODE_SIZE, DT_EXT_LINK, DT_LINK_SIZE, DT_NAVMESH_MAGIC, DT_NAVMESH_VERSION, DT_OFF_MESH_CONNECTION_SIZE,
+    DT_POLYTYPE_OFFMESH_CONNECTION, DT_VERTS_PER_POLYGON, MeshHeader, MeshTile, MMAP_MAGIC, MMAP_VERSION,
+    MmapTileHeader, NavMeshParams, Poly, PolyDetail,
+};
+
+pub struct MmapParser;
+
+impl MmapParser {
+    pub fn parse_params<R: Read>(reader: &mut R) -> Result<NavMeshParams, Error> {
+        Ok(NavMeshParams {
+            origin: Self::read_vector(reader)?,
+            tile_width: reader.read_f32::<LittleEndian>()?,
+            tile_height: reader.read_f32::<LittleEndian>()?,
+            max_tiles: reader.read_i32::<LittleEndian>()?,
+            max_polys: reader.read_i32::<LittleEndian>()?,
+        })
+    }
+
+    // reads only the headers, so tiles can be indexed without loading them
+    pub fn parse_tile_header<R: Read>(reader: &mut R) -> Result<(MmapTileHeader, MeshHeader), Error> {
+        let magic = reader.read_u32::<LittleEndian>()?;
+        if magic != MMAP_MAGIC {
+            return Err(Error::new(ErrorKind::InvalidData, format!("invalid mmap magic {:#X}", magic)));
+        }
+
+        let mmap_header = MmapTileHeader {
+            dt_version: reader.read_u32::<LittleEndian>()?,
+            mmap_version: reader.read_u32::<LittleEndian>()?,
+            size: reader.read_u32::<LittleEndian>()?,
+            uses_liquids: {
+                let mut uses_liquids = [0; 4];
+                reader.read_exact(&mut uses_liquids)?;
+                uses_liquids[0] != 0
+            },
+        };
+
+        if mmap_header.mmap_version != MMAP_VERSION {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unsupported mmap version {}", mmap_header.mmap_version),
+            ));
+        }
+
+        if mmap_header.dt_version != DT_NAVMESH_VERSION {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unsupported detour version {}", mmap_header.dt_version),
+            ));
+        }
+
+        let magic = reader.read_u32::<LittleEndian>()?;
+        if magic != DT_NAVMESH_MAGIC {
+            return Err(Error::new(ErrorKind::InvalidData, format!("invalid detour magic {:#X}", magic)));
+        }
+
+        let _version = reader.read_u32::<LittleEndian>()?;
+
+        let mesh_header = MeshHeader {
+            x: reader.read_i32::<LittleEndian>()?,
+            y: reader.read_i32::<LittleEndian>()?,
+            layer: reader.read_i32::<LittleEndian>()?,
+            user_id: reader.read_u32::<LittleEndian>()?,
+            poly_count: reader.read_i32::<LittleEndian>()?,
+            vert_count: reader.read_i32::<LittleEndian>()?,
+            max_link_count: reader.read_i32::<LittleEndian>()?,
+            detail_mesh_count: reader.read_i32::<LittleEndian>()?,
+            detail_vert_count: reader.read_i32::<LittleEndian>()?,
+            detail_tri_count: reader.read_i32::<LittleEndian>()?,
+            bv_node_count: reader.read_i32::<LittleEndian>()?,
+            off_mesh_con_count: reader.read_i32::<LittleEndian>()?,
+            off_mesh_base: reader.read_i32::<LittleEndian>()?,
+            walkable_height: reader.read_f32::<LittleEndian>()?,
+            walkable_radius: reader.read_f32::<LittleEndian>()?,
+            walkable_climb: reader.read_f32::<LittleEndian>()?,
+            bmin: Self::read_vector(reader)?,
+            bmax: Self::read_vector(reader)?,
+            bv_quant_factor: reader.read_f32::<LittleEndian>()?,
+        };
+
+        Ok((mmap_header, mesh_header))
+    }
+
+    pub fn parse_tile<R: Read>(reader: &mut R) -> Result<MeshTile, Error> {
+        let (_, header) = Self::parse_tile_header(reader)?;
+
+        let verts = (0..header.vert_count)
+            .map(|_| Self::read_vector(reader))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let polys = (0..header.poly_count).map(|_| -> Result<Poly, Error> {
+            let first_link = reader.read_u32::<LittleEndian>()?;
+            let mut verts = [0; DT_VERTS_PER_POLYGON];
+            reader.read_u16_into::<LittleEndian>(&mut verts)?;
+            let mut neis = [0; DT_VERTS_PER_POLYGON];
+            reader.read_u16_into::<LittleEndian>(&mut neis)?;
+
+            Ok(Poly {
+                first_link,
+                verts,
+                neis,
+                flags: reader.read_u16::<LittleEndian>()?,
+                vert_count: reader.read_u8()?,
+                area_and_type: reader.read_u8()?,
+            })
+        }).collect::<Result<Vec<_>, _>>()?;
+
+        // links are built by detour at runtime, so stored ones are just placeholders
+        Self::skip(reader, header.max_link_count as u64 * DT_LINK_SIZE)?;
+
+        let detail_meshes = (0..header.detail_mesh_count).map(|_| -> Result<PolyDetail, Error> {
+            let detail = PolyDetail {
+                vert_base: reader.read_u32::<LittleEndian>()?,
+                tri_base: reader.read_u32::<LittleEndian>()?,
+                vert_count: reader.read_u8()?,
+                tri_count: reader.read_u8()?,
+            };
+            // struct padding
+            reader.read_u16::<LittleEndian>()?;
+
+            Ok(detail)
+        }).collect::<Result<Vec<_>, _>>()?;
+
+        let detail_verts = (0..header.detail_vert_count)
+            .map(|_| Self::read_vector(reader))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let detail_tris = (0..header.detail_tri_count).map(|_| -> Result<[u8; 4], Error> {
+            let mut tri = [0; 4];
+            reader.read_exact(&mut tri)?;
+            Ok(tri)
+        }).collect::<Result<Vec<_>, _>>()?;
+
+        Self::skip(
+            reader,
+            header.bv_node_count as u64 * DT_BV_NODE_SIZE
+                + header.off_mesh_con_count as u64 * DT_OFF_MESH_CONNECTION_SIZE,
+        )?;
+
+        let tile = MeshTile { header, verts, polys, detail_meshes, detail_verts, detail_tris };
+        Self::validate_tile(&tile)?;
+
+        Ok(tile)
+    }
+
+    // indices from the file are used to access tile data, so broken tile should not pass
+    fn validate_tile(tile: &MeshTile) -> Result<(), Error> {
+        let invalid = |message: String| Error::new(ErrorKind::InvalidData, message);
+
+        for (index, poly) in tile.polys.iter().enumerate() {
+            // off-mesh connection is a segment between two points and is never walked as polygon
+            if poly.poly_type() == DT_POLYTYPE_OFFMESH_CONNECTION {
+                continue;
+            }
+
+            let vert_count = poly.vert_count as usize;
+            if !(3..=DT_VERTS_PER_POLYGON).contains(&vert_count) {
+                return Err(invalid(format!("poly {} has {} vertices", index, vert_count)));
+            }
+
+            if let Some(vert) = poly.verts[..vert_count].iter().find(|&&vert| vert as usize >= tile.verts.len()) {
+                return Err(invalid(format!("poly {} refers to missing vertex {}", index, vert)));
+            }
+
+            let missing_neighbour = poly.neis[..vert_count].iter().find(|&&nei| {
+                nei != 0 && nei & DT_EXT_LINK == 0 && nei as usize > tile.polys.len()
+            });
+            if let Some(nei) = missing_neighbour {
+                return Err(invalid(format!("poly {} refers to missing neighbour {}", index, nei)));
+            }
+        }
+
+        for (index, (detail, poly)) in tile.detail_meshes.iter().zip(tile.polys.iter()).enumerate() {
+            let vert_end = detail.vert_base as usize + detail.vert_count as usize;
+            let tri_end = detail.tri_base as usize + detail.tri_count as usize;
+            if vert_end > tile.detail_verts.len() || tri_end > tile.detail_tris.len() {
+                return Err(invalid(format!("detail mesh {} is out of detail data", index)));
+            }
+
+            // detail triangle refers to poly vertices first, then to own detail vertices
+            let max_vert = poly.vert_count as usize + detail.vert_count as usize;
+            let is_valid = tile.detail_tris[detail.tri_base as usize..tri_end].iter()
+                .all(|tri| tri[..3].iter().all(|&vert| (vert as usize) < max_vert));
+            if !is_valid {
+                return Err(invalid(format!("detail mesh {} refers to missing vertex", index)));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn write_params(buffer: &mut Vec<u8>, params: &NavMeshParams) -> Result<(), Error> {
+        Self::write_vector(buffer, &params.origin)?;
+        buffer.write_f32::<LittleEndian>(params.tile_width)?;
+        buffer.write_f32::<LittleEndian>(params.tile_height)?;
+        buffer.write_i32::<LittleEndian>(params.max_tiles)?;
+        buffer.write_i32::<LittleEndian>(params.max_polys)?;
+
+        Ok(())
+    }
+
+    // writes tile without links, bv tree and off-mesh connections
+    pub fn write_tile(buffer: &mut Vec<u8>, tile: &MeshTile) -> Result<(), Error> {
+        let mut data = Vec::new();
+        let header = MeshHeader {
+            poly_count: tile.polys.len() as i32,
+            vert_count: tile.verts.len() as i32,
+            max_link_count: 0,
+            detail_mesh_count: tile.detail_meshes.len() as i32,
+            detail_vert_count: tile.detail_verts.len() as i32,
+            detail_tri_count: tile.detail_tris.len() as i32,
+            bv_node_count: 0,
+            off_mesh_con_count: 0,
+            ..tile.header
+        };
+
+        data.write_u32::<LittleEndian>(DT_NAVMESH_MAGIC)?;
+        data.write_u32::<LittleEndian>(DT_NAVMESH_VERSION)?;
+        data.write_i32::<LittleEndian>(header.x)?;
+        data.write_i32::<LittleEndian>(header.y)?;
+        data.write_i32::<LittleEndian>(header.layer)?;
+        data.write_u32::<LittleEndian>(header.user_id)?;
+        data.write_i32::<LittleEndian>(header.poly_count)?;
+        data.write_i32::<LittleEndian>(header.vert_count)?;
+        data.write_i32::<LittleEndian>(header.max_link_count)?;
+        data.write_i32::<LittleEndian>(header.detail_mesh_count)?;
+        data.write_i32::<LittleEndian>(header.detail_vert_count)?;
+        data.write_i32::<LittleEndian>(header.detail_tri_count)?;
+        data.write_i32::<LittleEndian>(header.bv_node_count)?;
+        data.write_i32::<LittleEndian>(header.off_mesh_con_count)?;
+        data.write_i32::<LittleEndian>(header.off_mesh_base)?;
+        data.write_f32::<LittleEndian>(header.walkable_height)?;
+        data.write_f32::<LittleEndian>(header.walkable_radius)?;
+        data.write_f32::<LittleEndian>(header.walkable_climb)?;
+        Self::write_vector(&mut data, &header.bmin)?;
+        Self::write_vector(&mut data, &header.bmax)?;
+        data.write_f32::<LittleEndian>(header.bv_quant_factor)?;
+
+        for vert in tile.verts.iter() {
+            Self::write_vector(&mut data, vert)?;
+        }
+
+        for poly in tile.polys.iter() {
+            data.write_u32::<LittleEndian>(poly.first_link)?;
+            for vert in poly.verts {
+                data.write_u16::<LittleEndian>(vert)?;
+            }
+            for nei in poly.neis {
+                data.write_u16::<LittleEndian>(nei)?;
+            }
+            data.write_u16::<LittleEndian>(poly.flags)?;
+            data.write_u8(poly.vert_count)?;
+            data.write_u8(poly.area_and_type)?;
+        }
+
+        for detail in tile.detail_meshes.iter() {
+            data.write_u32::<LittleEndian>(detail.vert_base)?;
+            data.write_u32::<LittleEndian>(detail.tri_base)?;
+            data.write_u8(detail.vert_count)?;
+            data.write_u8(detail.tri_count)?;
+            data.write_u16::<LittleEndian>(0)?;
+        }
+
+        for vert in tile.detail_verts.iter() {
+            Self::write_vector(&mut data, vert)?;
+        }
+
+        for tri in tile.detail_tris.iter() {
+            data.extend_from_slice(tri);
+        }
+
+        buffer.write_u32::<LittleEndian>(MMAP_MAGIC)?;
+        buffer.write_u32::<LittleEndian>(DT_NAVMESH_VERSION)?;
+        buffer.write_u32::<LittleEndian>(MMAP_VERSION)?;
+        buffer.write_u32::<LittleEndian>(data.len() as u32)?;
+        buffer.extend_from_slice(&[0; 4]);
+        buffer.extend_from_slice(&data);
+
+        Ok(())
+    }
+
+    fn read_vector<R: Read>(reader: &mut R) -> Result<[f32; 3], Error> {
+        let mut vector = [0.0; 3];
+        reader.read_f32_into::<LittleEndian>(&mut vector)?;
+        Ok(vector)
+    }
+
+    fn write_vector(buffer: &mut Vec<u8>, vector: &[f32; 3]) -> Result<(), Error> {
+        for value in vector {
+            buffer.write_f32::<LittleEndian>(*value)?;
+        }
+
+        Ok(())
+    }
+
+    fn skip<R: Read>(reader: &mut R, size: u64) -> Result<(), Error> {
+        let skipped = std::io::copy(&mut reader.take(size), &mut sink())?;
+        if skipped != size {
+            return Err(Error::from(ErrorKind::UnexpectedEof));
+        }
+
+        Ok(())
+    }
+}
\ No newline at end of file
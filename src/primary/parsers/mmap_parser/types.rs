@@ -0,0 +1,91 @@
+pub const MMAP_MAGIC: u32 = 0x4d4d4150;
+pub const MMAP_VERSION: u32 = 15;
+pub const DT_NAVMESH_MAGIC: u32 = 0x444e4156;
+pub const DT_NAVMESH_VERSION: u32 = 7;
+
+pub const DT_VERTS_PER_POLYGON: usize = 6;
+// neighbour in another tile, lower bits contain the side of the tile
+pub const DT_EXT_LINK: u16 = 0x8000;
+pub const DT_POLYTYPE_OFFMESH_CONNECTION: u8 = 1;
+
+// sizes of the sections which are not used for path query
+pub const DT_LINK_SIZE: u64 = 16;
+pub const DT_BV_NODE_SIZE: u64 = 16;
+pub const DT_OFF_MESH_CONNECTION_SIZE: u64 = 36;
+
+// content of the <map>.mmap file
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NavMeshParams {
+    pub origin: [f32; 3],
+    pub tile_width: f32,
+    pub tile_height: f32,
+    pub max_tiles: i32,
+    pub max_polys: i32,
+}
+
+// header which server extractor puts before detour data in each .mmtile file
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MmapTileHeader {
+    pub dt_version: u32,
+    pub mmap_version: u32,
+    pub size: u32,
+    pub uses_liquids: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MeshHeader {
+    pub x: i32,
+    pub y: i32,
+    pub layer: i32,
+    pub user_id: u32,
+    pub poly_count: i32,
+    pub vert_count: i32,
+    pub max_link_count: i32,
+    pub detail_mesh_count: i32,
+    pub detail_vert_count: i32,
+    pub detail_tri_count: i32,
+    pub bv_node_count: i32,
+    pub off_mesh_con_count: i32,
+    pub off_mesh_base: i32,
+    pub walkable_height: f32,
+    pub walkable_radius: f32,
+    pub walkable_climb: f32,
+    pub bmin: [f32; 3],
+    pub bmax: [f32; 3],
+    pub bv_quant_factor: f32,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Poly {
+    pub first_link: u32,
+    pub verts: [u16; DT_VERTS_PER_POLYGON],
+    pub neis: [u16; DT_VERTS_PER_POLYGON],
+    pub flags: u16,
+    pub vert_count: u8,
+    pub area_and_type: u8,
+}
+
+impl Poly {
+    pub fn poly_type(&self) -> u8 {
+        self.area_and_type >> 6
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PolyDetail {
+    pub vert_base: u32,
+    pub tri_base: u32,
+    pub vert_count: u8,
+    pub tri_count: u8,
+}
+
+// detour coordinates are [y, z, x] in terms of the game coordinates
+#[derive(Debug, Clone, Default)]
+pub struct MeshTile {
+    pub header: MeshHeader,
+    pub verts: Vec<[f32; 3]>,
+    pub polys: Vec<Poly>,
+    pub detail_meshes: Vec<PolyDetail>,
+    pub detail_verts: Vec<[f32; 3]>,
+    pub detail_tris: Vec<[u8; 4]>,
+}
\ No newline at end of file
@@ -1,3 +1,6 @@
+pub mod map_parser;
+pub mod mmap_parser;
 pub mod movement_parser;
 pub mod position_parser;
+pub mod spline_parser;
 pub mod update_block_parser;
\ No newline at end of file
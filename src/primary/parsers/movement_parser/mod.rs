@@ -1,13 +1,14 @@
 use std::io::{BufRead, Error};
-use byteorder::{LittleEndian, ReadBytesExt};
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
 use crate::primary::client::{MovementFlags, MovementFlagsExtra};
 
 pub mod types;
 
-use crate::primary::parsers::movement_parser::types::{JumpInfo, MovementInfo};
+use crate::primary::parsers::movement_parser::types::{JumpInfo, MovementInfo, TransportInfo};
 use crate::primary::parsers::position_parser::PositionParser;
-use crate::primary::utils::read_packed_guid;
+use crate::primary::types::ObjectGuid;
+use crate::primary::utils::{read_packed_guid, write_packed_guid};
 
 pub struct MovementParser;
 
@@ -25,18 +26,19 @@ impl MovementParser {
 
         let position = PositionParser::parse(reader)?;
 
+        let mut transport = None;
         if movement_flags.contains(MovementFlags::TAXI) {
-            let _transport_guid = read_packed_guid(reader);
-
-            // transport x, y, z, orientation
-            let _position = PositionParser::parse(reader);
-
-            let _transport_time = reader.read_u32::<LittleEndian>()?;
-            let _transport_seat = reader.read_u8()?;
+            let guid = ObjectGuid::new(read_packed_guid(reader));
+            let offset = PositionParser::parse(reader)?;
+            let time = reader.read_u32::<LittleEndian>()?;
+            let seat = reader.read_u8()?;
 
+            let mut interpolated_time = None;
             if movement_flags_extra.contains(MovementFlagsExtra::INTERPOLATED_MOVEMENT) {
-                let _transport_time = reader.read_u32::<LittleEndian>()?;
+                interpolated_time = Some(reader.read_u32::<LittleEndian>()?);
             }
+
+            transport = Some(TransportInfo { guid, offset, time, seat, interpolated_time });
         }
 
         if movement_flags.contains(MovementFlags::SWIMMING)  ||
@@ -75,8 +77,62 @@ impl MovementParser {
                 jump_cos_angle,
                 jump_horizontal_speed
             },
+            transport,
         };
 
         Ok(movement_info)
     }
+
+    // pitch and spline elevation are not stored in MovementInfo, so zeroes are written
+    pub fn write(buffer: &mut Vec<u8>, movement_info: &MovementInfo) -> Result<(), Error> {
+        let MovementInfo {
+            movement_flags,
+            movement_flags_extra,
+            time,
+            position,
+            fall_time,
+            jump_info,
+            transport,
+        } = movement_info;
+
+        buffer.write_u32::<LittleEndian>(movement_flags.bits())?;
+        buffer.write_u16::<LittleEndian>(movement_flags_extra.bits())?;
+        buffer.write_u32::<LittleEndian>(*time)?;
+
+        PositionParser::write(buffer, position)?;
+
+        if movement_flags.contains(MovementFlags::TAXI) {
+            let transport = transport.unwrap_or_default();
+
+            write_packed_guid(buffer, transport.guid.raw())?;
+            PositionParser::write(buffer, &transport.offset)?;
+            buffer.write_u32::<LittleEndian>(transport.time)?;
+            buffer.write_u8(transport.seat)?;
+
+            if movement_flags_extra.contains(MovementFlagsExtra::INTERPOLATED_MOVEMENT) {
+                buffer.write_u32::<LittleEndian>(transport.interpolated_time.unwrap_or_default())?;
+            }
+        }
+
+        if movement_flags.contains(MovementFlags::SWIMMING)  ||
+            movement_flags.contains(MovementFlags::FLYING) ||
+            movement_flags_extra.contains(MovementFlagsExtra::ALWAYS_ALLOW_PITCHING) {
+            buffer.write_f32::<LittleEndian>(0.0)?;
+        }
+
+        buffer.write_u32::<LittleEndian>(*fall_time)?;
+
+        if movement_flags.contains(MovementFlags::JUMPING) {
+            buffer.write_f32::<LittleEndian>(jump_info.jump_vertical_speed)?;
+            buffer.write_f32::<LittleEndian>(jump_info.jump_sin_angle)?;
+            buffer.write_f32::<LittleEndian>(jump_info.jump_cos_angle)?;
+            buffer.write_f32::<LittleEndian>(jump_info.jump_horizontal_speed)?;
+        }
+
+        if movement_flags.contains(MovementFlags::SPLINE_ELEVATION) {
+            buffer.write_f32::<LittleEndian>(0.0)?;
+        }
+
+        Ok(())
+    }
 }
\ No newline at end of file
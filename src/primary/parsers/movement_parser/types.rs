@@ -3,6 +3,7 @@ use serde::ser::SerializeStruct;
 
 use crate::primary::client::{MovementFlags, MovementFlagsExtra};
 use crate::primary::parsers::position_parser::types::Position;
+use crate::primary::types::ObjectGuid;
 
 #[derive(Clone, Default, Debug)]
 pub struct MovementInfo {
@@ -12,17 +13,44 @@ pub struct MovementInfo {
     pub position: Position,
     pub fall_time: u32,
     pub jump_info: JumpInfo,
+    pub transport: Option<TransportInfo>,
 }
 
 impl<'de> Deserialize<'de> for MovementInfo {
-    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
-        todo!()
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        #[derive(Deserialize)]
+        struct RawMovementInfo {
+            movement_flags: u32,
+            movement_flags_extra: u16,
+            time: u32,
+            position: Position,
+            #[serde(default)]
+            fall_time: u32,
+            #[serde(default)]
+            jump_info: JumpInfo,
+            #[serde(default)]
+            transport: Option<TransportInfo>,
+        }
+
+        let RawMovementInfo {
+            movement_flags, movement_flags_extra, time, position, fall_time, jump_info, transport,
+        } = RawMovementInfo::deserialize(deserializer)?;
+
+        Ok(Self {
+            movement_flags: MovementFlags::from_bits_retain(movement_flags),
+            movement_flags_extra: MovementFlagsExtra::from_bits_retain(movement_flags_extra),
+            time,
+            position,
+            fall_time,
+            jump_info,
+            transport,
+        })
     }
 }
 
 impl Serialize for MovementInfo {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
-        const FIELDS_AMOUNT: usize = 6;
+        const FIELDS_AMOUNT: usize = 7;
         let mut state = serializer.serialize_struct("MovementInfo", FIELDS_AMOUNT)?;
         state.serialize_field("movement_flags", &self.movement_flags.bits())?;
         state.serialize_field("movement_flags_extra", &self.movement_flags_extra.bits())?;
@@ -30,6 +58,7 @@ impl Serialize for MovementInfo {
         state.serialize_field("position", &self.position)?;
         state.serialize_field("fall_time", &self.fall_time)?;
         state.serialize_field("jump_info", &self.jump_info)?;
+        state.serialize_field("transport", &self.transport)?;
         state.end()
     }
 }
@@ -43,8 +72,20 @@ pub struct JumpInfo {
 }
 
 impl<'de> Deserialize<'de> for JumpInfo {
-    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
-        todo!()
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        #[derive(Deserialize)]
+        struct RawJumpInfo {
+            jump_vertical_speed: f32,
+            jump_sin_angle: f32,
+            jump_cos_angle: f32,
+            jump_horizontal_speed: f32,
+        }
+
+        let RawJumpInfo {
+            jump_vertical_speed, jump_sin_angle, jump_cos_angle, jump_horizontal_speed,
+        } = RawJumpInfo::deserialize(deserializer)?;
+
+        Ok(Self { jump_vertical_speed, jump_sin_angle, jump_cos_angle, jump_horizontal_speed })
     }
 }
 
@@ -58,4 +99,61 @@ impl Serialize for JumpInfo {
         state.serialize_field("jump_horizontal_speed", &self.jump_horizontal_speed)?;
         state.end()
     }
+}
+
+// offset is relative to the transport (boat, zeppelin, elevator)
+#[derive(Clone, Copy, Default, Debug, Serialize, Deserialize)]
+pub struct TransportInfo {
+    pub guid: ObjectGuid,
+    pub offset: Position,
+    pub time: u32,
+    pub seat: u8,
+    #[serde(default)]
+    pub interpolated_time: Option<u32>,
+}
+
+impl TransportInfo {
+    pub fn world_position(&self, transport_position: &Position) -> Position {
+        self.offset.to_world(transport_position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primary::client::{MovementFlags, MovementFlagsExtra, Position};
+    use crate::primary::parsers::movement_parser::types::{JumpInfo, MovementInfo, TransportInfo};
+    use crate::primary::types::ObjectGuid;
+
+    #[test]
+    fn test_json_round_trip() {
+        let movement_info = MovementInfo {
+            movement_flags: MovementFlags::FORWARD | MovementFlags::TAXI,
+            movement_flags_extra: MovementFlagsExtra::from_bits_retain(0x20),
+            time: 1000,
+            position: Position::new(1.0, 2.0, 3.0, 0.5),
+            fall_time: 250,
+            jump_info: JumpInfo {
+                jump_vertical_speed: -7.9,
+                jump_sin_angle: 0.5,
+                jump_cos_angle: 0.8,
+                jump_horizontal_speed: 7.0,
+            },
+            transport: Some(TransportInfo {
+                guid: ObjectGuid::new(10),
+                offset: Position::new(2.0, 0.0, 1.0, 0.0),
+                ..TransportInfo::default()
+            }),
+        };
+
+        let json = serde_json::to_string(&movement_info).unwrap();
+        let restored: MovementInfo = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.movement_flags.bits(), movement_info.movement_flags.bits());
+        assert_eq!(restored.movement_flags_extra.bits(), 0x20);
+        assert_eq!(restored.fall_time, 250);
+        assert_eq!(restored.jump_info.jump_vertical_speed, -7.9);
+        assert_eq!(restored.jump_info.jump_horizontal_speed, 7.0);
+        assert_eq!(restored.transport.unwrap().guid, ObjectGuid::new(10));
+        assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+    }
 }
\ No newline at end of file
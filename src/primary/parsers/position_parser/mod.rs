@@ -1,5 +1,5 @@
 use std::io::{BufRead, Error};
-use byteorder::{LittleEndian, ReadBytesExt};
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
 pub mod types;
 
@@ -16,4 +16,13 @@ impl PositionParser {
 
         Ok(Position::new(x, y, z, orientation))
     }
+
+    pub fn write(buffer: &mut Vec<u8>, position: &Position) -> Result<(), Error> {
+        buffer.write_f32::<LittleEndian>(position.x)?;
+        buffer.write_f32::<LittleEndian>(position.y)?;
+        buffer.write_f32::<LittleEndian>(position.z)?;
+        buffer.write_f32::<LittleEndian>(position.orientation)?;
+
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -1,3 +1,4 @@
+use std::f32::consts::{PI, TAU};
 use std::fmt::{Debug, Formatter};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde::ser::SerializeStruct;
@@ -14,6 +15,47 @@ impl Position {
     pub fn new(x: f32, y: f32, z: f32, orientation: f32) -> Self {
         Self { x, y, z, orientation }
     }
+
+    pub fn distance(&self, other: &Position) -> f32 {
+        ((other.x - self.x).powi(2) + (other.y - self.y).powi(2) + (other.z - self.z).powi(2)).sqrt()
+    }
+
+    pub fn distance_2d(&self, other: &Position) -> f32 {
+        ((other.x - self.x).powi(2) + (other.y - self.y).powi(2)).sqrt()
+    }
+
+    // absolute angle from this position to other one, in range [0, 2π)
+    pub fn angle_to(&self, other: &Position) -> f32 {
+        Self::normalize_orientation((other.y - self.y).atan2(other.x - self.x))
+    }
+
+    // angle to other position relative to own orientation, in range [-π, π),
+    // positive values are to the left
+    pub fn bearing_to(&self, other: &Position) -> f32 {
+        let bearing = self.angle_to(other) - self.orientation;
+        (bearing + PI).rem_euclid(TAU) - PI
+    }
+
+    // checks if other position is inside the arc in front of this position
+    pub fn is_facing(&self, other: &Position, arc: f32) -> bool {
+        self.bearing_to(other).abs() <= arc / 2.0
+    }
+
+    pub fn normalize_orientation(orientation: f32) -> f32 {
+        orientation.rem_euclid(TAU)
+    }
+
+    // converts local offset (for example, on transport) into world coordinates of the origin
+    pub fn to_world(&self, origin: &Position) -> Position {
+        let (sin, cos) = origin.orientation.sin_cos();
+
+        Position::new(
+            origin.x + self.x * cos - self.y * sin,
+            origin.y + self.x * sin + self.y * cos,
+            origin.z + self.z,
+            Self::normalize_orientation(origin.orientation + self.orientation),
+        )
+    }
 }
 
 impl Debug for Position {
@@ -30,8 +72,18 @@ impl Debug for Position {
 }
 
 impl<'de> Deserialize<'de> for Position {
-    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
-        todo!()
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        #[derive(Deserialize)]
+        struct RawPosition {
+            x: f32,
+            y: f32,
+            z: f32,
+            #[serde(default)]
+            orientation: f32,
+        }
+
+        let RawPosition { x, y, z, orientation } = RawPosition::deserialize(deserializer)?;
+        Ok(Self { x, y, z, orientation })
     }
 }
 
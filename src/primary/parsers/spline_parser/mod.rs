@@ -0,0 +1,317 @@
+use std::io::{BufRead, Error};
+use std::time::Instant;
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+
+pub mod types;
+
+use crate::primary::client::SplineFlags;
+use crate::primary::parsers::position_parser::types::Position;
+use crate::primary::parsers::spline_parser::types::{MonsterMove, MonsterMoveType, Spline, SplineFacing};
+use crate::primary::types::ObjectGuid;
+
+pub struct SplineParser;
+
+impl SplineParser {
+    // spline part of the movement block from SMSG_UPDATE_OBJECT
+    pub fn parse<R: BufRead>(reader: &mut R) -> Result<Spline, Error> {
+        let flags = SplineFlags::from_bits_retain(reader.read_u32::<LittleEndian>()?);
+
+        let facing = if flags.contains(SplineFlags::FINAL_ANGLE) {
+            SplineFacing::Angle(reader.read_f32::<LittleEndian>()?)
+        } else if flags.contains(SplineFlags::FINAL_TARGET) {
+            SplineFacing::Target(ObjectGuid::new(reader.read_u64::<LittleEndian>()?))
+        } else if flags.contains(SplineFlags::FINAL_POINT) {
+            SplineFacing::Point(Self::read_vector(reader)?)
+        } else {
+            SplineFacing::None
+        };
+
+        let time_passed = reader.read_u32::<LittleEndian>()?;
+        let duration = reader.read_u32::<LittleEndian>()?;
+        let id = reader.read_u32::<LittleEndian>()?;
+
+        // duration modifiers
+        let _ = reader.read_f32::<LittleEndian>()?;
+        let _ = reader.read_f32::<LittleEndian>()?;
+
+        let vertical_acceleration = reader.read_f32::<LittleEndian>()?;
+        let effect_start_time = reader.read_u32::<LittleEndian>()?;
+
+        let points_amount = reader.read_u32::<LittleEndian>()?;
+        let mut points = Vec::new();
+        for _ in 0..points_amount {
+            points.push(Self::read_vector(reader)?);
+        }
+
+        let _evaluation_mode = reader.read_u8()?;
+        let end_point = Self::read_vector(reader)?;
+        if points.is_empty() {
+            points.push(end_point);
+        }
+
+        Ok(Spline {
+            id,
+            flags,
+            facing,
+            time_passed,
+            duration,
+            vertical_acceleration,
+            effect_start_time,
+            points,
+            received_at: Instant::now(),
+        })
+    }
+
+    pub fn write(buffer: &mut Vec<u8>, spline: &Spline) -> Result<(), Error> {
+        let mut flags = spline.flags - (SplineFlags::FINAL_ANGLE | SplineFlags::FINAL_TARGET | SplineFlags::FINAL_POINT);
+        match spline.facing {
+            SplineFacing::Angle(_) => flags |= SplineFlags::FINAL_ANGLE,
+            SplineFacing::Target(_) => flags |= SplineFlags::FINAL_TARGET,
+            SplineFacing::Point(_) => flags |= SplineFlags::FINAL_POINT,
+            SplineFacing::None => {},
+        }
+
+        buffer.write_u32::<LittleEndian>(flags.bits())?;
+        Self::write_facing(buffer, &spline.facing)?;
+
+        buffer.write_u32::<LittleEndian>(spline.time_passed)?;
+        buffer.write_u32::<LittleEndian>(spline.duration)?;
+        buffer.write_u32::<LittleEndian>(spline.id)?;
+        buffer.write_f32::<LittleEndian>(1.0)?;
+        buffer.write_f32::<LittleEndian>(1.0)?;
+        buffer.write_f32::<LittleEndian>(spline.vertical_acceleration)?;
+        buffer.write_u32::<LittleEndian>(spline.effect_start_time)?;
+
+        buffer.write_u32::<LittleEndian>(spline.points.len() as u32)?;
+        for point in spline.points.iter() {
+            Self::write_vector(buffer, point)?;
+        }
+
+        buffer.write_u8(spline.flags.contains(SplineFlags::CATMULLROM) as u8)?;
+        Self::write_vector(buffer, &spline.points.last().copied().unwrap_or_default())?;
+
+        Ok(())
+    }
+
+    pub fn parse_monster_move<R: BufRead>(reader: &mut R) -> Result<MonsterMove, Error> {
+        let _unknown = reader.read_u8()?;
+        let start = Self::read_vector(reader)?;
+        let spline_id = reader.read_u32::<LittleEndian>()?;
+        let move_type = reader.read_u8()?;
+
+        let facing = match move_type {
+            MonsterMoveType::FACING_SPOT => SplineFacing::Point(Self::read_vector(reader)?),
+            MonsterMoveType::FACING_TARGET => SplineFacing::Target(ObjectGuid::new(reader.read_u64::<LittleEndian>()?)),
+            MonsterMoveType::FACING_ANGLE => SplineFacing::Angle(reader.read_f32::<LittleEndian>()?),
+            _ => SplineFacing::None,
+        };
+
+        if move_type == MonsterMoveType::STOP {
+            return Ok(MonsterMove { start, spline_id, move_type, spline: None });
+        }
+
+        let flags = SplineFlags::from_bits_retain(reader.read_u32::<LittleEndian>()?);
+
+        let mut effect_start_time = 0;
+        if flags.contains(SplineFlags::ANIMATION) {
+            let _animation_type = reader.read_u8()?;
+            effect_start_time = reader.read_u32::<LittleEndian>()?;
+        }
+
+        let duration = reader.read_u32::<LittleEndian>()?;
+
+        let mut vertical_acceleration = 0.0;
+        if flags.contains(SplineFlags::PARABOLIC) {
+            vertical_acceleration = reader.read_f32::<LittleEndian>()?;
+            effect_start_time = reader.read_u32::<LittleEndian>()?;
+        }
+
+        let points_amount = reader.read_u32::<LittleEndian>()?;
+        let mut points = vec![start];
+
+        if flags.intersects(SplineFlags::FLYING | SplineFlags::CATMULLROM) {
+            for _ in 0..points_amount {
+                points.push(Self::read_vector(reader)?);
+            }
+        } else if points_amount > 0 {
+            let destination = Self::read_vector(reader)?;
+
+            // intermediate points are packed as offsets from the middle of the path
+            let middle = Self::get_middle(&start, &destination);
+            for _ in 1..points_amount {
+                let offset = Self::unpack_offset(reader.read_u32::<LittleEndian>()?);
+                points.push(Position::new(
+                    middle.x - offset.x,
+                    middle.y - offset.y,
+                    middle.z - offset.z,
+                    0.0,
+                ));
+            }
+
+            points.push(destination);
+        }
+
+        let spline = Spline {
+            id: spline_id,
+            flags,
+            facing,
+            time_passed: 0,
+            duration,
+            vertical_acceleration,
+            effect_start_time,
+            points,
+            received_at: Instant::now(),
+        };
+
+        Ok(MonsterMove { start, spline_id, move_type, spline: Some(spline) })
+    }
+
+    pub fn write_monster_move(buffer: &mut Vec<u8>, monster_move: &MonsterMove) -> Result<(), Error> {
+        buffer.write_u8(0)?;
+        Self::write_vector(buffer, &monster_move.start)?;
+        buffer.write_u32::<LittleEndian>(monster_move.spline_id)?;
+
+        let Some(spline) = &monster_move.spline else {
+            buffer.write_u8(MonsterMoveType::STOP)?;
+            return Ok(());
+        };
+
+        let move_type = match spline.facing {
+            SplineFacing::Point(_) => MonsterMoveType::FACING_SPOT,
+            SplineFacing::Target(_) => MonsterMoveType::FACING_TARGET,
+            SplineFacing::Angle(_) => MonsterMoveType::FACING_ANGLE,
+            SplineFacing::None => MonsterMoveType::NORMAL,
+        };
+        buffer.write_u8(move_type)?;
+        Self::write_facing(buffer, &spline.facing)?;
+
+        buffer.write_u32::<LittleEndian>(spline.flags.bits())?;
+
+        if spline.flags.contains(SplineFlags::ANIMATION) {
+            buffer.write_u8(0)?;
+            buffer.write_u32::<LittleEndian>(spline.effect_start_time)?;
+        }
+
+        buffer.write_u32::<LittleEndian>(spline.duration)?;
+
+        if spline.flags.contains(SplineFlags::PARABOLIC) {
+            buffer.write_f32::<LittleEndian>(spline.vertical_acceleration)?;
+            buffer.write_u32::<LittleEndian>(spline.effect_start_time)?;
+        }
+
+        // first point is the start position which is sent separately
+        let path = spline.points.get(1..).unwrap_or_default();
+        buffer.write_u32::<LittleEndian>(path.len() as u32)?;
+
+        if spline.flags.intersects(SplineFlags::FLYING | SplineFlags::CATMULLROM) {
+            for point in path {
+                Self::write_vector(buffer, point)?;
+            }
+        } else if let Some((destination, intermediate)) = path.split_last() {
+            Self::write_vector(buffer, destination)?;
+
+            let middle = Self::get_middle(&monster_move.start, destination);
+            for point in intermediate {
+                buffer.write_u32::<LittleEndian>(Self::pack_offset(&Position::new(
+                    middle.x - point.x,
+                    middle.y - point.y,
+                    middle.z - point.z,
+                    0.0,
+                )))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_facing(buffer: &mut Vec<u8>, facing: &SplineFacing) -> Result<(), Error> {
+        match facing {
+            SplineFacing::Angle(angle) => buffer.write_f32::<LittleEndian>(*angle)?,
+            SplineFacing::Target(guid) => buffer.write_u64::<LittleEndian>(guid.raw())?,
+            SplineFacing::Point(point) => Self::write_vector(buffer, point)?,
+            SplineFacing::None => {},
+        }
+
+        Ok(())
+    }
+
+    fn read_vector<R: BufRead>(reader: &mut R) -> Result<Position, Error> {
+        let x = reader.read_f32::<LittleEndian>()?;
+        let y = reader.read_f32::<LittleEndian>()?;
+        let z = reader.read_f32::<LittleEndian>()?;
+
+        Ok(Position::new(x, y, z, 0.0))
+    }
+
+    fn write_vector(buffer: &mut Vec<u8>, position: &Position) -> Result<(), Error> {
+        buffer.write_f32::<LittleEndian>(position.x)?;
+        buffer.write_f32::<LittleEndian>(position.y)?;
+        buffer.write_f32::<LittleEndian>(position.z)?;
+
+        Ok(())
+    }
+
+    fn get_middle(start: &Position, destination: &Position) -> Position {
+        Position::new(
+            (start.x + destination.x) / 2.0,
+            (start.y + destination.y) / 2.0,
+            (start.z + destination.z) / 2.0,
+            0.0,
+        )
+    }
+
+    // x and y take 11 signed bits, z takes 10 signed bits, each in 0.25 units
+    fn unpack_offset(packed: u32) -> Position {
+        let x = ((packed << 21) as i32 >> 21) as f32 * 0.25;
+        let y = ((packed << 10) as i32 >> 21) as f32 * 0.25;
+        let z = (packed as i32 >> 22) as f32 * 0.25;
+
+        Position::new(x, y, z, 0.0)
+    }
+
+    fn pack_offset(offset: &Position) -> u32 {
+        let x = ((offset.x / 0.25) as i32 as u32) & 0x7FF;
+        let y = ((offset.y / 0.25) as i32 as u32) & 0x7FF;
+        let z = ((offset.z / 0.25) as i32 as u32) & 0x3FF;
+
+        x | (y << 11) | (z << 22)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primary::client::SplineFlags;
+    use crate::primary::parsers::position_parser::types::Position;
+    use crate::primary::parsers::spline_parser::SplineParser;
+    use crate::primary::parsers::spline_parser::types::{MonsterMove, MonsterMoveType, Spline, SplineFacing};
+    use crate::primary::types::ObjectGuid;
+
+    #[test]
+    fn test_monster_move_round_trip() {
+        let mut spline = Spline::new(7, SplineFlags::WALKMODE, 3000, vec![
+            Position::new(10.0, 10.0, 5.0, 0.0),
+            Position::new(12.5, 8.0, 5.25, 0.0),
+            Position::new(20.0, 10.0, 6.0, 0.0),
+        ]);
+        spline.facing = SplineFacing::Target(ObjectGuid::new(42));
+
+        let monster_move = MonsterMove {
+            start: Position::new(10.0, 10.0, 5.0, 0.0),
+            spline_id: 7,
+            move_type: MonsterMoveType::FACING_TARGET,
+            spline: Some(spline),
+        };
+
+        let mut buffer = Vec::new();
+        SplineParser::write_monster_move(&mut buffer, &monster_move).unwrap();
+        let parsed = SplineParser::parse_monster_move(&mut buffer.as_slice()).unwrap();
+
+        assert_eq!(parsed.move_type, MonsterMoveType::FACING_TARGET);
+        let spline = parsed.spline.unwrap();
+        assert_eq!(spline.duration, 3000);
+        assert!(matches!(spline.facing, SplineFacing::Target(guid) if guid.raw() == 42));
+
+        let points: Vec<(f32, f32, f32)> = spline.points.iter().map(|p| (p.x, p.y, p.z)).collect();
+        assert_eq!(points, vec![(10.0, 10.0, 5.0), (12.5, 8.0, 5.25), (20.0, 10.0, 6.0)]);
+    }
+}
\ No newline at end of file
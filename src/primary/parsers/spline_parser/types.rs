@@ -0,0 +1,227 @@
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::ser::SerializeStruct;
+
+use crate::primary::client::SplineFlags;
+use crate::primary::parsers::position_parser::types::Position;
+use crate::primary::types::ObjectGuid;
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub enum SplineFacing {
+    #[default]
+    None,
+    Point(Position),
+    Target(ObjectGuid),
+    Angle(f32),
+}
+
+#[non_exhaustive]
+pub struct MonsterMoveType;
+
+impl MonsterMoveType {
+    pub const NORMAL: u8 = 0;
+    pub const STOP: u8 = 1;
+    pub const FACING_SPOT: u8 = 2;
+    pub const FACING_TARGET: u8 = 3;
+    pub const FACING_ANGLE: u8 = 4;
+}
+
+// first point is the start position
+#[derive(Clone, Debug)]
+pub struct Spline {
+    pub id: u32,
+    pub flags: SplineFlags,
+    pub facing: SplineFacing,
+    // milliseconds already passed at the moment of receiving
+    pub time_passed: u32,
+    pub duration: u32,
+    pub vertical_acceleration: f32,
+    pub effect_start_time: u32,
+    pub points: Vec<Position>,
+    pub received_at: Instant,
+}
+
+impl Spline {
+    pub fn new(id: u32, flags: SplineFlags, duration: u32, points: Vec<Position>) -> Self {
+        Self {
+            id,
+            flags,
+            facing: SplineFacing::None,
+            time_passed: 0,
+            duration,
+            vertical_acceleration: 0.0,
+            effect_start_time: 0,
+            points,
+            received_at: Instant::now(),
+        }
+    }
+
+    pub fn start_time(&self) -> Instant {
+        let time_passed = Duration::from_millis(self.time_passed as u64);
+        self.received_at.checked_sub(time_passed).unwrap_or(self.received_at)
+    }
+
+    pub fn end_time(&self) -> Instant {
+        self.start_time() + Duration::from_millis(self.duration as u64)
+    }
+
+    pub fn is_finished(&self, now: Instant) -> bool {
+        !self.flags.contains(SplineFlags::CYCLIC) && now >= self.end_time()
+    }
+
+    pub fn destination(&self) -> Option<Position> {
+        let mut destination = *self.points.last()?;
+        destination.orientation = self.get_final_orientation()?;
+
+        Some(destination)
+    }
+
+    // points are passed with constant speed, so position depends on the distance along the path
+    pub fn position_at(&self, now: Instant) -> Option<Position> {
+        let elapsed = now.saturating_duration_since(self.start_time()).as_millis() as u64;
+
+        let progress = if self.duration == 0 {
+            1.0
+        } else if self.flags.contains(SplineFlags::CYCLIC) {
+            (elapsed % self.duration as u64) as f32 / self.duration as f32
+        } else {
+            (elapsed as f32 / self.duration as f32).min(1.0)
+        };
+
+        if progress >= 1.0 {
+            return self.destination();
+        }
+
+        let lengths: Vec<f32> = self.points.windows(2)
+            .map(|segment| segment[0].distance(&segment[1]))
+            .collect();
+        let mut distance = lengths.iter().sum::<f32>() * progress;
+
+        for (index, length) in lengths.iter().enumerate() {
+            if distance <= *length && *length > 0.0 {
+                let (from, to) = (&self.points[index], &self.points[index + 1]);
+                let ratio = distance / length;
+
+                return Some(Position::new(
+                    from.x + (to.x - from.x) * ratio,
+                    from.y + (to.y - from.y) * ratio,
+                    from.z + (to.z - from.z) * ratio,
+                    from.angle_to(to),
+                ));
+            }
+
+            distance -= length;
+        }
+
+        self.destination()
+    }
+
+    fn get_final_orientation(&self) -> Option<f32> {
+        let destination = self.points.last()?;
+
+        let orientation = match self.facing {
+            SplineFacing::Angle(angle) => angle,
+            SplineFacing::Point(point) => destination.angle_to(&point),
+            _ => match self.points.len() {
+                0 | 1 => destination.orientation,
+                len => self.points[len - 2].angle_to(destination),
+            },
+        };
+
+        Some(orientation)
+    }
+}
+
+impl<'de> Deserialize<'de> for Spline {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        #[derive(Deserialize)]
+        struct RawSpline {
+            id: u32,
+            flags: u32,
+            #[serde(default)]
+            facing: SplineFacing,
+            #[serde(default)]
+            time_passed: u32,
+            duration: u32,
+            #[serde(default)]
+            vertical_acceleration: f32,
+            #[serde(default)]
+            effect_start_time: u32,
+            points: Vec<Position>,
+        }
+
+        let RawSpline {
+            id, flags, facing, time_passed, duration, vertical_acceleration, effect_start_time, points,
+        } = RawSpline::deserialize(deserializer)?;
+
+        Ok(Self {
+            id,
+            flags: SplineFlags::from_bits_retain(flags),
+            facing,
+            time_passed,
+            duration,
+            vertical_acceleration,
+            effect_start_time,
+            points,
+            received_at: Instant::now(),
+        })
+    }
+}
+
+impl Serialize for Spline {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        const FIELDS_AMOUNT: usize = 8;
+        let mut state = serializer.serialize_struct("Spline", FIELDS_AMOUNT)?;
+        state.serialize_field("id", &self.id)?;
+        state.serialize_field("flags", &self.flags.bits())?;
+        state.serialize_field("facing", &self.facing)?;
+        state.serialize_field("time_passed", &self.time_passed)?;
+        state.serialize_field("duration", &self.duration)?;
+        state.serialize_field("vertical_acceleration", &self.vertical_acceleration)?;
+        state.serialize_field("effect_start_time", &self.effect_start_time)?;
+        state.serialize_field("points", &self.points)?;
+        state.end()
+    }
+}
+
+// spline is None when the object stops
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MonsterMove {
+    pub start: Position,
+    pub spline_id: u32,
+    pub move_type: u8,
+    pub spline: Option<Spline>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::primary::client::SplineFlags;
+    use crate::primary::parsers::position_parser::types::Position;
+    use crate::primary::parsers::spline_parser::types::{Spline, SplineFacing};
+
+    #[test]
+    fn test_position_at() {
+        let now = Instant::now();
+        let mut spline = Spline::new(1, SplineFlags::NONE, 2000, vec![
+            Position::new(0.0, 0.0, 0.0, 0.0),
+            Position::new(10.0, 0.0, 0.0, 0.0),
+            Position::new(10.0, 10.0, 0.0, 0.0),
+        ]);
+        spline.received_at = now;
+        spline.time_passed = 500;
+        spline.facing = SplineFacing::Angle(1.0);
+
+        let position = spline.position_at(now).unwrap();
+        assert_eq!((position.x, position.y), (5.0, 0.0));
+
+        let position = spline.position_at(now + Duration::from_millis(1000)).unwrap();
+        assert_eq!((position.x, position.y), (10.0, 5.0));
+        assert!((position.orientation - std::f32::consts::FRAC_PI_2).abs() < f32::EPSILON);
+
+        let position = spline.position_at(now + Duration::from_secs(10)).unwrap();
+        assert_eq!((position.x, position.y, position.orientation), (10.0, 10.0, 1.0));
+        assert!(spline.is_finished(now + Duration::from_millis(1500)));
+    }
+}
\ No newline at end of file
@@ -1,15 +1,23 @@
 use std::collections::{BTreeMap};
 use std::io::{BufRead, Error, ErrorKind};
-use byteorder::{LittleEndian, ReadBytesExt};
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
 pub mod types;
 
-use crate::primary::client::{FieldType, FieldValue, MovementFlags, ObjectField, PlayerField, SplineFlags, UnitField, UnitMoveType};
+use crate::primary::client::{MovementFlags, ObjectField, SplineFlags, UnitMoveType};
 use crate::primary::parsers::movement_parser::MovementParser;
+use crate::primary::parsers::movement_parser::types::TransportInfo;
 use crate::primary::parsers::position_parser::PositionParser;
-use crate::primary::parsers::update_block_parser::types::{MovementData, ObjectUpdateFlags, ObjectUpdateType, ParsedBlock, UpdateFields};
+use crate::primary::parsers::position_parser::types::Position;
+use crate::primary::parsers::spline_parser::SplineParser;
+use crate::primary::parsers::spline_parser::types::Spline;
+use crate::primary::parsers::update_block_parser::types::{
+    MovementData, ObjectTypeID, ObjectTypeMask, ObjectUpdateFlags, ObjectUpdateType, ParsedBlock,
+    RawFields,
+};
 
-use crate::primary::utils::read_packed_guid;
+use crate::primary::types::ObjectGuid;
+use crate::primary::utils::{read_packed_guid, write_packed_guid};
 
 const MOVE_TYPES: [u8; 9] = [
     UnitMoveType::MOVE_WALK,
@@ -55,13 +63,13 @@ impl UpdateBlocksParser {
 
         match block_type {
             ObjectUpdateType::VALUES => {
-                let guid = read_packed_guid(reader);
+                let guid = ObjectGuid::new(read_packed_guid(reader));
 
                 parsed_block.guid = Some(guid);
 
                 match Self::parse_updated_values(reader) {
-                    Ok(update_fields) => {
-                        parsed_block.update_fields = update_fields;
+                    Ok(raw_fields) => {
+                        parsed_block.raw_fields = raw_fields;
                     },
                     Err(err) => {
                         return Err(err);
@@ -69,7 +77,7 @@ impl UpdateBlocksParser {
                 }
             }
             ObjectUpdateType::MOVEMENT => {
-                let guid = read_packed_guid(reader);
+                let guid = ObjectGuid::new(read_packed_guid(reader));
 
                 parsed_block.guid = Some(guid);
 
@@ -84,7 +92,7 @@ impl UpdateBlocksParser {
             }
             ObjectUpdateType::CREATE_OBJECT |
             ObjectUpdateType::CREATE_OBJECT2 => {
-                let guid = read_packed_guid(reader);
+                let guid = ObjectGuid::new(read_packed_guid(reader));
 
                 parsed_block.guid = Some(guid);
 
@@ -100,8 +108,8 @@ impl UpdateBlocksParser {
                 }
 
                 match Self::parse_updated_values(reader) {
-                    Ok(update_fields) => {
-                        parsed_block.update_fields = update_fields;
+                    Ok(raw_fields) => {
+                        parsed_block.raw_fields = raw_fields;
                     },
                     Err(err) => {
                         return Err(err);
@@ -110,9 +118,9 @@ impl UpdateBlocksParser {
             }
             ObjectUpdateType::OUT_OF_RANGE_OBJECTS => {
                 let guid_amount = reader.read_u32::<LittleEndian>()?;
-                let mut guids: Vec<u64> = Vec::new();
+                let mut guids: Vec<ObjectGuid> = Vec::new();
                 for _ in 0..guid_amount {
-                    let guid = read_packed_guid(reader);
+                    let guid = ObjectGuid::new(read_packed_guid(reader));
                     guids.push(guid);
                 }
 
@@ -120,9 +128,9 @@ impl UpdateBlocksParser {
             }
             ObjectUpdateType::NEAR_OBJECTS => {
                 let guid_amount = reader.read_u32::<LittleEndian>()?;
-                let mut guids: Vec<u64> = Vec::new();
+                let mut guids: Vec<ObjectGuid> = Vec::new();
                 for _ in 0..guid_amount {
-                    let guid = read_packed_guid(reader);
+                    let guid = ObjectGuid::new(read_packed_guid(reader));
                     guids.push(guid);
                 }
 
@@ -138,10 +146,9 @@ impl UpdateBlocksParser {
 
     fn parse_updated_values<R: BufRead>(
         reader: &mut R
-    ) -> Result<UpdateFields, Error> {
+    ) -> Result<RawFields, Error> {
         let blocks_amount = reader.read_u8()?;
-        let mut update_blocks: BTreeMap<u32, u32> = BTreeMap::new();
-        let mut update_fields: UpdateFields = BTreeMap::new();
+        let mut raw_fields: RawFields = BTreeMap::new();
 
         if blocks_amount > 0 {
             let mut update_mask = vec![0i32; blocks_amount as usize];
@@ -156,53 +163,14 @@ impl UpdateBlocksParser {
 
                 for _ in 0..32 {
                     if bitmask & 1 != 0 {
-                        update_blocks.insert(index, reader.read_u32::<LittleEndian>()?);
+                        raw_fields.insert(index, reader.read_u32::<LittleEndian>()?);
                     }
                     bitmask >>= 1;
                     index += 1;
                 }
             }
 
-            for (k, v) in update_blocks.clone().into_iter() {
-                let field_type = if k < ObjectField::LIMIT {
-                    ObjectField::get_field_type(k)
-                } else if k < UnitField::LIMIT {
-                    UnitField::get_field_type(k)
-                } else {
-                    PlayerField::get_field_type(k)
-                };
-
-                let value = match field_type {
-                    FieldType::Integer => {
-                        Some(FieldValue::Integer(v))
-                    },
-                    FieldType::Bytes => {
-                        Some(FieldValue::Bytes(v))
-                    },
-                    FieldType::Long => {
-                        if let Some(next_v) = update_blocks.get(&(k + 1)) {
-                            Some(FieldValue::Long((u64::from(*next_v) << 32) | u64::from(v)))
-                        } else {
-                            Some(FieldValue::Long(u64::from(v)))
-                        }
-                    },
-                    FieldType::Float => {
-                        Some(FieldValue::Float(f32::from_bits(v)))
-                    },
-                    FieldType::TwoShorts => {
-                        let first: u16 = (v & 0xFFFF) as u16;
-                        let second: u16 = ((v >> 16) & 0xFFFF) as u16;
-                        Some(FieldValue::TwoShorts(first, second))
-                    },
-                    FieldType::None => None,
-                };
-
-                if let Some(value) = value {
-                    update_fields.insert(k, value);
-                }
-            }
-
-            return Ok(update_fields);
+            return Ok(raw_fields);
         }
 
         Err(Error::new(ErrorKind::Other, "No blocks for update. Just ignore."))
@@ -230,63 +198,36 @@ impl UpdateBlocksParser {
             movement_data.movement_speed = movement_speed;
 
             if movement_info.movement_flags.contains(MovementFlags::SPLINE_ENABLED) {
-                let spline_flags = SplineFlags::from_bits(
-                    reader.read_u32::<LittleEndian>()?
-                ).unwrap_or(SplineFlags::NONE);
-
-                if spline_flags.contains(SplineFlags::FINAL_ANGLE) {
-                    let _spline_facing_angle = reader.read_f32::<LittleEndian>()?;
-                }
-
-                if spline_flags.contains(SplineFlags::FINAL_TARGET) {
-                    let _spline_facing_target_guid = reader.read_u64::<LittleEndian>()?;
-                }
-
-                if spline_flags.contains(SplineFlags::FINAL_POINT) {
-                    let _spline_facing_point_x = reader.read_f32::<LittleEndian>()?;
-                    let _spline_facing_point_y = reader.read_f32::<LittleEndian>()?;
-                    let _spline_facing_point_z = reader.read_f32::<LittleEndian>()?;
-                }
-
-                let _ = reader.read_u32::<LittleEndian>()?;
-                let _ = reader.read_u32::<LittleEndian>()?;
-                let _ = reader.read_u32::<LittleEndian>()?;
-
-                let _ = reader.read_u32::<LittleEndian>()?;
-                let _ = reader.read_u32::<LittleEndian>()?;
-                let _ = reader.read_u32::<LittleEndian>()?;
-                let _ = reader.read_u32::<LittleEndian>()?;
-
-                let spline_amount = reader.read_u32::<LittleEndian>()?;
-
-                for _ in 0..spline_amount {
-                    let _spline_point_x = reader.read_f32::<LittleEndian>()?;
-                    let _spline_point_y = reader.read_f32::<LittleEndian>()?;
-                    let _spline_point_z = reader.read_f32::<LittleEndian>()?;
-                }
-
-                let _spline_evaluation_mode = reader.read_u8()?;
-                let _spline_end_point_x = reader.read_f32::<LittleEndian>()?;
-                let _spline_end_point_y = reader.read_f32::<LittleEndian>()?;
-                let _spline_end_point_z = reader.read_f32::<LittleEndian>()?;
+                movement_data.spline = Some(SplineParser::parse(reader)?);
             }
 
             movement_data.movement_info = Some(movement_info);
 
         } else {
             if object_update_flags.contains(ObjectUpdateFlags::POSITION) {
-                let _transport_guid = read_packed_guid(reader);
+                let transport_guid = ObjectGuid::new(read_packed_guid(reader));
+
+                let x = reader.read_f32::<LittleEndian>()?;
+                let y = reader.read_f32::<LittleEndian>()?;
+                let z = reader.read_f32::<LittleEndian>()?;
 
-                let _ = reader.read_f32::<LittleEndian>()?;
-                let _ = reader.read_f32::<LittleEndian>()?;
-                let _ = reader.read_f32::<LittleEndian>()?;
+                // orientation of the object is inside this block,
+                // trailing float is corpse orientation and is 0 for everything else
+                let offset = PositionParser::parse(reader)?;
+                let _corpse_orientation = reader.read_f32::<LittleEndian>()?;
 
-                // transport offset x, y, z, orientation
-                let _position = PositionParser::parse(reader)?;
-                let _ = reader.read_f32::<LittleEndian>()?;
+                movement_data.position = Some(Position::new(x, y, z, offset.orientation));
+
+                if !transport_guid.is_empty() {
+                    movement_data.transport = Some(TransportInfo {
+                        guid: transport_guid,
+                        offset,
+                        ..TransportInfo::default()
+                    });
+                }
             }
             if object_update_flags.contains(ObjectUpdateFlags::STATIONARY_POSITION) {
-                let _position = PositionParser::parse(reader)?;
+                movement_data.position = Some(PositionParser::parse(reader)?);
             }
         }
 
@@ -299,7 +240,7 @@ impl UpdateBlocksParser {
         }
 
         if object_update_flags.contains(ObjectUpdateFlags::HAS_TARGET) {
-            let target_guid = read_packed_guid(reader);
+            let target_guid = ObjectGuid::new(read_packed_guid(reader));
             movement_data.target_guid = Some(target_guid);
         }
 
@@ -318,16 +259,186 @@ impl UpdateBlocksParser {
 
         Ok(movement_data)
     }
+
+    // block type is detected from the filled data: guid lists, create (movement + values),
+    // movement or values
+    pub fn write(buffer: &mut Vec<u8>, parsed_blocks: &[ParsedBlock]) -> Result<(), Error> {
+        buffer.write_u32::<LittleEndian>(parsed_blocks.len() as u32)?;
+
+        for parsed_block in parsed_blocks {
+            Self::write_block(buffer, parsed_block)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_block(buffer: &mut Vec<u8>, parsed_block: &ParsedBlock) -> Result<(), Error> {
+        if !parsed_block.out_of_range_guids.is_empty() {
+            buffer.write_u8(ObjectUpdateType::OUT_OF_RANGE_OBJECTS)?;
+            return Self::write_guids(buffer, &parsed_block.out_of_range_guids);
+        }
+
+        if !parsed_block.near_object_guids.is_empty() {
+            buffer.write_u8(ObjectUpdateType::NEAR_OBJECTS)?;
+            return Self::write_guids(buffer, &parsed_block.near_object_guids);
+        }
+
+        let guid = parsed_block.guid.ok_or_else(|| {
+            Error::new(ErrorKind::InvalidInput, "Block without guid cannot be written")
+        })?;
+
+        match (&parsed_block.movement_data, parsed_block.raw_fields.is_empty()) {
+            (Some(movement_data), false) => {
+                buffer.write_u8(ObjectUpdateType::CREATE_OBJECT)?;
+                write_packed_guid(buffer, guid.raw())?;
+                buffer.write_u8(Self::get_object_type_id(&parsed_block.raw_fields))?;
+                Self::write_movement_data(buffer, movement_data)?;
+                Self::write_updated_values(buffer, &parsed_block.raw_fields)?;
+            },
+            (Some(movement_data), true) => {
+                buffer.write_u8(ObjectUpdateType::MOVEMENT)?;
+                write_packed_guid(buffer, guid.raw())?;
+                Self::write_movement_data(buffer, movement_data)?;
+            },
+            (None, false) => {
+                buffer.write_u8(ObjectUpdateType::VALUES)?;
+                write_packed_guid(buffer, guid.raw())?;
+                Self::write_updated_values(buffer, &parsed_block.raw_fields)?;
+            },
+            (None, true) => {
+                return Err(Error::new(ErrorKind::InvalidInput, "Block has nothing to write"));
+            },
+        }
+
+        Ok(())
+    }
+
+    fn write_guids(buffer: &mut Vec<u8>, guids: &[ObjectGuid]) -> Result<(), Error> {
+        buffer.write_u32::<LittleEndian>(guids.len() as u32)?;
+        for guid in guids {
+            write_packed_guid(buffer, guid.raw())?;
+        }
+
+        Ok(())
+    }
+
+    fn get_object_type_id(raw_fields: &RawFields) -> u8 {
+        let type_mask = raw_fields.get(&ObjectField::TYPE).copied().unwrap_or(ObjectTypeMask::TYPEMASK_OBJECT);
+
+        [
+            (ObjectTypeMask::TYPEMASK_PLAYER, ObjectTypeID::TYPEID_PLAYER),
+            (ObjectTypeMask::TYPEMASK_UNIT, ObjectTypeID::TYPEID_UNIT),
+            (ObjectTypeMask::TYPEMASK_CONTAINER, ObjectTypeID::TYPEID_CONTAINER),
+            (ObjectTypeMask::TYPEMASK_ITEM, ObjectTypeID::TYPEID_ITEM),
+            (ObjectTypeMask::TYPEMASK_GAMEOBJECT, ObjectTypeID::TYPEID_GAMEOBJECT),
+            (ObjectTypeMask::TYPEMASK_DYNAMICOBJECT, ObjectTypeID::TYPEID_DYNAMICOBJECT),
+            (ObjectTypeMask::TYPEMASK_CORPSE, ObjectTypeID::TYPEID_CORPSE),
+        ]
+            .into_iter()
+            .find(|(mask, _)| type_mask & mask != 0)
+            .map(|(_, type_id)| type_id)
+            .unwrap_or(ObjectTypeID::TYPEID_OBJECT)
+    }
+
+    fn write_updated_values(buffer: &mut Vec<u8>, raw_fields: &RawFields) -> Result<(), Error> {
+        let max_index = raw_fields.keys().next_back().copied().unwrap_or(0);
+        let blocks_amount = max_index / 32 + 1;
+
+        let mut update_mask = vec![0u32; blocks_amount as usize];
+        for k in raw_fields.keys() {
+            update_mask[(k / 32) as usize] |= 1 << (k % 32);
+        }
+
+        buffer.write_u8(blocks_amount as u8)?;
+        for mask in update_mask {
+            buffer.write_u32::<LittleEndian>(mask)?;
+        }
+
+        for value in raw_fields.values() {
+            buffer.write_u32::<LittleEndian>(*value)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_movement_data(buffer: &mut Vec<u8>, movement_data: &MovementData) -> Result<(), Error> {
+        let mut object_update_flags = ObjectUpdateFlags::NONE;
+        object_update_flags.set(ObjectUpdateFlags::LIVING, movement_data.movement_info.is_some());
+        let has_position = movement_data.movement_info.is_none() && movement_data.position.is_some();
+        object_update_flags.set(
+            ObjectUpdateFlags::POSITION,
+            has_position && movement_data.transport.is_some(),
+        );
+        object_update_flags.set(
+            ObjectUpdateFlags::STATIONARY_POSITION,
+            has_position && movement_data.transport.is_none(),
+        );
+        object_update_flags.set(ObjectUpdateFlags::LOWGUID, movement_data.low_guid.is_some());
+        object_update_flags.set(ObjectUpdateFlags::HIGHGUID, movement_data.high_guid.is_some());
+        object_update_flags.set(ObjectUpdateFlags::HAS_TARGET, movement_data.target_guid.is_some());
+
+        buffer.write_u16::<LittleEndian>(object_update_flags.bits())?;
+
+        if let Some(movement_info) = &movement_data.movement_info {
+            MovementParser::write(buffer, movement_info)?;
+
+            for move_type in MOVE_TYPES {
+                let speed = movement_data.movement_speed.get(&move_type).copied().unwrap_or(0.0);
+                buffer.write_f32::<LittleEndian>(speed)?;
+            }
+
+            if movement_info.movement_flags.contains(MovementFlags::SPLINE_ENABLED) {
+                match &movement_data.spline {
+                    Some(spline) => SplineParser::write(buffer, spline)?,
+                    // flag requires spline data, so empty spline is written to keep the block valid
+                    None => SplineParser::write(buffer, &Spline::new(0, SplineFlags::NONE, 0, vec![]))?,
+                }
+            }
+        } else if let Some(position) = &movement_data.position {
+            match &movement_data.transport {
+                Some(transport) => {
+                    write_packed_guid(buffer, transport.guid.raw())?;
+                    buffer.write_f32::<LittleEndian>(position.x)?;
+                    buffer.write_f32::<LittleEndian>(position.y)?;
+                    buffer.write_f32::<LittleEndian>(position.z)?;
+                    // orientation is sent only once, inside the offset
+                    PositionParser::write(buffer, &transport.offset)?;
+                    buffer.write_f32::<LittleEndian>(0.0)?;
+                },
+                None => PositionParser::write(buffer, position)?,
+            }
+        }
+
+        if let Some(low_guid) = movement_data.low_guid {
+            buffer.write_u32::<LittleEndian>(low_guid)?;
+        }
+
+        if let Some(high_guid) = movement_data.high_guid {
+            buffer.write_u32::<LittleEndian>(high_guid)?;
+        }
+
+        if let Some(target_guid) = movement_data.target_guid {
+            write_packed_guid(buffer, target_guid.raw())?;
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
     use std::io::Cursor;
     use flate2::read::DeflateDecoder;
 
+    use byteorder::{LittleEndian, WriteBytesExt};
+
     use crate::player::{ObjectField, PlayerField, UnitField};
     use crate::primary::client::FieldValue;
     use crate::primary::parsers::update_block_parser::UpdateBlocksParser;
+    use crate::primary::parsers::update_block_parser::types::{encode_update_fields, ObjectUpdateFlags, ParsedBlock};
+    use crate::primary::types::ObjectGuid;
+    use crate::primary::utils::write_packed_guid;
 
     const COMPRESSED_PACKET: [u8; 339] = [99, 100, 96, 96, 96,
         226, 223, 188, 53, 140, 157, 165, 16, 200, 132, 1, 227, 199, 181, 110, 179, 100, 235, 220,
@@ -347,7 +458,7 @@ mod tests {
         111, 117, 192, 133, 193, 225, 224, 192, 192, 144, 7, 212, 7, 114, 43, 46, 252, 31, 8, 2,
         128, 42, 68, 129, 24, 20, 6, 226, 64, 44, 1, 196, 160, 176, 7, 133, 5, 0, 2, 177, 96, 33];
 
-    const TEST_GUID: u64 = 123123123;
+    const TEST_GUID: ObjectGuid = ObjectGuid::new(123123123);
     const TEST_HEALTH: u32 = 71;
     const TEST_XP: u32 = 400;
 
@@ -360,24 +471,119 @@ mod tests {
         let parsed = UpdateBlocksParser::parse(&mut Cursor::new(buffer)).expect("Cannot parse");
         assert_eq!(parsed[0].guid, Some(TEST_GUID));
 
-        if let Some(FieldValue::Long(guid)) = parsed[0].update_fields.get(&ObjectField::GUID) {
-            assert_eq!(*guid, TEST_GUID);
+        if let Some(FieldValue::Long(guid)) = parsed[0].get_update_fields().get(&ObjectField::GUID) {
+            assert_eq!(*guid, TEST_GUID.raw());
         } else {
             panic!("GUID was not parsed correctly !");
         }
 
-        if let Some(FieldValue::Integer(health)) = parsed[0].update_fields.get(&UnitField::HEALTH) {
+        if let Some(FieldValue::Integer(health)) = parsed[0].get_update_fields().get(&UnitField::HEALTH) {
             assert_eq!(*health, TEST_HEALTH);
         } else {
             panic!("HEALTH was not parsed correctly !");
         }
 
         if let Some(FieldValue::Integer(xp)) = parsed[0]
-            .update_fields.get(&PlayerField::NEXT_LEVEL_XP)
+            .get_update_fields().get(&PlayerField::NEXT_LEVEL_XP)
         {
             assert_eq!(*xp, TEST_XP);
         } else {
             panic!("NEXT_LEVEL_XP was not parsed correctly !");
         }
     }
+
+    #[test]
+    fn test_parsed_blocks_json_round_trip() {
+        let mut buffer = Vec::new();
+        let mut decoder = DeflateDecoder::new(&COMPRESSED_PACKET[..]);
+        std::io::Read::read_to_end(&mut decoder, &mut buffer).expect("Cannot read");
+
+        let parsed = UpdateBlocksParser::parse(&mut Cursor::new(buffer)).expect("Cannot parse");
+        let json = serde_json::to_string(&parsed).expect("Cannot serialize");
+
+        let restored: Vec<ParsedBlock> = serde_json::from_str(&json).expect("Cannot deserialize");
+        assert_eq!(restored[0].guid, Some(TEST_GUID));
+        assert_eq!(restored[0].get_update_fields().len(), parsed[0].get_update_fields().len());
+        assert_eq!(json, serde_json::to_string(&restored).expect("Cannot serialize"));
+
+        // value which does not fit into u32 field is rejected instead of truncated
+        let mut update_fields = BTreeMap::new();
+        update_fields.insert(UnitField::HEALTH, FieldValue::Integer(424242));
+        let block = ParsedBlock {
+            guid: Some(TEST_GUID), raw_fields: encode_update_fields(&update_fields), ..ParsedBlock::new()
+        };
+
+        let json = serde_json::to_string(&vec![block]).expect("Cannot serialize")
+            .replace("424242", &(u32::MAX as u64 + 1).to_string());
+        assert!(json.contains("4294967296"));
+        assert!(serde_json::from_str::<Vec<ParsedBlock>>(&json).is_err());
+    }
+
+    // movement part of POSITION block: transport guid, world position, offset with orientation
+    // and corpse orientation
+    fn build_position_block(transport_guid: u64, position: [f32; 3], offset: [f32; 4]) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        buffer.write_u16::<LittleEndian>(ObjectUpdateFlags::POSITION.bits()).unwrap();
+        write_packed_guid(&mut buffer, transport_guid).unwrap();
+        for value in position.into_iter().chain(offset) {
+            buffer.write_f32::<LittleEndian>(value).unwrap();
+        }
+        buffer.write_f32::<LittleEndian>(0.0).unwrap();
+
+        buffer
+    }
+
+    #[test]
+    fn test_position_block_orientation() {
+        let buffer = build_position_block(0, [10.0, 20.0, 30.0], [10.0, 20.0, 30.0, 1.5]);
+        let movement_data = UpdateBlocksParser::parse_movement_data(&mut Cursor::new(buffer)).unwrap();
+
+        let position = movement_data.position.unwrap();
+        assert_eq!((position.x, position.y, position.z), (10.0, 20.0, 30.0));
+        assert_eq!(position.orientation, 1.5);
+        assert!(movement_data.transport.is_none());
+
+        // writer puts orientation back into the same place
+        let mut buffer = Vec::new();
+        UpdateBlocksParser::write_movement_data(&mut buffer, &movement_data).unwrap();
+        let restored = UpdateBlocksParser::parse_movement_data(&mut Cursor::new(buffer)).unwrap();
+        assert_eq!(restored.position.unwrap().orientation, 1.5);
+    }
+
+    #[test]
+    fn test_passenger_world_position() {
+        let facing = std::f32::consts::FRAC_PI_2;
+
+        let buffer = build_position_block(0, [100.0, 200.0, 10.0], [100.0, 200.0, 10.0, facing]);
+        let transport = UpdateBlocksParser::parse_movement_data(&mut Cursor::new(buffer)).unwrap();
+        let transport_position = transport.position.unwrap();
+
+        let buffer = build_position_block(0x1FC0_0000_0000_0010, [102.0, 200.0, 11.0], [2.0, 0.0, 1.0, 0.0]);
+        let passenger = UpdateBlocksParser::parse_movement_data(&mut Cursor::new(buffer)).unwrap();
+        let transport_info = passenger.transport.unwrap();
+        assert_eq!(transport_info.guid, ObjectGuid::new(0x1FC0_0000_0000_0010));
+
+        // transport faces +y, so offset along its x axis goes along world y
+        let position = transport_info.world_position(&transport_position);
+        assert!((position.x - 100.0).abs() < 1e-4);
+        assert!((position.y - 202.0).abs() < 1e-4);
+        assert_eq!(position.z, 11.0);
+        assert!((position.orientation - facing).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_partial_long_field_round_trip() {
+        // only high half of the guid is changed, it cannot be decoded on its own
+        let mut raw_fields = BTreeMap::new();
+        raw_fields.insert(ObjectField::GUID + 1, 5);
+        raw_fields.insert(UnitField::HEALTH, 100);
+        let block = ParsedBlock { guid: Some(TEST_GUID), raw_fields, ..ParsedBlock::new() };
+
+        let mut buffer = Vec::new();
+        UpdateBlocksParser::write(&mut buffer, std::slice::from_ref(&block)).unwrap();
+        let parsed = UpdateBlocksParser::parse(&mut Cursor::new(buffer)).unwrap();
+
+        assert_eq!(parsed[0].raw_fields, block.raw_fields);
+        assert_eq!(parsed[0].get_update_fields().len(), 1);
+    }
 }
\ No newline at end of file
@@ -1,85 +1,271 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::{Debug, Formatter};
+use std::sync::OnceLock;
 use bitflags::bitflags;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error as DeError;
 use serde::ser::SerializeStruct;
 
-use crate::primary::client::{FieldValue, ObjectField, PlayerField, UnitField};
-use crate::primary::parsers::movement_parser::types::MovementInfo;
+use crate::primary::client::{FieldType, FieldValue, ObjectField, PlayerField, UnitField};
+use crate::primary::parsers::movement_parser::types::{MovementInfo, TransportInfo};
+use crate::primary::types::ObjectGuid;
+use crate::primary::parsers::position_parser::types::Position;
+use crate::primary::parsers::spline_parser::types::Spline;
 
 pub type UpdateFields = BTreeMap<u32, FieldValue>;
 
+// raw u32 values by field index, as they come in the update mask
+pub type RawFields = BTreeMap<u32, u32>;
+
+pub fn get_field_type(field_index: u32) -> FieldType {
+    if field_index < ObjectField::LIMIT {
+        ObjectField::get_field_type(field_index)
+    } else if field_index < UnitField::LIMIT {
+        UnitField::get_field_type(field_index)
+    } else {
+        PlayerField::get_field_type(field_index)
+    }
+}
+
+// unnamed fields are serialized by their index to keep them distinguishable
+pub fn get_field_name(field_index: u32) -> String {
+    let name = if field_index < ObjectField::LIMIT {
+        ObjectField::get_field_name(field_index)
+    } else if field_index < UnitField::LIMIT {
+        UnitField::get_field_name(field_index)
+    } else {
+        PlayerField::get_field_name(field_index)
+    };
+
+    if name.is_empty() {
+        field_index.to_string()
+    } else {
+        name
+    }
+}
+
+// 64-bit value is stored as two fields, high half has no own type
+pub fn decode_field_value(field_index: u32, value: u32, high_value: Option<u32>) -> Option<FieldValue> {
+    match get_field_type(field_index) {
+        FieldType::Integer => Some(FieldValue::Integer(value)),
+        FieldType::Bytes => Some(FieldValue::Bytes(value)),
+        FieldType::Long => {
+            Some(FieldValue::Long((u64::from(high_value.unwrap_or(0)) << 32) | u64::from(value)))
+        },
+        FieldType::Float => Some(FieldValue::Float(f32::from_bits(value))),
+        FieldType::TwoShorts => {
+            let first: u16 = (value & 0xFFFF) as u16;
+            let second: u16 = ((value >> 16) & 0xFFFF) as u16;
+            Some(FieldValue::TwoShorts(first, second))
+        },
+        FieldType::None => None,
+    }
+}
+
+pub fn decode_update_fields(raw_fields: &RawFields) -> UpdateFields {
+    raw_fields
+        .iter()
+        .filter_map(|(index, value)| {
+            decode_field_value(*index, *value, raw_fields.get(&(index + 1)).copied())
+                .map(|value| (*index, value))
+        })
+        .collect()
+}
+
+pub fn encode_update_fields(update_fields: &UpdateFields) -> RawFields {
+    let mut raw_fields: RawFields = BTreeMap::new();
+
+    for (k, v) in update_fields {
+        match *v {
+            FieldValue::Integer(value) | FieldValue::Bytes(value) => {
+                raw_fields.insert(*k, value);
+            },
+            FieldValue::Long(value) => {
+                raw_fields.insert(*k, value as u32);
+                raw_fields.insert(*k + 1, (value >> 32) as u32);
+            },
+            FieldValue::Float(value) => {
+                raw_fields.insert(*k, value.to_bits());
+            },
+            FieldValue::TwoShorts(first, second) => {
+                raw_fields.insert(*k, (first as u32) | ((second as u32) << 16));
+            },
+        }
+    }
+
+    raw_fields
+}
+
+pub fn get_field_index(field_name: &str) -> Option<u32> {
+    static FIELD_INDEXES: OnceLock<HashMap<String, u32>> = OnceLock::new();
+
+    FIELD_INDEXES
+        .get_or_init(|| {
+            (0..PlayerField::LIMIT).map(|index| (get_field_name(index), index)).collect()
+        })
+        .get(field_name)
+        .copied()
+        .or_else(|| field_name.parse::<u32>().ok())
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawFieldValue {
+    Integer(u64),
+    Float(f32),
+    TwoShorts(u16, u16),
+}
+
+fn deserialize_update_fields<'de, D>(deserializer: D) -> Result<UpdateFields, D::Error>
+    where D: Deserializer<'de>
+{
+    let named_fields: BTreeMap<String, RawFieldValue> = BTreeMap::deserialize(deserializer)?;
+    let mut update_fields: UpdateFields = BTreeMap::new();
+
+    for (name, raw_value) in named_fields {
+        let index = get_field_index(&name)
+            .ok_or_else(|| D::Error::custom(format!("unknown update field \"{}\"", name)))?;
+
+        let to_u32 = |value: u64| u32::try_from(value).map_err(|_| {
+            D::Error::custom(format!("value {} of update field \"{}\" does not fit into u32", value, name))
+        });
+
+        let value = match (get_field_type(index), raw_value) {
+            (FieldType::Integer, RawFieldValue::Integer(value)) => FieldValue::Integer(to_u32(value)?),
+            (FieldType::Bytes, RawFieldValue::Integer(value)) => FieldValue::Bytes(to_u32(value)?),
+            (FieldType::Long, RawFieldValue::Integer(value)) => FieldValue::Long(value),
+            (FieldType::Float, RawFieldValue::Integer(value)) => FieldValue::Float(value as f32),
+            (FieldType::Float, RawFieldValue::Float(value)) => FieldValue::Float(value),
+            (FieldType::TwoShorts, RawFieldValue::TwoShorts(first, second)) => {
+                FieldValue::TwoShorts(first, second)
+            },
+            _ => {
+                return Err(D::Error::custom(format!("invalid value for update field \"{}\"", name)));
+            },
+        };
+
+        update_fields.insert(index, value);
+    }
+
+    Ok(update_fields)
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct MovementData {
     pub movement_info: Option<MovementInfo>,
+    // position of non-living objects (game objects, dynamic objects, corpses)
+    pub position: Option<Position>,
     pub high_guid: Option<u32>,
     pub low_guid: Option<u32>,
-    pub target_guid: Option<u64>,
+    pub target_guid: Option<ObjectGuid>,
     pub movement_speed: BTreeMap<u8, f32>,
+    pub spline: Option<Spline>,
+    // transport of non-living objects, living ones keep it inside movement info
+    pub transport: Option<TransportInfo>,
 }
 
 impl MovementData {
     pub fn new() -> Self {
         Self {
             movement_info: None,
+            position: None,
             high_guid: None,
             low_guid: None,
             target_guid: None,
             movement_speed: BTreeMap::new(),
+            spline: None,
+            transport: None,
         }
     }
 }
 
 impl<'de> Deserialize<'de> for MovementData {
-    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
-        todo!()
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        #[derive(Deserialize)]
+        struct RawMovementData {
+            movement_info: Option<MovementInfo>,
+            #[serde(default)]
+            position: Option<Position>,
+            high_guid: Option<u32>,
+            low_guid: Option<u32>,
+            target_guid: Option<ObjectGuid>,
+            #[serde(default)]
+            movement_speed: BTreeMap<u8, f32>,
+            #[serde(default)]
+            spline: Option<Spline>,
+            #[serde(default)]
+            transport: Option<TransportInfo>,
+        }
+
+        let RawMovementData {
+            movement_info, position, high_guid, low_guid, target_guid, movement_speed, spline, transport,
+        } = RawMovementData::deserialize(deserializer)?;
+
+        Ok(Self {
+            movement_info, position, high_guid, low_guid, target_guid, movement_speed, spline, transport,
+        })
     }
 }
 
 impl Serialize for MovementData {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
-        const FIELDS_AMOUNT: usize = 5;
+        const FIELDS_AMOUNT: usize = 8;
         let mut state = serializer.serialize_struct("MovementData", FIELDS_AMOUNT)?;
         state.serialize_field("movement_info", &self.movement_info)?;
+        state.serialize_field("position", &self.position)?;
         state.serialize_field("high_guid", &self.high_guid)?;
         state.serialize_field("low_guid", &self.low_guid)?;
         state.serialize_field("target_guid", &self.target_guid)?;
         state.serialize_field("movement_speed", &self.movement_speed)?;
+        state.serialize_field("spline", &self.spline)?;
+        state.serialize_field("transport", &self.transport)?;
         state.end()
     }
 }
 
 #[derive(Clone, Default)]
 pub struct ParsedBlock {
-    pub guid: Option<u64>,
-    pub out_of_range_guids: Vec<u64>,
-    pub near_object_guids: Vec<u64>,
-    pub update_fields: UpdateFields,
+    pub guid: Option<ObjectGuid>,
+    pub out_of_range_guids: Vec<ObjectGuid>,
+    pub near_object_guids: Vec<ObjectGuid>,
+    // values as sent by server, halves of 64-bit fields cannot be decoded separately,
+    // so decoded fields are built from them on demand
+    pub raw_fields: RawFields,
     pub movement_data: Option<MovementData>,
 }
 
 impl<'de> Deserialize<'de> for ParsedBlock {
-    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
-        todo!()
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        #[derive(Deserialize)]
+        struct RawParsedBlock {
+            guid: Option<ObjectGuid>,
+            #[serde(default)]
+            out_of_range_guids: Vec<ObjectGuid>,
+            #[serde(default)]
+            near_object_guids: Vec<ObjectGuid>,
+            #[serde(default, deserialize_with = "deserialize_update_fields")]
+            update_fields: UpdateFields,
+            movement_data: Option<MovementData>,
+        }
+
+        let RawParsedBlock {
+            guid, out_of_range_guids, near_object_guids, update_fields, movement_data,
+        } = RawParsedBlock::deserialize(deserializer)?;
+
+        let raw_fields = encode_update_fields(&update_fields);
+
+        Ok(Self { guid, out_of_range_guids, near_object_guids, raw_fields, movement_data })
     }
 }
 
 impl Serialize for ParsedBlock {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
         let mut update_fields: BTreeMap<String, FieldValue> = BTreeMap::new();
-        for (k, v) in &self.update_fields {
-            let key = if k < &ObjectField::LIMIT {
-                ObjectField::get_field_name(*k)
-            } else if k < &UnitField::LIMIT {
-                UnitField::get_field_name(*k)
-            } else {
-                PlayerField::get_field_name(*k)
-            };
-
-            update_fields.insert(key, v.clone());
+        for (k, v) in self.get_update_fields() {
+            update_fields.insert(get_field_name(k), v);
         }
 
-        const FIELDS_AMOUNT: usize = 3;
+        const FIELDS_AMOUNT: usize = 5;
         let mut state = serializer.serialize_struct("ParsedBlock", FIELDS_AMOUNT)?;
         state.serialize_field("guid", &self.guid)?;
         state.serialize_field("out_of_range_guids", &self.out_of_range_guids)?;
@@ -96,14 +282,23 @@ impl ParsedBlock {
             guid: None,
             out_of_range_guids: Vec::new(),
             near_object_guids: Vec::new(),
-            update_fields: BTreeMap::new(),
+            raw_fields: BTreeMap::new(),
             movement_data: None,
         }
     }
 
+    pub fn get_update_fields(&self) -> UpdateFields {
+        decode_update_fields(&self.raw_fields)
+    }
+
+    // only create blocks carry both movement data and values
+    pub fn is_create(&self) -> bool {
+        self.movement_data.is_some() && !self.raw_fields.is_empty()
+    }
+
     pub fn is_empty(parsed_block: &ParsedBlock) -> bool {
         parsed_block.guid.is_none()
-            && parsed_block.update_fields.is_empty()
+            && parsed_block.raw_fields.is_empty()
             && parsed_block.movement_data.is_none()
             && parsed_block.out_of_range_guids.is_empty()
             && parsed_block.near_object_guids.is_empty()
@@ -116,7 +311,7 @@ impl Debug for ParsedBlock {
             f,
             "guid: {:?}, update_fields: {:?}",
             self.guid,
-            self.update_fields,
+            self.get_update_fields(),
         )
     }
 }
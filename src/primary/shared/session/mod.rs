@@ -1,13 +1,14 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug};
 
 pub mod types;
 
-use crate::primary::client::{Player, Realm, WardenModuleInfo};
+use crate::primary::client::{MovementFlags, Player, Realm, WardenModuleInfo, WorldEvent, WorldLocation};
 use crate::primary::config::{Config, ConfigParams};
 use crate::primary::crypto::srp::Srp;
 use crate::primary::errors::ConfigError;
 use crate::primary::shared::session::types::{ActionFlags, StateFlags};
+use crate::primary::types::ObjectGuid;
 
 #[derive(Debug)]
 pub struct Session {
@@ -16,11 +17,19 @@ pub struct Session {
     pub warden_module_info: Option<WardenModuleInfo>,
     pub config: Option<Config>,
     pub me: Option<Player>,
-    pub follow_target: Option<u64>,
+    pub follow_target: Option<ObjectGuid>,
     pub action_flags: ActionFlags,
     pub state_flags: StateFlags,
+    // own movement state, changed by server (root, can fly etc)
+    pub movement_flags: MovementFlags,
     pub party: Vec<Player>,
     pub spells_map: HashSet<u32>,
+    // unknown until the character enters the world, so the first location always fires the event
+    pub location: Option<WorldLocation>,
+    // world state id -> value, refreshed on every zone change
+    pub world_states: HashMap<u32, u32>,
+    // map id -> instance id the character is saved to
+    pub instance_binds: HashMap<u32, u64>,
 }
 
 impl Session {
@@ -34,8 +43,12 @@ impl Session {
             follow_target: None,
             action_flags: ActionFlags::NONE,
             state_flags: StateFlags::NONE,
+            movement_flags: MovementFlags::NONE,
             party: Vec::new(),
             spells_map: HashSet::new(),
+            location: None,
+            world_states: HashMap::new(),
+            instance_binds: HashMap::new(),
         }
     }
 
@@ -56,4 +69,62 @@ impl Session {
 
         Ok(())
     }
+
+    // returns event only when location was actually changed
+    pub fn set_location(&mut self, location: WorldLocation) -> Option<WorldEvent> {
+        if self.location == Some(location) {
+            return None;
+        }
+
+        let previous = self.location.replace(location);
+        Some(WorldEvent::LocationChanged(previous, location))
+    }
+
+    // zone and area are unknown until SMSG_INIT_WORLD_STATES arrives for the new map
+    pub fn set_map(&mut self, map_id: u32) -> Option<WorldEvent> {
+        if self.location.map(|location| location.map_id) == Some(map_id) {
+            return None;
+        }
+
+        self.world_states.clear();
+        self.set_location(WorldLocation {
+            map_id,
+            instance_id: self.instance_binds.get(&map_id).copied(),
+            ..WorldLocation::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primary::client::{WorldEvent, WorldLocation};
+    use crate::primary::shared::session::Session;
+
+    #[test]
+    fn test_set_location() {
+        let mut session = Session::new();
+        session.instance_binds.insert(533, 42);
+
+        // map 0 is the first one, but still differs from unknown location
+        assert!(matches!(
+            session.set_map(0),
+            Some(WorldEvent::LocationChanged(None, current)) if current == WorldLocation::default()
+        ));
+
+        let location = WorldLocation { map_id: 0, zone_id: 1519, area_id: 4411, instance_id: None };
+        assert!(matches!(
+            session.set_location(location),
+            Some(WorldEvent::LocationChanged(Some(previous), current)) if previous == WorldLocation::default() && current == location
+        ));
+        assert!(session.set_location(location).is_none());
+        assert!(session.set_map(0).is_none());
+
+        match session.set_map(533) {
+            Some(WorldEvent::LocationChanged(previous, current)) => {
+                assert_eq!(previous, Some(location));
+                assert_eq!(current, WorldLocation { map_id: 533, zone_id: 0, area_id: 0, instance_id: Some(42) });
+            },
+            _ => panic!("location should be changed"),
+        }
+    }
 }
\ No newline at end of file
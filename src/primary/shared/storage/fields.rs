@@ -0,0 +1,150 @@
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+use crate::primary::client::{FieldType, FieldValue};
+use crate::primary::parsers::update_block_parser::types::{
+    decode_field_value, decode_update_fields, get_field_type, RawFields, UpdateFields,
+};
+
+// lets views read both decoded fields and the raw store
+pub trait FieldSource: Debug {
+    fn get_field(&self, index: u32) -> Option<FieldValue>;
+}
+
+impl FieldSource for UpdateFields {
+    fn get_field(&self, index: u32) -> Option<FieldValue> {
+        self.get(&index).cloned()
+    }
+}
+
+impl FieldSource for UpdateFieldStore {
+    fn get_field(&self, index: u32) -> Option<FieldValue> {
+        self.get(index)
+    }
+}
+
+// raw u32 values of all known fields, VALUES blocks are merged on top of them
+// 64-bit fields are decoded from the merged halves, as they can be updated separately
+#[derive(Debug, Clone, Default)]
+pub struct UpdateFieldStore {
+    values: RawFields,
+}
+
+impl UpdateFieldStore {
+    pub fn new() -> Self {
+        Self {
+            values: BTreeMap::new(),
+        }
+    }
+
+    // returns indexes of decoded fields which value was changed, together with their previous values
+    pub fn merge(&mut self, raw_fields: &RawFields) -> Vec<(u32, Option<FieldValue>)> {
+        let mut changed: Vec<(u32, Option<FieldValue>)> = Vec::new();
+
+        for (index, value) in raw_fields.iter() {
+            if self.values.get(index) == Some(value) {
+                continue;
+            }
+
+            // halves come in ascending order, so the old value is taken before any of them is stored
+            let base_index = Self::get_base_index(*index);
+            if changed.last().map(|(index, _)| *index) != Some(base_index) {
+                changed.push((base_index, self.get(base_index)));
+            }
+
+            self.values.insert(*index, *value);
+        }
+
+        changed
+    }
+
+    pub fn get(&self, index: u32) -> Option<FieldValue> {
+        let high_value = self.values.get(&(index + 1)).copied();
+        match self.values.get(&index) {
+            Some(value) => decode_field_value(index, *value, high_value),
+            // high half was received without low one
+            None if matches!(get_field_type(index), FieldType::Long) => {
+                high_value.map(|value| FieldValue::Long(u64::from(value) << 32))
+            },
+            None => None,
+        }
+    }
+
+    pub fn get_raw(&self, index: u32) -> Option<u32> {
+        self.values.get(&index).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn to_fields(&self) -> UpdateFields {
+        let mut fields = decode_update_fields(&self.values);
+
+        for index in self.values.keys() {
+            let base_index = Self::get_base_index(*index);
+            if base_index != *index && !fields.contains_key(&base_index) {
+                fields.extend(self.get(base_index).map(|value| (base_index, value)));
+            }
+        }
+
+        fields
+    }
+
+    // high half of 64-bit field refers to the field it belongs to
+    fn get_base_index(index: u32) -> u32 {
+        let is_high_half = index > 0
+            && matches!(get_field_type(index), FieldType::None)
+            && matches!(get_field_type(index - 1), FieldType::Long);
+
+        match is_high_half {
+            true => index - 1,
+            false => index,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::primary::client::{FieldValue, ObjectField, UnitField};
+    use crate::primary::shared::storage::fields::UpdateFieldStore;
+
+    #[test]
+    fn test_merge_long_halves() {
+        let mut store = UpdateFieldStore::new();
+
+        let mut raw_fields = BTreeMap::new();
+        raw_fields.insert(ObjectField::GUID, 0x0000_0001);
+        raw_fields.insert(ObjectField::GUID + 1, 0x0000_0002);
+        raw_fields.insert(ObjectField::ENTRY, 42);
+        assert_eq!(store.merge(&raw_fields), vec![(ObjectField::GUID, None), (ObjectField::ENTRY, None)]);
+        assert_eq!(store.get(ObjectField::GUID), Some(FieldValue::Long(0x0000_0002_0000_0001)));
+
+        // only high half changed
+        let mut raw_fields = BTreeMap::new();
+        raw_fields.insert(ObjectField::GUID + 1, 0x0000_0003);
+        raw_fields.insert(ObjectField::ENTRY, 42);
+        assert_eq!(
+            store.merge(&raw_fields),
+            vec![(ObjectField::GUID, Some(FieldValue::Long(0x0000_0002_0000_0001)))],
+        );
+        assert_eq!(store.get(ObjectField::GUID), Some(FieldValue::Long(0x0000_0003_0000_0001)));
+
+        let mut raw_fields = BTreeMap::new();
+        raw_fields.insert(UnitField::SUMMON + 1, 0x0000_0005);
+        store.merge(&raw_fields);
+
+        let fields = store.to_fields();
+        assert_eq!(fields.len(), 3);
+        assert_eq!(fields.get(&ObjectField::ENTRY), Some(&FieldValue::Integer(42)));
+        assert_eq!(fields.get(&UnitField::SUMMON), Some(&FieldValue::Long(0x0000_0005_0000_0000)));
+        assert_eq!(store.get(UnitField::SUMMON), Some(FieldValue::Long(0x0000_0005_0000_0000)));
+        assert_eq!(store.get_raw(ObjectField::GUID + 1), Some(3));
+    }
+}
\ No newline at end of file
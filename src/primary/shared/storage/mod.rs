@@ -1,16 +1,91 @@
 use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+pub mod fields;
+pub mod objects;
+pub mod spatial;
 
 use crate::primary::client::Player;
+use crate::primary::shared::storage::objects::{ObjectManager, WorldObject};
+use crate::primary::types::ObjectGuid;
+
+// server may never answer the query (for example when player logged out), so it can be sent again
+const NAME_QUERY_TIMEOUT: Duration = Duration::from_secs(10);
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct DataStorage {
-    pub players_map: BTreeMap<u64, Player>,
+    pub players_map: BTreeMap<ObjectGuid, Player>,
+    pub objects: ObjectManager,
+    // guids with sent name query which is not answered yet, with the time it was sent
+    pub pending_name_queries: BTreeMap<ObjectGuid, Instant>,
 }
 
 impl DataStorage {
     pub fn new() -> Self {
         Self {
             players_map: BTreeMap::new(),
+            objects: ObjectManager::new(),
+            pending_name_queries: BTreeMap::new(),
+        }
+    }
+
+    // returns false if query for this guid was already sent and is not expired yet
+    pub fn add_name_query(&mut self, guid: ObjectGuid) -> bool {
+        let now = Instant::now();
+        match self.pending_name_queries.get(&guid) {
+            Some(sent_at) if now.duration_since(*sent_at) < NAME_QUERY_TIMEOUT => false,
+            _ => {
+                self.pending_name_queries.insert(guid, now);
+                true
+            },
         }
     }
+
+    pub fn complete_name_query(&mut self, guid: ObjectGuid) {
+        self.pending_name_queries.remove(&guid);
+    }
+
+    // answers for queries from previous map or session may never come
+    pub fn clear_name_queries(&mut self) {
+        self.pending_name_queries.clear();
+    }
+
+    pub fn get_player_by_name(&self, name: &str) -> Option<&Player> {
+        self.players_map.values().find(|player| player.name.eq_ignore_ascii_case(name))
+    }
+
+    // only players are known by name, creatures and game objects are never queried
+    pub fn get_player_object_by_name(&self, name: &str) -> Option<&WorldObject> {
+        self.get_player_by_name(name).and_then(|player| self.objects.get(player.guid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use crate::primary::shared::storage::{DataStorage, NAME_QUERY_TIMEOUT};
+    use crate::primary::types::ObjectGuid;
+
+    #[test]
+    fn test_name_queries() {
+        let mut storage = DataStorage::new();
+        let guid = ObjectGuid::from(1);
+
+        assert!(storage.add_name_query(guid));
+        assert!(!storage.add_name_query(guid));
+
+        storage.complete_name_query(guid);
+        assert!(storage.add_name_query(guid));
+
+        // unanswered query is sent again after timeout
+        if let Some(sent_at) = Instant::now().checked_sub(NAME_QUERY_TIMEOUT) {
+            storage.pending_name_queries.insert(guid, sent_at);
+            assert!(storage.add_name_query(guid));
+            assert!(!storage.add_name_query(guid));
+        }
+
+        storage.clear_name_queries();
+        assert!(storage.pending_name_queries.is_empty());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,705 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Instant;
+
+use crate::primary::client::{FieldValue, ObjectField, Position};
+use crate::primary::client::player::views::{PlayerView, UnitView};
+use crate::primary::shared::storage::fields::UpdateFieldStore;
+use crate::primary::shared::storage::spatial::SpatialIndex;
+use crate::primary::parsers::movement_parser::types::{MovementInfo, TransportInfo};
+use crate::primary::parsers::spline_parser::types::Spline;
+use crate::primary::parsers::update_block_parser::types::{
+    MovementData, ObjectTypeMask, ParsedBlock, UpdateFields,
+};
+use crate::primary::types::{HighGuid, ObjectGuid};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ObjectType {
+    Object,
+    Item,
+    Container,
+    Unit,
+    Player,
+    GameObject,
+    DynamicObject,
+    Corpse,
+}
+
+impl ObjectType {
+    // type mask contains all parent types, so most specific one should be checked first
+    pub fn from_type_mask(type_mask: u32) -> Self {
+        [
+            (ObjectTypeMask::TYPEMASK_PLAYER, Self::Player),
+            (ObjectTypeMask::TYPEMASK_UNIT, Self::Unit),
+            (ObjectTypeMask::TYPEMASK_CONTAINER, Self::Container),
+            (ObjectTypeMask::TYPEMASK_ITEM, Self::Item),
+            (ObjectTypeMask::TYPEMASK_GAMEOBJECT, Self::GameObject),
+            (ObjectTypeMask::TYPEMASK_DYNAMICOBJECT, Self::DynamicObject),
+            (ObjectTypeMask::TYPEMASK_CORPSE, Self::Corpse),
+        ]
+            .into_iter()
+            .find(|(mask, _)| type_mask & mask != 0)
+            .map(|(_, object_type)| object_type)
+            .unwrap_or(Self::Object)
+    }
+
+    pub fn from_fields(update_fields: &UpdateFields) -> Option<Self> {
+        match update_fields.get(&ObjectField::TYPE) {
+            Some(FieldValue::Integer(type_mask)) => Some(Self::from_type_mask(*type_mask)),
+            _ => None,
+        }
+    }
+
+    // fallback for blocks without TYPE field, guid cannot tell item from container
+    pub fn from_guid(guid: ObjectGuid) -> Self {
+        match guid.high() {
+            HighGuid::Player if !guid.is_empty() => Self::Player,
+            HighGuid::Item => Self::Item,
+            HighGuid::Unit | HighGuid::Pet | HighGuid::Vehicle => Self::Unit,
+            HighGuid::GameObject | HighGuid::Transport | HighGuid::MoTransport => Self::GameObject,
+            HighGuid::DynamicObject => Self::DynamicObject,
+            HighGuid::Corpse => Self::Corpse,
+            _ => Self::Object,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    pub index: u32,
+    pub old_value: Option<FieldValue>,
+    pub new_value: FieldValue,
+}
+
+#[derive(Debug, Clone)]
+pub enum ObjectEvent {
+    Appeared(ObjectGuid, ObjectType),
+    Updated(ObjectGuid, ObjectType, Vec<FieldChange>),
+    Moved(ObjectGuid, ObjectType, Position),
+    Removed(ObjectGuid, ObjectType),
+}
+
+impl ObjectEvent {
+    pub fn guid(&self) -> ObjectGuid {
+        match self {
+            Self::Appeared(guid, _)
+            | Self::Updated(guid, _, _)
+            | Self::Moved(guid, _, _)
+            | Self::Removed(guid, _) => *guid,
+        }
+    }
+
+    pub fn object_type(&self) -> ObjectType {
+        match self {
+            Self::Appeared(_, object_type)
+            | Self::Updated(_, object_type, _)
+            | Self::Moved(_, object_type, _)
+            | Self::Removed(_, object_type) => *object_type,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WorldObject {
+    pub guid: ObjectGuid,
+    pub object_type: ObjectType,
+    // raw values, decoded on access
+    pub fields: UpdateFieldStore,
+    pub movement_data: Option<MovementData>,
+    pub position: Option<Position>,
+    pub spline: Option<Spline>,
+    pub transport: Option<TransportInfo>,
+}
+
+impl WorldObject {
+    pub fn new(guid: ObjectGuid, object_type: ObjectType) -> Self {
+        Self {
+            guid,
+            object_type,
+            fields: UpdateFieldStore::new(),
+            movement_data: None,
+            position: None,
+            spline: None,
+            transport: None,
+        }
+    }
+
+    // position of the object moving by spline is calculated from the time passed since spline start
+    pub fn position_at(&self, now: Instant) -> Option<Position> {
+        match &self.spline {
+            Some(spline) => spline.position_at(now).or(self.position),
+            None => self.position,
+        }
+    }
+
+    pub fn unit_view(&self) -> Option<UnitView<'_>> {
+        match self.object_type {
+            ObjectType::Unit | ObjectType::Player => Some(UnitView::new(&self.fields)),
+            _ => None,
+        }
+    }
+
+    pub fn player_view(&self) -> Option<PlayerView<'_>> {
+        match self.object_type {
+            ObjectType::Player => Some(PlayerView::new(&self.fields)),
+            _ => None,
+        }
+    }
+}
+
+const NEAREST_SEARCH_RADIUS: f32 = 50.0;
+// bigger than any map
+const MAX_SEARCH_RADIUS: f32 = 70000.0;
+
+// every object currently visible to the client
+#[derive(Debug, Default)]
+pub struct ObjectManager {
+    objects: BTreeMap<ObjectGuid, WorldObject>,
+    // transport guid -> guids of objects on it
+    passengers: BTreeMap<ObjectGuid, BTreeSet<ObjectGuid>>,
+    spatial_index: SpatialIndex,
+    // objects moving by spline leave their indexed cell, so they are always checked by queries
+    splining: BTreeSet<ObjectGuid>,
+}
+
+impl ObjectManager {
+    pub fn new() -> Self {
+        Self {
+            objects: BTreeMap::new(),
+            passengers: BTreeMap::new(),
+            spatial_index: SpatialIndex::default(),
+            splining: BTreeSet::new(),
+        }
+    }
+
+    pub fn get(&self, guid: ObjectGuid) -> Option<&WorldObject> {
+        self.objects.get(&guid)
+    }
+
+    pub fn get_mut(&mut self, guid: ObjectGuid) -> Option<&mut WorldObject> {
+        self.objects.get_mut(&guid)
+    }
+
+    pub fn contains(&self, guid: ObjectGuid) -> bool {
+        self.objects.contains_key(&guid)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &WorldObject> {
+        self.objects.values()
+    }
+
+    pub fn get_by_type(&self, object_type: ObjectType) -> impl Iterator<Item = &WorldObject> {
+        self.objects.values().filter(move |object| object.object_type == object_type)
+    }
+
+    pub fn len(&self) -> usize {
+        self.objects.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.objects.is_empty()
+    }
+
+    pub fn remove(&mut self, guid: ObjectGuid) -> Option<ObjectEvent> {
+        self.set_transport(guid, None);
+        self.passengers.remove(&guid);
+        self.spatial_index.remove(guid);
+        self.splining.remove(&guid);
+        self.objects.remove(&guid).map(|object| ObjectEvent::Removed(guid, object.object_type))
+    }
+
+    pub fn clear(&mut self) {
+        self.objects.clear();
+        self.passengers.clear();
+        self.spatial_index.clear();
+        self.splining.clear();
+    }
+
+    pub fn get_passengers(&self, transport_guid: ObjectGuid) -> impl Iterator<Item = &WorldObject> {
+        self.passengers.get(&transport_guid)
+            .into_iter()
+            .flatten()
+            .filter_map(|guid| self.objects.get(guid))
+    }
+
+    // position of the object on transport is calculated from the current transport position
+    pub fn get_world_position(&self, guid: ObjectGuid) -> Option<Position> {
+        let object = self.objects.get(&guid)?;
+
+        let transport_position = object.transport.as_ref().and_then(|transport| {
+            let position = self.objects.get(&transport.guid)?.position?;
+            Some(transport.world_position(&position))
+        });
+
+        transport_position.or(object.position)
+    }
+
+    // sorted by distance, objects without known position are skipped
+    pub fn get_in_radius(&self, center: &Position, radius: f32) -> Vec<&WorldObject> {
+        let now = Instant::now();
+        let mut guids: BTreeSet<ObjectGuid> = self.spatial_index.query(center, radius).into_iter().collect();
+        guids.extend(self.splining.iter().copied());
+
+        let mut objects: Vec<(f32, &WorldObject)> = guids
+            .into_iter()
+            .filter_map(|guid| self.objects.get(&guid))
+            .filter_map(|object| Some((object.position_at(now)?.distance(center), object)))
+            .filter(|(distance, _)| *distance <= radius)
+            .collect();
+
+        objects.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        objects.into_iter().map(|(_, object)| object).collect()
+    }
+
+    pub fn get_in_radius_of(&self, guid: ObjectGuid, radius: f32) -> Vec<&WorldObject> {
+        let Some(center) = self.objects.get(&guid).and_then(|object| object.position_at(Instant::now())) else {
+            return vec![];
+        };
+
+        let mut objects = self.get_in_radius(&center, radius);
+        objects.retain(|object| object.guid != guid);
+        objects
+    }
+
+    pub fn get_nearest(
+        &self,
+        center: &Position,
+        object_type: ObjectType,
+        amount: usize,
+    ) -> Vec<&WorldObject> {
+        let mut radius = NEAREST_SEARCH_RADIUS;
+
+        loop {
+            let mut objects = self.get_in_radius(center, radius);
+            objects.retain(|object| object.object_type == object_type);
+
+            if objects.len() >= amount || radius >= MAX_SEARCH_RADIUS {
+                objects.truncate(amount);
+                return objects;
+            }
+
+            radius *= 2.0;
+        }
+    }
+
+    pub fn get_by_entry(&self, entry: u32) -> impl Iterator<Item = &WorldObject> {
+        self.objects.values().filter(move |object| {
+            object.fields.get(ObjectField::ENTRY) == Some(FieldValue::Integer(entry))
+        })
+    }
+
+    // None if any of objects is unknown or has no position
+    pub fn is_facing(&self, guid: ObjectGuid, target_guid: ObjectGuid, arc: f32) -> Option<bool> {
+        let now = Instant::now();
+        let position = self.objects.get(&guid)?.position_at(now)?;
+        let target_position = self.objects.get(&target_guid)?.position_at(now)?;
+
+        Some(position.is_facing(&target_position, arc))
+    }
+
+    // returns lifecycle events for every object affected by the block
+    pub fn apply_block(&mut self, parsed_block: &ParsedBlock) -> Vec<ObjectEvent> {
+        let mut events = Vec::new();
+
+        for guid in parsed_block.out_of_range_guids.iter() {
+            events.extend(self.remove(*guid));
+        }
+
+        let Some(guid) = parsed_block.guid else {
+            return events;
+        };
+
+        let object_type = ObjectType::from_fields(&parsed_block.get_update_fields());
+        // object can be created again without leaving the visibility range, old values are outdated then
+        let is_new = parsed_block.is_create() || !self.objects.contains_key(&guid);
+        let object = self.objects.entry(guid).or_insert_with(|| {
+            WorldObject::new(guid, object_type.unwrap_or_else(|| ObjectType::from_guid(guid)))
+        });
+
+        if is_new {
+            object.fields = UpdateFieldStore::new();
+        }
+
+        if let Some(object_type) = object_type {
+            object.object_type = object_type;
+        }
+
+        let mut changes = Vec::new();
+        for (index, old_value) in object.fields.merge(&parsed_block.raw_fields) {
+            if let Some(new_value) = object.fields.get(index) {
+                changes.push(FieldChange { index, old_value, new_value });
+            }
+        }
+
+        let mut moved_to = None;
+        let mut transport = None;
+        if let Some(movement_data) = &parsed_block.movement_data {
+            let position = movement_data.movement_info
+                .as_ref()
+                .map(|movement_info| movement_info.position)
+                .or(movement_data.position);
+
+            if let Some(position) = position {
+                if !is_new && !Self::is_same_position(object.position, position) {
+                    moved_to = Some(position);
+                }
+                object.position = Some(position);
+            }
+
+            if movement_data.movement_info.is_some() {
+                object.spline = movement_data.spline.clone();
+            }
+
+            object.movement_data = Some(movement_data.clone());
+
+            transport = Some(
+                movement_data.movement_info
+                    .as_ref()
+                    .and_then(|movement_info| movement_info.transport)
+                    .or(movement_data.transport)
+            );
+        }
+
+        let object_type = object.object_type;
+        self.index(guid);
+        if let Some(transport) = transport {
+            self.set_transport(guid, transport);
+        }
+
+        if is_new {
+            events.push(ObjectEvent::Appeared(guid, object_type));
+        } else {
+            if !changes.is_empty() {
+                events.push(ObjectEvent::Updated(guid, object_type, changes));
+            }
+
+            if let Some(position) = moved_to {
+                events.push(ObjectEvent::Moved(guid, object_type, position));
+            }
+        }
+
+        events
+    }
+
+    pub fn update_movement(&mut self, guid: ObjectGuid, movement_info: &MovementInfo) -> Option<ObjectEvent> {
+        if !self.objects.contains_key(&guid) {
+            return None;
+        }
+
+        self.set_transport(guid, movement_info.transport);
+        self.update_position(guid, movement_info.position)
+    }
+
+    pub fn update_position(&mut self, guid: ObjectGuid, position: Position) -> Option<ObjectEvent> {
+        let object = self.objects.get_mut(&guid)?;
+        let is_moved = !Self::is_same_position(object.position, position);
+        object.position = Some(position);
+        object.spline = None;
+        let object_type = object.object_type;
+        self.index(guid);
+
+        match is_moved {
+            true => Some(ObjectEvent::Moved(guid, object_type, position)),
+            false => None,
+        }
+    }
+
+    // spline is None when the object stops
+    pub fn start_spline(
+        &mut self,
+        guid: ObjectGuid,
+        start: Position,
+        spline: Option<Spline>,
+    ) -> Option<ObjectEvent> {
+        let object = self.objects.get_mut(&guid)?;
+        let orientation = object.position.map(|position| position.orientation).unwrap_or_default();
+        let position = Position::new(start.x, start.y, start.z, orientation);
+
+        let is_moved = !Self::is_same_position(object.position, position);
+        object.position = Some(position);
+        object.spline = spline;
+        let object_type = object.object_type;
+        self.index(guid);
+
+        match is_moved {
+            true => Some(ObjectEvent::Moved(guid, object_type, position)),
+            false => None,
+        }
+    }
+
+    fn index(&mut self, guid: ObjectGuid) {
+        let Some(object) = self.objects.get(&guid) else {
+            return;
+        };
+
+        self.spatial_index.update(guid, object.position);
+        match object.spline.is_some() {
+            true => self.splining.insert(guid),
+            false => self.splining.remove(&guid),
+        };
+    }
+
+    fn set_transport(&mut self, guid: ObjectGuid, transport: Option<TransportInfo>) {
+        let Some(object) = self.objects.get_mut(&guid) else {
+            return;
+        };
+
+        if let Some(previous) = object.transport.take() {
+            if let Some(passengers) = self.passengers.get_mut(&previous.guid) {
+                passengers.remove(&guid);
+                if passengers.is_empty() {
+                    self.passengers.remove(&previous.guid);
+                }
+            }
+        }
+
+        if let Some(transport) = transport {
+            self.passengers.entry(transport.guid).or_default().insert(guid);
+        }
+
+        object.transport = transport;
+    }
+
+    fn is_same_position(current: Option<Position>, position: Position) -> bool {
+        match current {
+            Some(current) => {
+                current.x == position.x
+                    && current.y == position.y
+                    && current.z == position.z
+                    && current.orientation == position.orientation
+            },
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::f32::consts::PI;
+    use std::time::{Duration, Instant};
+
+    use crate::primary::client::{FieldValue, ObjectField, Position, SplineFlags};
+    use crate::primary::parsers::movement_parser::types::TransportInfo;
+    use crate::primary::parsers::spline_parser::types::Spline;
+    use crate::primary::parsers::update_block_parser::types::{
+        encode_update_fields, MovementData, ObjectTypeMask, ParsedBlock,
+    };
+    use crate::primary::shared::storage::objects::{ObjectEvent, ObjectManager, ObjectType, WorldObject};
+    use crate::primary::types::ObjectGuid;
+
+    #[test]
+    fn test_apply_blocks() {
+        let mut objects = ObjectManager::new();
+
+        let mut update_fields = BTreeMap::new();
+        update_fields.insert(ObjectField::TYPE, FieldValue::Integer(ObjectTypeMask::IS_UNIT));
+        let events = objects.apply_block(&ParsedBlock {
+            guid: Some(ObjectGuid::new(1)),
+            raw_fields: encode_update_fields(&update_fields),
+            movement_data: Some(MovementData {
+                position: Some(Position::new(1.0, 2.0, 3.0, 0.0)),
+                ..MovementData::new()
+            }),
+            ..ParsedBlock::new()
+        });
+
+        assert!(matches!(events[..], [ObjectEvent::Appeared(guid, ObjectType::Unit)] if guid.raw() == 1));
+
+        let mut update_fields = BTreeMap::new();
+        update_fields.insert(ObjectField::TYPE, FieldValue::Integer(
+            ObjectTypeMask::TYPEMASK_OBJECT | ObjectTypeMask::TYPEMASK_GAMEOBJECT
+        ));
+        objects.apply_block(&ParsedBlock {
+            guid: Some(ObjectGuid::new(2)),
+            raw_fields: encode_update_fields(&update_fields),
+            ..ParsedBlock::new()
+        });
+
+        assert_eq!(objects.len(), 2);
+        assert_eq!(objects.get(ObjectGuid::new(1)).unwrap().object_type, ObjectType::Unit);
+        assert_eq!(objects.get(ObjectGuid::new(1)).unwrap().position.unwrap().z, 3.0);
+        assert_eq!(objects.get_by_type(ObjectType::GameObject).count(), 1);
+
+        let mut update_fields = BTreeMap::new();
+        update_fields.insert(ObjectField::ENTRY, FieldValue::Integer(42));
+        let events = objects.apply_block(&ParsedBlock {
+            guid: Some(ObjectGuid::new(1)),
+            raw_fields: encode_update_fields(&update_fields),
+            ..ParsedBlock::new()
+        });
+
+        let unit = objects.get(ObjectGuid::new(1)).unwrap();
+        assert_eq!(unit.object_type, ObjectType::Unit);
+        assert_eq!(unit.fields.len(), 2);
+        assert!(unit.position.is_some());
+
+        match &events[..] {
+            [ObjectEvent::Updated(guid, ObjectType::Unit, changes)] if guid.raw() == 1 => {
+                assert_eq!(changes.len(), 1);
+                assert_eq!(changes[0].index, ObjectField::ENTRY);
+                assert_eq!(changes[0].old_value, None);
+                assert_eq!(changes[0].new_value, FieldValue::Integer(42));
+            },
+            _ => panic!("unexpected events: {:?}", events),
+        }
+
+        let event = objects.update_position(ObjectGuid::new(1), Position::new(2.0, 2.0, 3.0, 0.0));
+        assert!(matches!(event, Some(ObjectEvent::Moved(guid, ObjectType::Unit, _)) if guid.raw() == 1));
+        assert!(objects.update_position(ObjectGuid::new(1), Position::new(2.0, 2.0, 3.0, 0.0)).is_none());
+
+        let events = objects.apply_block(
+            &ParsedBlock { out_of_range_guids: vec![1, 2, 3].into_iter().map(ObjectGuid::new).collect(), ..ParsedBlock::new() }
+        );
+        assert!(objects.is_empty());
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[1], ObjectEvent::Removed(guid, ObjectType::GameObject) if guid.raw() == 2));
+    }
+
+    #[test]
+    fn test_create_tracked_object() {
+        let mut objects = ObjectManager::new();
+
+        let create_block = |entry: Option<u32>| {
+            let mut update_fields = BTreeMap::new();
+            update_fields.insert(ObjectField::TYPE, FieldValue::Integer(ObjectTypeMask::IS_UNIT));
+            if let Some(entry) = entry {
+                update_fields.insert(ObjectField::ENTRY, FieldValue::Integer(entry));
+            }
+
+            ParsedBlock {
+                guid: Some(ObjectGuid::new(1)),
+                raw_fields: encode_update_fields(&update_fields),
+                movement_data: Some(MovementData {
+                    position: Some(Position::new(1.0, 2.0, 3.0, 0.0)),
+                    ..MovementData::new()
+                }),
+                ..ParsedBlock::new()
+            }
+        };
+
+        objects.apply_block(&create_block(Some(42)));
+        let events = objects.apply_block(&create_block(None));
+
+        assert!(matches!(events[..], [ObjectEvent::Appeared(guid, ObjectType::Unit)] if guid.raw() == 1));
+        let unit = objects.get(ObjectGuid::new(1)).unwrap();
+        assert_eq!(unit.fields.len(), 1);
+        assert_eq!(unit.fields.get(ObjectField::ENTRY), None);
+    }
+
+    #[test]
+    fn test_spatial_queries() {
+        let mut objects = ObjectManager::new();
+
+        for (guid, x, type_mask) in [
+            (1, 0.0, ObjectTypeMask::IS_PLAYER),
+            (2, 10.0, ObjectTypeMask::IS_UNIT),
+            (3, 30.0, ObjectTypeMask::IS_UNIT),
+            (4, 120.0, ObjectTypeMask::IS_UNIT),
+            (5, -5.0, ObjectTypeMask::IS_UNIT),
+        ] {
+            let mut update_fields = BTreeMap::new();
+            update_fields.insert(ObjectField::TYPE, FieldValue::Integer(type_mask));
+            update_fields.insert(ObjectField::ENTRY, FieldValue::Integer(guid as u32 % 2));
+
+            objects.apply_block(&ParsedBlock {
+                guid: Some(ObjectGuid::new(guid)),
+                raw_fields: encode_update_fields(&update_fields),
+                movement_data: Some(MovementData {
+                    position: Some(Position::new(x, 0.0, 0.0, 0.0)),
+                    ..MovementData::new()
+                }),
+                ..ParsedBlock::new()
+            });
+        }
+
+        let guids = |objects: Vec<&WorldObject>| objects.iter().map(|o| o.guid.raw()).collect::<Vec<u64>>();
+
+        assert_eq!(guids(objects.get_in_radius_of(ObjectGuid::new(1), 50.0)), vec![5, 2, 3]);
+        assert_eq!(guids(objects.get_nearest(&Position::new(100.0, 0.0, 0.0, 0.0), ObjectType::Unit, 2)), vec![4, 3]);
+        assert_eq!(objects.get_by_entry(1).count(), 3);
+        assert_eq!(objects.is_facing(ObjectGuid::new(1), ObjectGuid::new(2), PI / 2.0), Some(true));
+        assert_eq!(objects.is_facing(ObjectGuid::new(1), ObjectGuid::new(5), PI / 2.0), Some(false));
+
+        objects.update_position(ObjectGuid::new(4), Position::new(15.0, 0.0, 0.0, 0.0));
+        assert_eq!(guids(objects.get_in_radius_of(ObjectGuid::new(1), 20.0)), vec![5, 2, 4]);
+
+        objects.remove(ObjectGuid::new(2));
+        assert_eq!(guids(objects.get_in_radius_of(ObjectGuid::new(1), 20.0)), vec![5, 4]);
+    }
+
+    #[test]
+    fn test_spatial_queries_with_spline() {
+        let mut objects = ObjectManager::new();
+
+        for (guid, x) in [(1, 0.0), (2, 200.0)] {
+            let mut update_fields = BTreeMap::new();
+            update_fields.insert(ObjectField::TYPE, FieldValue::Integer(ObjectTypeMask::IS_UNIT));
+
+            objects.apply_block(&ParsedBlock {
+                guid: Some(ObjectGuid::new(guid)),
+                raw_fields: encode_update_fields(&update_fields),
+                movement_data: Some(MovementData {
+                    position: Some(Position::new(x, 0.0, 0.0, 0.0)),
+                    ..MovementData::new()
+                }),
+                ..ParsedBlock::new()
+            });
+        }
+
+        // creature started at 200 yards and is already half way to the player
+        let start = Position::new(200.0, 0.0, 0.0, 0.0);
+        let mut spline = Spline::new(1, SplineFlags::empty(), 10000, vec![start, Position::new(0.0, 0.0, 0.0, 0.0)]);
+        spline.received_at = Instant::now() - Duration::from_millis(5000);
+        objects.start_spline(ObjectGuid::new(2), start, Some(spline));
+
+        let guids = |objects: Vec<&WorldObject>| objects.iter().map(|o| o.guid.raw()).collect::<Vec<u64>>();
+
+        assert_eq!(guids(objects.get_in_radius_of(ObjectGuid::new(1), 120.0)), vec![2]);
+        assert!(objects.get_in_radius(&start, 50.0).is_empty());
+        assert_eq!(
+            guids(objects.get_nearest(&Position::new(100.0, 0.0, 0.0, 0.0), ObjectType::Unit, 1)),
+            vec![2],
+        );
+        assert_eq!(objects.is_facing(ObjectGuid::new(1), ObjectGuid::new(2), PI / 2.0), Some(true));
+
+        objects.start_spline(ObjectGuid::new(2), Position::new(150.0, 0.0, 0.0, 0.0), None);
+        assert!(objects.get_in_radius_of(ObjectGuid::new(1), 120.0).is_empty());
+    }
+
+    #[test]
+    fn test_transport_world_position() {
+        let mut objects = ObjectManager::new();
+
+        objects.apply_block(&ParsedBlock {
+            guid: Some(ObjectGuid::new(10)),
+            movement_data: Some(MovementData {
+                position: Some(Position::new(100.0, 200.0, 10.0, std::f32::consts::FRAC_PI_2)),
+                ..MovementData::new()
+            }),
+            ..ParsedBlock::new()
+        });
+
+        objects.apply_block(&ParsedBlock {
+            guid: Some(ObjectGuid::new(11)),
+            movement_data: Some(MovementData {
+                position: Some(Position::new(0.0, 0.0, 0.0, 0.0)),
+                transport: Some(TransportInfo {
+                    guid: ObjectGuid::new(10),
+                    offset: Position::new(2.0, 0.0, 1.0, 0.0),
+                    ..TransportInfo::default()
+                }),
+                ..MovementData::new()
+            }),
+            ..ParsedBlock::new()
+        });
+
+        let position = objects.get_world_position(ObjectGuid::new(11)).unwrap();
+        assert!((position.x - 100.0).abs() < 1e-4);
+        assert!((position.y - 202.0).abs() < 1e-4);
+        assert_eq!(position.z, 11.0);
+        assert_eq!(objects.get_passengers(ObjectGuid::new(10)).count(), 1);
+
+        objects.remove(ObjectGuid::new(11));
+        assert_eq!(objects.get_passengers(ObjectGuid::new(10)).count(), 0);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,96 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::primary::client::Position;
+use crate::primary::types::ObjectGuid;
+
+// visibility distance is about 100 yards, so every query touches only few cells
+const DEFAULT_CELL_SIZE: f32 = 50.0;
+
+type Cell = (i32, i32);
+
+// uniform 2D grid, picks candidates for radius queries
+#[derive(Debug)]
+pub struct SpatialIndex {
+    cell_size: f32,
+    cells: HashMap<Cell, BTreeSet<ObjectGuid>>,
+    locations: HashMap<ObjectGuid, Cell>,
+}
+
+impl SpatialIndex {
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+            locations: HashMap::new(),
+        }
+    }
+
+    pub fn update(&mut self, guid: ObjectGuid, position: Option<Position>) {
+        let Some(position) = position else {
+            self.remove(guid);
+            return;
+        };
+
+        let cell = self.get_cell(position.x, position.y);
+        if self.locations.get(&guid) == Some(&cell) {
+            return;
+        }
+
+        self.remove(guid);
+        self.cells.entry(cell).or_default().insert(guid);
+        self.locations.insert(guid, cell);
+    }
+
+    pub fn remove(&mut self, guid: ObjectGuid) {
+        if let Some(cell) = self.locations.remove(&guid) {
+            if let Some(guids) = self.cells.get_mut(&cell) {
+                guids.remove(&guid);
+                if guids.is_empty() {
+                    self.cells.remove(&cell);
+                }
+            }
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.locations.clear();
+    }
+
+    // returns guids from all cells intersecting the square around the circle,
+    // exact distance should be checked by the caller
+    pub fn query(&self, center: &Position, radius: f32) -> Vec<ObjectGuid> {
+        let (min_x, min_y) = self.get_cell(center.x - radius, center.y - radius);
+        let (max_x, max_y) = self.get_cell(center.x + radius, center.y + radius);
+
+        // big radius covers more cells than exist, so it is cheaper to check the cells directly
+        let cells_amount = (max_x - min_x + 1) as i64 * (max_y - min_y + 1) as i64;
+        if cells_amount > self.cells.len() as i64 {
+            return self.cells.iter()
+                .filter(|((x, y), _)| (min_x..=max_x).contains(x) && (min_y..=max_y).contains(y))
+                .flat_map(|(_, guids)| guids.iter().copied())
+                .collect();
+        }
+
+        let mut guids = Vec::new();
+        for x in min_x..=max_x {
+            for y in min_y..=max_y {
+                if let Some(cell) = self.cells.get(&(x, y)) {
+                    guids.extend(cell.iter().copied());
+                }
+            }
+        }
+
+        guids
+    }
+
+    fn get_cell(&self, x: f32, y: f32) -> Cell {
+        ((x / self.cell_size).floor() as i32, (y / self.cell_size).floor() as i32)
+    }
+}
+
+impl Default for SpatialIndex {
+    fn default() -> Self {
+        Self::new(DEFAULT_CELL_SIZE)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+pub use crate::primary::parsers::map_parser::MapParser;
+pub use crate::primary::parsers::map_parser::types::{GridLiquid, GridMap, HeightMap, LiquidFlags, LiquidInfo};
+
+use crate::primary::errors::TerrainError;
+
+use crate::primary::client::Position;
+
+// distance between points which are added along the path to follow the ground
+const GROUND_STEP: f32 = 2.0;
+// bigger difference means the path goes over a bridge or through a building, where grids know nothing
+const MAX_GROUND_OFFSET: f32 = 10.0;
+
+// (map id, grid x, grid y)
+type GridKey = (u32, u32, u32);
+
+// keeps loaded grids of .map files, missing grids are cached as well to not touch disk again
+pub struct Terrain {
+    directory: Option<PathBuf>,
+    grids: HashMap<GridKey, Option<Arc<GridMap>>>,
+}
+
+impl Terrain {
+    pub fn new(directory: Option<PathBuf>) -> Self {
+        Self {
+            directory,
+            grids: HashMap::new(),
+        }
+    }
+
+    pub fn loaded_grids_amount(&self) -> usize {
+        self.grids.values().filter(|grid| grid.is_some()).count()
+    }
+
+    pub fn height_at(&mut self, map_id: u32, x: f32, y: f32) -> Result<Option<f32>, TerrainError> {
+        Ok(self.get_grid(map_id, x, y)?.map(|grid| grid.height_at(x, y)))
+    }
+
+    pub fn liquid_at(&mut self, map_id: u32, x: f32, y: f32) -> Result<Option<LiquidInfo>, TerrainError> {
+        Ok(self.get_grid(map_id, x, y)?.and_then(|grid| grid.liquid_at(x, y)))
+    }
+
+    pub fn area_id_at(&mut self, map_id: u32, x: f32, y: f32) -> Result<Option<u16>, TerrainError> {
+        Ok(self.get_grid(map_id, x, y)?.map(|grid| grid.area_id_at(x, y)))
+    }
+
+    // splits the path into short segments and puts them on the ground, so heartbeats do not float or dig in
+    pub fn snap_path(&mut self, map_id: u32, start: &Position, waypoints: Vec<Position>) -> Vec<Position> {
+        let mut path = Vec::with_capacity(waypoints.len());
+        let mut previous = *start;
+
+        for waypoint in waypoints {
+            let steps = (previous.distance_2d(&waypoint) / GROUND_STEP).ceil().max(1.0) as usize;
+            let orientation = previous.angle_to(&waypoint);
+
+            for step in 1..steps {
+                let t = step as f32 / steps as f32;
+                let mut point = Position {
+                    x: previous.x + (waypoint.x - previous.x) * t,
+                    y: previous.y + (waypoint.y - previous.y) * t,
+                    z: previous.z + (waypoint.z - previous.z) * t,
+                    orientation,
+                };
+                point.z = self.ground_z(map_id, &point);
+                path.push(point);
+            }
+
+            let point = Position { z: self.ground_z(map_id, &waypoint), ..waypoint };
+            path.push(point);
+            previous = point;
+        }
+
+        path
+    }
+
+    fn ground_z(&mut self, map_id: u32, point: &Position) -> f32 {
+        match self.height_at(map_id, point.x, point.y) {
+            Ok(Some(z)) if (z - point.z).abs() <= MAX_GROUND_OFFSET => z,
+            _ => point.z,
+        }
+    }
+
+    fn get_grid(&mut self, map_id: u32, x: f32, y: f32) -> Result<Option<Arc<GridMap>>, TerrainError> {
+        let directory = self.directory.as_ref().ok_or(TerrainError::NotConfigured)?;
+        let Some((grid_x, grid_y)) = GridMap::get_grid_coords(x, y) else {
+            return Ok(None);
+        };
+
+        let key = (map_id, grid_x, grid_y);
+        if let Some(grid) = self.grids.get(&key) {
+            return Ok(grid.clone());
+        }
+
+        let path = directory.join(format!("{:03}{:02}{:02}.map", map_id, grid_x, grid_y));
+        let grid = match path.exists() {
+            true => {
+                let file = File::open(&path)
+                    .map_err(|err| TerrainError::CannotRead(err, path.display().to_string()))?;
+                let grid = MapParser::parse(&mut BufReader::new(file))
+                    .map_err(|err| TerrainError::CannotRead(err, path.display().to_string()))?;
+
+                Some(Arc::new(grid))
+            },
+            false => None,
+        };
+
+        self.grids.insert(key, grid.clone());
+
+        Ok(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use tempdir::TempDir;
+
+    use crate::primary::client::Position;
+    use crate::primary::errors::TerrainError;
+    use crate::primary::parsers::map_parser::types::{MAP_RESOLUTION, SIZE_OF_GRIDS};
+    use crate::primary::terrain::{GridLiquid, GridMap, HeightMap, LiquidFlags, MapParser, Terrain};
+
+    #[test]
+    fn test_terrain_queries() {
+        let temp_dir = TempDir::new("_maps").unwrap();
+
+        // slope which rises by 1 per height cell along x axis of the grid
+        let v9 = (0..=MAP_RESOLUTION)
+            .flat_map(|i| (0..=MAP_RESOLUTION).map(move |_| i as f32))
+            .collect();
+        let v8 = (0..MAP_RESOLUTION)
+            .flat_map(|i| (0..MAP_RESOLUTION).map(move |_| i as f32 + 0.5))
+            .collect();
+
+        let grid = GridMap {
+            area_id: 1,
+            area_map: Some((0..256).collect()),
+            height: 0.0,
+            height_map: Some(HeightMap { v9, v8 }),
+            // water covers only the middle of the grid
+            liquid: Some(GridLiquid {
+                flags: LiquidFlags::WATER,
+                level: 3.0,
+                offset_x: 32,
+                offset_y: 32,
+                width: 64,
+                height: 64,
+                ..GridLiquid::default()
+            }),
+        };
+
+        let mut buffer = Vec::new();
+        MapParser::write(&mut buffer, &grid).unwrap();
+        fs::write(temp_dir.path().join("0013232.map"), buffer).unwrap();
+
+        let mut terrain = Terrain::new(Some(temp_dir.path().to_path_buf()));
+
+        // middle of the grid, a quarter of the cell further along x axis
+        let x = -SIZE_OF_GRIDS * (0.5 + 0.25 / MAP_RESOLUTION as f32);
+        let y = -SIZE_OF_GRIDS * 0.5;
+
+        let height = terrain.height_at(1, x, y).unwrap().unwrap();
+        assert!((height - 64.25).abs() < 0.01);
+        assert_eq!(terrain.area_id_at(1, x, y).unwrap(), Some(8 * 16 + 8));
+
+        let liquid = terrain.liquid_at(1, x, y).unwrap().unwrap();
+        assert_eq!(liquid.flags, LiquidFlags::WATER);
+        assert!(liquid.is_under_surface(2.0));
+        assert!(terrain.liquid_at(1, -SIZE_OF_GRIDS * 0.1, y).unwrap().is_none());
+
+        assert_eq!(terrain.height_at(1, 100.0, 100.0).unwrap(), None);
+        assert_eq!(terrain.loaded_grids_amount(), 1);
+
+        assert!(matches!(Terrain::new(None).height_at(1, x, y), Err(TerrainError::NotConfigured)));
+
+        // slope rises by 1 per cell, which is a bit longer than GROUND_STEP
+        let start = Position::new(x, y, height, 0.0);
+        let path = terrain.snap_path(1, &start, vec![Position::new(x - 20.0, y, 70.0, 1.0)]);
+        assert!(path.len() > 1);
+        for point in &path {
+            let height = terrain.height_at(1, point.x, point.y).unwrap().unwrap();
+            assert!((point.z - height).abs() < 0.01);
+        }
+        assert_eq!(path.last().unwrap().orientation, 1.0);
+
+        // too far from the ground, probably a bridge
+        let path = terrain.snap_path(1, &start, vec![Position::new(x, y + 1.0, 100.0, 0.0)]);
+        assert_eq!(path.last().unwrap().z, 100.0);
+
+        temp_dir.close().unwrap();
+    }
+}
\ No newline at end of file
@@ -1,12 +1,15 @@
 use std::io::{BufRead, Write};
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 
-use crate::primary::client::{CooldownInfo, Player, Realm, Spell};
+use crate::primary::client::{CooldownInfo, InstanceBind, Player, Realm, Spell};
 use crate::primary::errors::FieldError;
 use crate::primary::parsers::movement_parser::MovementParser;
 use crate::primary::parsers::movement_parser::types::MovementInfo;
 use crate::primary::parsers::position_parser::types::Position;
+use crate::primary::parsers::spline_parser::SplineParser;
+use crate::primary::parsers::spline_parser::types::MonsterMove;
 use crate::primary::parsers::update_block_parser::{UpdateBlocksParser, types::ParsedBlock};
+use crate::primary::types::{ObjectGuid, TerminatedString};
 
 pub trait BinaryConverter {
     fn write_into(&mut self, buffer: &mut Vec<u8>) -> Result<(), FieldError>;
@@ -150,8 +153,9 @@ impl<const N: usize> BinaryConverter for [u8; N] {
 }
 
 impl BinaryConverter for Vec<ParsedBlock> {
-    fn write_into(&mut self, _buffer: &mut Vec<u8>) -> Result<(), FieldError> {
-        todo!()
+    fn write_into(&mut self, buffer: &mut Vec<u8>) -> Result<(), FieldError> {
+        UpdateBlocksParser::write(buffer, self)
+            .map_err(|e| FieldError::CannotWrite(e, "Vec<ParsedBlock>".to_string()))
     }
 
     fn read_from<R: BufRead>(mut reader: R) -> Result<Self, FieldError> {
@@ -162,8 +166,24 @@ impl BinaryConverter for Vec<ParsedBlock> {
 }
 
 impl BinaryConverter for Vec<Realm> {
-    fn write_into(&mut self, _buffer: &mut Vec<u8>) -> Result<(), FieldError> {
-        todo!()
+    fn write_into(&mut self, buffer: &mut Vec<u8>) -> Result<(), FieldError> {
+        let label = "Vec<Realm>";
+
+        buffer.write_i16::<LittleEndian>(self.len() as i16)
+            .map_err(|e| FieldError::CannotWrite(e, format!("realms_count:i16 ({})", label)))?;
+        for realm in self.iter_mut() {
+            realm.icon.write_into(buffer)?;
+            realm.lock.write_into(buffer)?;
+            realm.flags.write_into(buffer)?;
+            TerminatedString::from(realm.name.as_str()).write_into(buffer)?;
+            TerminatedString::from(realm.address.as_str()).write_into(buffer)?;
+            realm.population.write_into(buffer)?;
+            realm.characters.write_into(buffer)?;
+            realm.timezone.write_into(buffer)?;
+            realm.server_id.write_into(buffer)?;
+        }
+
+        Ok(())
     }
 
     fn read_from<R: BufRead>(mut reader: R) -> Result<Self, FieldError> {
@@ -215,8 +235,53 @@ impl BinaryConverter for Vec<Realm> {
 }
 
 impl BinaryConverter for Vec<Player> {
-    fn write_into(&mut self, _buffer: &mut Vec<u8>) -> Result<(), FieldError> {
-        todo!()
+    // appearance, location, guild, pet and inventory are not stored in Player, so zeroes are written
+    fn write_into(&mut self, buffer: &mut Vec<u8>) -> Result<(), FieldError> {
+        let label = "Vec<Character>";
+
+        buffer.write_u8(self.len() as u8)
+            .map_err(|e| FieldError::CannotWrite(e, format!("characters_count:u8 ({})", label)))?;
+        for character in self.iter_mut() {
+            let mut position = character.position.unwrap_or_default();
+
+            character.guid.write_into(buffer)?;
+            TerminatedString::from(character.name.as_str()).write_into(buffer)?;
+            character.race.write_into(buffer)?;
+            character.class.write_into(buffer)?;
+            character.gender.write_into(buffer)?;
+
+            // skin, face, hair_style, hair_color, facial_hair
+            [0u8; 5].write_into(buffer)?;
+            character.level.write_into(buffer)?;
+
+            // zone_id
+            0u32.write_into(buffer)?;
+            character.map_id.write_into(buffer)?;
+
+            position.x.write_into(buffer)?;
+            position.y.write_into(buffer)?;
+            position.z.write_into(buffer)?;
+
+            // guild_id, char_flags, char_customize_flags, first_login
+            0u32.write_into(buffer)?;
+            0u32.write_into(buffer)?;
+            0u32.write_into(buffer)?;
+            0u8.write_into(buffer)?;
+
+            // pet_display_id, pet_level, pet_family
+            0u32.write_into(buffer)?;
+            0u32.write_into(buffer)?;
+            0u32.write_into(buffer)?;
+
+            // inventory
+            for _ in 0..23 {
+                0u32.write_into(buffer)?;
+                0u8.write_into(buffer)?;
+                0u32.write_into(buffer)?;
+            }
+        }
+
+        Ok(())
     }
 
     fn read_from<R: BufRead>(mut reader: R) -> Result<Self, FieldError> {
@@ -259,7 +324,7 @@ impl BinaryConverter for Vec<Player> {
 
             let _zone_id = reader.read_u32::<LittleEndian>()
                 .map_err(|e| FieldError::CannotRead(e, format!("zone_id:u32 ({})", label)))?;
-            let _map_id = reader.read_u32::<LittleEndian>()
+            let map_id = reader.read_u32::<LittleEndian>()
                 .map_err(|e| FieldError::CannotRead(e, format!("map_id:u32 ({})", label)))?;
 
             let x = reader.read_f32::<LittleEndian>()
@@ -297,7 +362,7 @@ impl BinaryConverter for Vec<Player> {
             }
 
             characters.push(Player {
-                guid,
+                guid: ObjectGuid::new(guid),
                 name,
                 race,
                 class,
@@ -306,6 +371,7 @@ impl BinaryConverter for Vec<Player> {
                 fields: Default::default(),
                 movement_speed: Default::default(),
                 position: Some(Position::new(x, y, z, 0.0)),
+                map_id,
             });
         }
 
@@ -314,8 +380,9 @@ impl BinaryConverter for Vec<Player> {
 }
 
 impl BinaryConverter for MovementInfo {
-    fn write_into(&mut self, _buffer: &mut Vec<u8>) -> Result<(), FieldError> {
-        todo!()
+    fn write_into(&mut self, buffer: &mut Vec<u8>) -> Result<(), FieldError> {
+        MovementParser::write(buffer, self)
+            .map_err(|e| FieldError::CannotWrite(e, "MovementInfo".to_string()))
     }
 
     fn read_from<R: BufRead>(mut reader: R) -> Result<Self, FieldError> where Self: Sized {
@@ -324,9 +391,31 @@ impl BinaryConverter for MovementInfo {
     }
 }
 
+impl BinaryConverter for MonsterMove {
+    fn write_into(&mut self, buffer: &mut Vec<u8>) -> Result<(), FieldError> {
+        SplineParser::write_monster_move(buffer, self)
+            .map_err(|e| FieldError::CannotWrite(e, "MonsterMove".to_string()))
+    }
+
+    fn read_from<R: BufRead>(mut reader: R) -> Result<Self, FieldError> where Self: Sized {
+        SplineParser::parse_monster_move(&mut reader)
+            .map_err(|e| FieldError::CannotRead(e, "MonsterMove".to_string()))
+    }
+}
+
 impl BinaryConverter for Vec<Spell> {
-    fn write_into(&mut self, _buffer: &mut Vec<u8>) -> Result<(), FieldError> {
-        todo!()
+    fn write_into(&mut self, buffer: &mut Vec<u8>) -> Result<(), FieldError> {
+        let label = "Vec<Spell>";
+
+        buffer.write_u16::<LittleEndian>(self.len() as u16)
+            .map_err(|e| FieldError::CannotWrite(e, format!("spell_count:u16 ({})", label)))?;
+        for spell in self.iter_mut() {
+            spell.spell_id.write_into(buffer)?;
+            buffer.write_u16::<LittleEndian>(0)
+                .map_err(|e| FieldError::CannotWrite(e, format!("unknown:u16 ({})", label)))?;
+        }
+
+        Ok(())
     }
 
     fn read_from<R: BufRead>(mut reader: R) -> Result<Self, FieldError> where Self: Sized {
@@ -351,8 +440,20 @@ impl BinaryConverter for Vec<Spell> {
 }
 
 impl BinaryConverter for Vec<CooldownInfo> {
-    fn write_into(&mut self, _buffer: &mut Vec<u8>) -> Result<(), FieldError> {
-        todo!()
+    fn write_into(&mut self, buffer: &mut Vec<u8>) -> Result<(), FieldError> {
+        let label = "Vec<CooldownInfo>";
+
+        buffer.write_u16::<LittleEndian>(self.len() as u16)
+            .map_err(|e| FieldError::CannotWrite(e, format!("cooldown_count:u16 ({})", label)))?;
+        for cooldown in self.iter_mut() {
+            cooldown.spell_id.write_into(buffer)?;
+            cooldown.item_id.write_into(buffer)?;
+            cooldown.spell_category.write_into(buffer)?;
+            cooldown.cooldown_duration.write_into(buffer)?;
+            cooldown.cooldown_category.write_into(buffer)?;
+        }
+
+        Ok(())
     }
 
     fn read_from<R: BufRead>(mut reader: R) -> Result<Self, FieldError> where Self: Sized {
@@ -386,12 +487,289 @@ impl BinaryConverter for Vec<CooldownInfo> {
     }
 }
 
+impl BinaryConverter for Vec<InstanceBind> {
+    fn write_into(&mut self, buffer: &mut Vec<u8>) -> Result<(), FieldError> {
+        let label = "Vec<InstanceBind>";
+
+        buffer.write_u32::<LittleEndian>(self.len() as u32)
+            .map_err(|e| FieldError::CannotWrite(e, format!("bind_count:u32 ({})", label)))?;
+        for bind in self.iter_mut() {
+            bind.map_id.write_into(buffer)?;
+            bind.difficulty.write_into(buffer)?;
+            bind.instance_id.write_into(buffer)?;
+            (bind.locked as u8).write_into(buffer)?;
+            (bind.extended as u8).write_into(buffer)?;
+            bind.reset_time.write_into(buffer)?;
+        }
+
+        Ok(())
+    }
+
+    fn read_from<R: BufRead>(mut reader: R) -> Result<Self, FieldError> where Self: Sized {
+        let mut binds = Vec::new();
+        let label = "Vec<InstanceBind>";
+
+        let bind_count = reader.read_u32::<LittleEndian>()
+            .map_err(|e| FieldError::CannotRead(e, format!("bind_count:u32 ({})", label)))?;
+        for _ in 0..bind_count {
+            let map_id = reader.read_u32::<LittleEndian>()
+                .map_err(|e| FieldError::CannotRead(e, format!("map_id:u32 ({})", label)))?;
+            let difficulty = reader.read_u32::<LittleEndian>()
+                .map_err(|e| FieldError::CannotRead(e, format!("difficulty:u32 ({})", label)))?;
+            let instance_id = reader.read_u64::<LittleEndian>()
+                .map_err(|e| FieldError::CannotRead(e, format!("instance_id:u64 ({})", label)))?;
+            let locked = reader.read_u8()
+                .map_err(|e| FieldError::CannotRead(e, format!("locked:u8 ({})", label)))?;
+            let extended = reader.read_u8()
+                .map_err(|e| FieldError::CannotRead(e, format!("extended:u8 ({})", label)))?;
+            let reset_time = reader.read_u32::<LittleEndian>()
+                .map_err(|e| FieldError::CannotRead(e, format!("reset_time:u32 ({})", label)))?;
+
+            binds.push(InstanceBind {
+                map_id,
+                difficulty,
+                instance_id,
+                locked: locked != 0,
+                extended: extended != 0,
+                reset_time,
+            });
+        }
+
+        Ok(binds)
+    }
+}
+
 impl BinaryConverter for Vec<u8> {
     fn write_into(&mut self, buffer: &mut Vec<u8>) -> Result<(), FieldError> {
         buffer.write_all(self).map_err(|e| FieldError::CannotWrite(e, "Vec<u8>".to_string()))
     }
 
-    fn read_from<R: BufRead>(_reader: R) -> Result<Self, FieldError> where Self: Sized {
-        todo!()
+    fn read_from<R: BufRead>(mut reader: R) -> Result<Self, FieldError> where Self: Sized {
+        let mut internal_buf = vec![];
+        reader.read_to_end(&mut internal_buf)
+            .map_err(|e| FieldError::CannotRead(e, "Vec<u8>".to_string()))?;
+        Ok(internal_buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::io::Cursor;
+    use serde::Serialize;
+
+    use crate::primary::client::{
+        CooldownInfo, FieldValue, MovementFlags, MovementFlagsExtra, ObjectField, Player,
+        PlayerField, Position, Realm, Spell, Spline, SplineFacing, SplineFlags, UnitField,
+    };
+    use crate::primary::parsers::movement_parser::types::{JumpInfo, MovementInfo, TransportInfo};
+    use crate::primary::parsers::update_block_parser::types::{
+        encode_update_fields, MovementData, ParsedBlock,
+    };
+    use crate::primary::traits::binary_converter::BinaryConverter;
+    use crate::primary::types::ObjectGuid;
+
+    fn assert_round_trip<T: BinaryConverter + Serialize>(mut origin: T) {
+        let mut buffer = Vec::new();
+        origin.write_into(&mut buffer).expect("Cannot write");
+
+        let mut reader = Cursor::new(buffer.clone());
+        let restored = T::read_from(&mut reader).expect("Cannot read");
+        assert_eq!(reader.position() as usize, buffer.len());
+
+        assert_eq!(
+            serde_json::to_string(&origin).unwrap(),
+            serde_json::to_string(&restored).unwrap(),
+        );
+    }
+
+    fn get_movement_info(movement_flags: MovementFlags) -> MovementInfo {
+        // jump info is only sent while jumping
+        let jump_info = if movement_flags.contains(MovementFlags::JUMPING) {
+            JumpInfo {
+                jump_vertical_speed: 7.5,
+                jump_sin_angle: 0.5,
+                jump_cos_angle: 0.25,
+                jump_horizontal_speed: 2.0,
+            }
+        } else {
+            JumpInfo::default()
+        };
+
+        let transport = movement_flags.contains(MovementFlags::TAXI).then(|| TransportInfo {
+            guid: ObjectGuid::new(0x1FC0_0000_0000_0010),
+            offset: Position::new(0.5, 1.5, 2.5, 3.0),
+            time: 100,
+            seat: 1,
+            interpolated_time: None,
+        });
+
+        MovementInfo {
+            movement_flags,
+            movement_flags_extra: MovementFlagsExtra::NONE,
+            time: 12345,
+            position: Position::new(1.5, -2.5, 3.0, 0.75),
+            fall_time: 10,
+            jump_info,
+            transport,
+        }
+    }
+
+    #[test]
+    fn test_realms_round_trip() {
+        assert_round_trip(vec![
+            Realm {
+                icon: 1,
+                lock: 0,
+                flags: 2,
+                name: "Test Realm".to_string(),
+                address: "127.0.0.1:8085".to_string(),
+                population: 0.5,
+                characters: 3,
+                timezone: 1,
+                server_id: 4,
+            },
+        ]);
+    }
+
+    #[test]
+    fn test_characters_round_trip() {
+        let mut player = Player::new(ObjectGuid::new(42), "Tentacli".to_string(), 1, 2, 0, 80);
+        player.position = Some(Position::new(10.0, 20.0, 30.0, 0.0));
+        player.map_id = 571;
+
+        assert_round_trip(vec![player]);
+    }
+
+    #[test]
+    fn test_movement_info_round_trip() {
+        assert_round_trip(get_movement_info(
+            MovementFlags::FORWARD | MovementFlags::JUMPING | MovementFlags::SWIMMING
+        ));
+    }
+
+    #[test]
+    fn test_spells_round_trip() {
+        assert_round_trip(vec![Spell { spell_id: 133 }, Spell { spell_id: 168 }]);
+        assert_round_trip(vec![
+            CooldownInfo {
+                spell_id: 133,
+                item_id: 5,
+                spell_category: 6,
+                cooldown_duration: 1500,
+                cooldown_category: 7,
+            },
+        ]);
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let mut buffer = Vec::new();
+        vec![1u8, 2, 3].write_into(&mut buffer).unwrap();
+
+        assert_eq!(Vec::<u8>::read_from(Cursor::new(buffer)).unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_update_blocks_round_trip() {
+        let mut update_fields = BTreeMap::new();
+        update_fields.insert(ObjectField::GUID, FieldValue::Long(0x0000_0001_0000_0042));
+        update_fields.insert(ObjectField::TYPE, FieldValue::Integer(0x19));
+        update_fields.insert(ObjectField::SCALE_X, FieldValue::Float(1.0));
+        update_fields.insert(UnitField::BYTES_0, FieldValue::Bytes(0x01020304));
+        update_fields.insert(UnitField::HEALTH, FieldValue::Integer(100));
+        update_fields.insert(UnitField::ATTACK_POWER_MODS, FieldValue::TwoShorts(3, 4));
+        update_fields.insert(PlayerField::VISIBLE_ITEM_1_ENCHANTMENT, FieldValue::TwoShorts(1, 2));
+
+        let mut movement_speed = BTreeMap::new();
+        for move_type in 0..9 {
+            movement_speed.insert(move_type, 2.5 + move_type as f32);
+        }
+
+        let create_block = ParsedBlock {
+            guid: Some(ObjectGuid::new(0x42)),
+            raw_fields: encode_update_fields(&update_fields),
+            movement_data: Some(MovementData {
+                movement_info: Some(get_movement_info(
+                    MovementFlags::FORWARD | MovementFlags::SPLINE_ENABLED
+                )),
+                position: None,
+                high_guid: None,
+                low_guid: Some(0x42),
+                target_guid: Some(ObjectGuid::new(0x43)),
+                movement_speed: movement_speed.clone(),
+                spline: Some(Spline {
+                    facing: SplineFacing::Angle(1.5),
+                    time_passed: 250,
+                    ..Spline::new(3, SplineFlags::WALKMODE | SplineFlags::FINAL_ANGLE, 1000, vec![
+                        Position::new(1.0, 2.0, 3.0, 0.0),
+                        Position::new(4.0, 5.0, 6.0, 0.0),
+                    ])
+                }),
+                transport: None,
+            }),
+            ..ParsedBlock::new()
+        };
+
+        let movement_block = ParsedBlock {
+            guid: Some(ObjectGuid::new(0x43)),
+            movement_data: Some(MovementData {
+                movement_info: Some(get_movement_info(MovementFlags::JUMPING | MovementFlags::TAXI)),
+                movement_speed,
+                ..MovementData::new()
+            }),
+            ..ParsedBlock::new()
+        };
+
+        let values_block = ParsedBlock {
+            guid: Some(ObjectGuid::new(0x44)),
+            raw_fields: encode_update_fields(&update_fields),
+            ..ParsedBlock::new()
+        };
+
+        let stationary_block = ParsedBlock {
+            guid: Some(ObjectGuid::new(0xF110_0000_0000_0048)),
+            raw_fields: encode_update_fields(&update_fields),
+            movement_data: Some(MovementData {
+                position: Some(Position::new(1.5, 2.5, 3.5, 0.5)),
+                ..MovementData::new()
+            }),
+            ..ParsedBlock::new()
+        };
+
+        let transport_passenger_block = ParsedBlock {
+            guid: Some(ObjectGuid::new(0xF110_0000_0000_0049)),
+            movement_data: Some(MovementData {
+                // orientation is sent only once, inside the offset
+                position: Some(Position::new(1.5, 2.5, 3.5, 3.0)),
+                transport: Some(TransportInfo {
+                    guid: ObjectGuid::new(0x1FC0_0000_0000_0010),
+                    offset: Position::new(0.5, 1.5, 2.5, 3.0),
+                    ..TransportInfo::default()
+                }),
+                ..MovementData::new()
+            }),
+            ..ParsedBlock::new()
+        };
+
+        let out_of_range_block = ParsedBlock {
+            out_of_range_guids: vec![ObjectGuid::new(0x45), ObjectGuid::new(0xF130_0000_0000_0046)],
+            ..ParsedBlock::new()
+        };
+
+        let near_objects_block = ParsedBlock {
+            near_object_guids: vec![ObjectGuid::new(0x47)],
+            ..ParsedBlock::new()
+        };
+
+        assert_round_trip(vec![
+            create_block,
+            movement_block,
+            values_block,
+            stationary_block,
+            transport_passenger_block,
+            out_of_range_block,
+            near_objects_block,
+        ]);
     }
-}
\ No newline at end of file
+}
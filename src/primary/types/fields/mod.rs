@@ -1,5 +1,7 @@
+mod object_guid;
 mod packed_guid;
 mod terminated_string;
 
+pub use object_guid::{HighGuid, ObjectGuid};
 pub use packed_guid::PackedGuid;
 pub use terminated_string::TerminatedString;
\ No newline at end of file
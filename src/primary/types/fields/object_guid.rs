@@ -0,0 +1,202 @@
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::primary::errors::FieldError;
+use crate::primary::traits::binary_converter::BinaryConverter;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HighGuid {
+    Player,
+    Item,
+    GameObject,
+    Transport,
+    Unit,
+    Pet,
+    Vehicle,
+    DynamicObject,
+    Corpse,
+    MoTransport,
+    Instance,
+    Group,
+    Unknown(u16),
+}
+
+impl HighGuid {
+    pub fn from_u16(value: u16) -> Self {
+        match value {
+            0x0000 => Self::Player,
+            0x4000 => Self::Item,
+            0xF110 => Self::GameObject,
+            0xF120 => Self::Transport,
+            0xF130 => Self::Unit,
+            0xF140 => Self::Pet,
+            0xF150 => Self::Vehicle,
+            0xF100 => Self::DynamicObject,
+            0xF101 => Self::Corpse,
+            0x1FC0 => Self::MoTransport,
+            0x1F40 => Self::Instance,
+            0x1F50 => Self::Group,
+            value => Self::Unknown(value),
+        }
+    }
+
+    pub fn to_u16(self) -> u16 {
+        match self {
+            Self::Player => 0x0000,
+            Self::Item => 0x4000,
+            Self::GameObject => 0xF110,
+            Self::Transport => 0xF120,
+            Self::Unit => 0xF130,
+            Self::Pet => 0xF140,
+            Self::Vehicle => 0xF150,
+            Self::DynamicObject => 0xF100,
+            Self::Corpse => 0xF101,
+            Self::MoTransport => 0x1FC0,
+            Self::Instance => 0x1F40,
+            Self::Group => 0x1F50,
+            Self::Unknown(value) => value,
+        }
+    }
+
+    // guids of these types contain entry of the object template
+    pub fn has_entry(self) -> bool {
+        matches!(
+            self,
+            Self::GameObject | Self::Transport | Self::Unit | Self::Pet | Self::Vehicle | Self::MoTransport
+        )
+    }
+}
+
+// high (16) | entry (24) | counter (24) for guids with entry,
+// high (16) | unused (16) | counter (32) for the rest
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ObjectGuid(u64);
+
+impl ObjectGuid {
+    pub const fn new(value: u64) -> Self {
+        Self(value)
+    }
+
+    pub fn create(high: HighGuid, entry: u32, counter: u32) -> Self {
+        let high_part = (high.to_u16() as u64) << 48;
+
+        match high.has_entry() {
+            true => Self(high_part | ((entry as u64 & 0xFFFFFF) << 24) | (counter as u64 & 0xFFFFFF)),
+            false => Self(high_part | counter as u64),
+        }
+    }
+
+    pub fn raw(&self) -> u64 {
+        self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn high(&self) -> HighGuid {
+        HighGuid::from_u16((self.0 >> 48) as u16)
+    }
+
+    pub fn entry(&self) -> Option<u32> {
+        match self.high().has_entry() {
+            true => Some(((self.0 >> 24) & 0xFFFFFF) as u32),
+            false => None,
+        }
+    }
+
+    pub fn counter(&self) -> u32 {
+        match self.high().has_entry() {
+            true => (self.0 & 0xFFFFFF) as u32,
+            false => self.0 as u32,
+        }
+    }
+
+    pub fn is_player(&self) -> bool {
+        !self.is_empty() && self.high() == HighGuid::Player
+    }
+
+    pub fn is_creature(&self) -> bool {
+        matches!(self.high(), HighGuid::Unit | HighGuid::Vehicle)
+    }
+
+    pub fn is_unit(&self) -> bool {
+        self.is_player() || self.is_creature() || self.high() == HighGuid::Pet
+    }
+}
+
+impl From<u64> for ObjectGuid {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ObjectGuid> for u64 {
+    fn from(guid: ObjectGuid) -> Self {
+        guid.0
+    }
+}
+
+impl Display for ObjectGuid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.entry() {
+            Some(entry) => write!(
+                f, "{:?} (entry: {}, counter: {}, 0x{:016X})", self.high(), entry, self.counter(), self.0,
+            ),
+            None => write!(f, "{:?} (counter: {}, 0x{:016X})", self.high(), self.counter(), self.0),
+        }
+    }
+}
+
+// serialized as number to keep JSON details compatible with injector input
+impl<'de> Deserialize<'de> for ObjectGuid {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        u64::deserialize(deserializer).map(ObjectGuid)
+    }
+}
+
+impl Serialize for ObjectGuid {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        serializer.serialize_u64(self.0)
+    }
+}
+
+impl BinaryConverter for ObjectGuid {
+    fn write_into(&mut self, buffer: &mut Vec<u8>) -> Result<(), FieldError> {
+        buffer.write_u64::<LittleEndian>(self.0)
+            .map_err(|e| FieldError::CannotWrite(e, "u64 (ObjectGuid)".to_string()))
+    }
+
+    fn read_from<R: BufRead>(mut reader: R) -> Result<Self, FieldError> {
+        reader.read_u64::<LittleEndian>()
+            .map(ObjectGuid)
+            .map_err(|e| FieldError::CannotRead(e, "u64 (ObjectGuid)".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::primary::types::{HighGuid, ObjectGuid};
+
+    #[test]
+    fn test_decode_high_guid() {
+        let guid = ObjectGuid::new(0xF130_0012_3400_0056);
+        assert_eq!(guid.high(), HighGuid::Unit);
+        assert_eq!(guid.entry(), Some(0x1234));
+        assert_eq!(guid.counter(), 0x56);
+        assert!(guid.is_creature());
+        assert_eq!(ObjectGuid::create(HighGuid::Unit, 0x1234, 0x56), guid);
+
+        let guid = ObjectGuid::new(0x0000_0000_0000_0042);
+        assert!(guid.is_player());
+        assert_eq!(guid.entry(), None);
+        assert_eq!(guid.counter(), 0x42);
+
+        let guid = ObjectGuid::create(HighGuid::MoTransport, 20808, 7);
+        assert_eq!(guid.high(), HighGuid::MoTransport);
+        assert_eq!(guid.entry(), Some(20808));
+        assert_eq!(guid.to_string(), "MoTransport (entry: 20808, counter: 7, 0x1FC0005148000007)");
+    }
+}
\ No newline at end of file
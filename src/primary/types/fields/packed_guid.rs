@@ -1,21 +1,23 @@
-use std::io::{BufRead, Write};
+use std::io::BufRead;
 use byteorder::ReadBytesExt;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::primary::errors::FieldError;
 use crate::primary::traits::binary_converter::BinaryConverter;
+use crate::primary::types::ObjectGuid;
+use crate::primary::utils::write_packed_guid;
 
 #[derive(Debug, Default, Clone)]
-pub struct PackedGuid(pub u64);
+pub struct PackedGuid(pub ObjectGuid);
 
-impl PartialEq<u64> for PackedGuid {
-    fn eq(&self, other: &u64) -> bool {
+impl PartialEq<ObjectGuid> for PackedGuid {
+    fn eq(&self, other: &ObjectGuid) -> bool {
         let PackedGuid(guid) = self;
         guid == other
     }
 }
 
-impl PartialEq<PackedGuid> for u64 {
+impl PartialEq<PackedGuid> for ObjectGuid {
     fn eq(&self, other: &PackedGuid) -> bool {
         let PackedGuid(guid) = other;
         guid == self
@@ -23,36 +25,22 @@ impl PartialEq<PackedGuid> for u64 {
 }
 
 impl<'de> Deserialize<'de> for PackedGuid {
-    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
-        todo!()
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        ObjectGuid::deserialize(deserializer).map(PackedGuid)
     }
 }
 
 impl Serialize for PackedGuid {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
-        serializer.serialize_u64(self.0)
+        self.0.serialize(serializer)
     }
 }
 
 impl BinaryConverter for PackedGuid {
     fn write_into(&mut self, buffer: &mut Vec<u8>) -> Result<(), FieldError> {
-        let PackedGuid(mut guid) = self;
-        let mut packed_guid = [0u8; 9];
-        let mut size = 1;
-        let mut index = 0;
-
-        while guid != 0 {
-            if guid & 0xFF > 0 {
-                packed_guid[0] |= 1 << index;
-                packed_guid[size] = guid as u8;
-                size += 1;
-            }
-
-            index += 1;
-            guid >>= 8;
-        }
+        let PackedGuid(guid) = self;
 
-        buffer.write_all(&packed_guid[..size])
+        write_packed_guid(buffer, guid.raw())
             .map_err(|e| FieldError::CannotWrite(e, "bytes (PackedGuid)".to_string()))?;
 
         Ok(())
@@ -62,7 +50,7 @@ impl BinaryConverter for PackedGuid {
         let mask = reader.read_u8().unwrap_or(0);
 
         if mask == 0 {
-            return Ok(PackedGuid(0));
+            return Ok(PackedGuid(ObjectGuid::default()));
         }
 
         let mut guid: u64 = 0;
@@ -79,6 +67,6 @@ impl BinaryConverter for PackedGuid {
             i += 1;
         }
 
-        Ok(PackedGuid(guid))
+        Ok(PackedGuid(ObjectGuid::new(guid)))
     }
 }
\ No newline at end of file
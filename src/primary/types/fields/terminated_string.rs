@@ -53,7 +53,8 @@ impl BinaryConverter for TerminatedString {
 
         reader.read_until(0, &mut internal_buf)
             .map_err(|e| FieldError::CannotRead(e, format!("bytes ({})", label)))?;
-        match String::from_utf8(internal_buf[..internal_buf.len() - 1].to_vec()) {
+        let bytes = internal_buf.strip_suffix(&[0]).unwrap_or(&internal_buf);
+        match String::from_utf8(bytes.to_vec()) {
             Ok(string) => Ok(Self(string)),
             Err(err) => Err(FieldError::InvalidString(err, label.to_owned())),
         }
@@ -61,8 +62,8 @@ impl BinaryConverter for TerminatedString {
 }
 
 impl<'de> Deserialize<'de> for TerminatedString {
-    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
-        todo!()
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        String::deserialize(deserializer).map(TerminatedString)
     }
 }
 
@@ -4,10 +4,12 @@ use tokio::sync::Mutex;
 
 mod fields;
 
-pub use fields::{PackedGuid, TerminatedString};
-use crate::primary::client::{Message, Player, Realm};
+pub use fields::{HighGuid, ObjectGuid, PackedGuid, TerminatedString};
+use crate::primary::client::{Message, OutgoingMessage, Player, Position, Realm, WorldEvent};
+use crate::primary::client::movement::types::{FollowEvent, FollowTarget, JumpType, PatrolMode};
 
 use crate::primary::shared::storage::DataStorage;
+use crate::primary::shared::storage::objects::ObjectEvent;
 use crate::primary::shared::session::Session;
 use crate::primary::traits::packet_handler::PacketHandler;
 
@@ -33,15 +35,30 @@ pub enum HandlerOutput {
     TransferCharactersList(Vec<Player>),
     TransferRealmsList(Vec<Realm>),
     UpdatePlayer(Player),
+    ObjectEvent(ObjectEvent),
+    FollowEvent(FollowEvent),
+    WorldEvent(WorldEvent),
 
     // commands
     ConnectionRequest(String, u16),
     Drop,
     ExitConfirmed,
     ExitRequest,
+    Follow(FollowTarget),
     Freeze,
+    Jump(JumpType),
+    MarkRoutePoint(u64),
+    MoveTo(Vec<Position>),
+    NavigateTo(Position),
+    PlayRoute(String, PatrolMode),
+    RecordRoute(String, Option<FollowTarget>),
     SelectCharacter(Player),
     SelectRealm(Realm),
+    SendChatMessage(OutgoingMessage),
+    StopFollow,
+    StopMovement,
+    StopRecording,
+    StopRoute,
 
     // messages
     ResponseMessage(String, Option<String>),
@@ -1,5 +1,5 @@
 use std::{num::ParseIntError};
-use std::io::{BufRead, Read};
+use std::io::{BufRead, Error, Read, Write};
 use byteorder::ReadBytesExt;
 use flate2::read::ZlibDecoder;
 
@@ -41,6 +41,25 @@ pub fn read_packed_guid<R: BufRead>(reader: &mut R) -> u64 {
     guid
 }
 
+pub fn write_packed_guid<W: Write>(writer: &mut W, mut guid: u64) -> Result<(), Error> {
+    let mut packed_guid = [0u8; 9];
+    let mut size = 1;
+    let mut index = 0;
+
+    while guid != 0 {
+        if guid & 0xFF > 0 {
+            packed_guid[0] |= 1 << index;
+            packed_guid[size] = guid as u8;
+            size += 1;
+        }
+
+        index += 1;
+        guid >>= 8;
+    }
+
+    writer.write_all(&packed_guid[..size])
+}
+
 pub fn decompress(data: &[u8]) -> Vec<u8> {
     let mut buffer = Vec::new();
 